@@ -15,8 +15,18 @@ pub enum Response<T> {
     More(T, Vec<Secondary>),
 }
 
+/// A [`Response`] along with the raw body it was parsed from
+///
+/// Caches can choose to store the body instead of the parsed objects, see
+/// [`Store::store_raw`](crate::cache::Store::store_raw)
+#[derive(Debug)]
+pub struct RawResponse<T> {
+    pub body: String,
+    pub response: Response<T>,
+}
+
 pub trait MakeRequest<R: Request>: ApiClient {
-    type Future: Future<Item = Response<R::Result>, Error = Self::Err> + Send + 'static;
+    type Future: Future<Item = RawResponse<R::Result>, Error = Self::Err> + Send + 'static;
 
     fn make(&self, request: &R) -> Self::Future;
 }
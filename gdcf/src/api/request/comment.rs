@@ -1,7 +1,7 @@
 //! Module containing request structs for retrieving profile/level comments
 
 use crate::api::request::{BaseRequest, PaginatableRequest, Request, GD_21};
-use gdcf_model::comment::{CommentUser, LevelComment, ProfileComment};
+use gdcf_model::comment::{LevelComment, ProfileComment};
 use std::{
     fmt::{Display, Formatter},
     hash::{Hash, Hasher},
@@ -108,8 +108,12 @@ impl Hash for LevelCommentsRequest {
     }
 }
 
+eq_by_hashed_fields!(LevelCommentsRequest: level_id, sort_mode, limit, page, total);
+
+/// The comments are returned without user data, see
+/// [`Gdcf::level_comments`](crate::Gdcf::level_comments) for how to attach it
 impl Request for LevelCommentsRequest {
-    type Result = Vec<LevelComment<Option<CommentUser>>>;
+    type Result = Vec<LevelComment>;
 }
 
 impl PaginatableRequest for LevelCommentsRequest {
@@ -179,6 +183,8 @@ impl Hash for ProfileCommentsRequest {
     }
 }
 
+eq_by_hashed_fields!(ProfileCommentsRequest: account_id, page, total);
+
 impl Request for ProfileCommentsRequest {
     type Result = Vec<ProfileComment>;
 }
@@ -0,0 +1,134 @@
+//! Module containing request structs for retrieving the in-game leaderboards
+
+use crate::api::request::{Authentication, BaseRequest, Request, GD_21};
+use gdcf_model::user::LeaderboardEntry;
+use std::{
+    fmt::{Display, Formatter},
+    hash::{Hash, Hasher},
+};
+
+/// The different leaderboards that can be requested
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum LeaderboardType {
+    /// The global top leaderboard, ranking players by stars
+    ///
+    /// ## GD Internals:
+    /// This variant is represented by the value `"top"` in the boomlings API
+    Top,
+
+    /// The creator leaderboard, ranking players by creator points
+    ///
+    /// ## GD Internals:
+    /// This variant is represented by the value `"creators"` in the boomlings API
+    Creators,
+
+    /// The global leaderboard around the player making the request
+    ///
+    /// Note that this leaderboard requires the request to be made by a logged-in player.
+    ///
+    /// ## GD Internals:
+    /// This variant is represented by the value `"relative"` in the boomlings API
+    Relative,
+
+    /// The leaderboard containing only the friends of the player making the request
+    ///
+    /// Note that this leaderboard requires the request to be made by a logged-in player.
+    ///
+    /// ## GD Internals:
+    /// This variant is represented by the value `"friends"` in the boomlings API
+    Friends,
+}
+
+impl Display for LeaderboardType {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            LeaderboardType::Top => write!(f, "top"),
+            LeaderboardType::Creators => write!(f, "creators"),
+            LeaderboardType::Relative => write!(f, "relative"),
+            LeaderboardType::Friends => write!(f, "friends"),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct LeaderboardRequest {
+    /// The base request data
+    pub base: BaseRequest,
+
+    /// The leaderboard to retrieve
+    ///
+    /// ## GD Internals:
+    /// This field is called `type` in the boomlings API
+    pub leaderboard_type: LeaderboardType,
+
+    /// The amount of entries to retrieve. The game always requests `100` entries
+    ///
+    /// ## GD Internals:
+    /// This field is called `count` in the boomlings API
+    pub count: u32,
+
+    /// The account on whose behalf this request is made
+    ///
+    /// This is required for the [`LeaderboardType::Relative`] and [`LeaderboardType::Friends`]
+    /// leaderboards and ignored otherwise.
+    ///
+    /// ## GD Internals:
+    /// This value is split into the `accountID` and `gjp` fields in the boomlings API. Both are
+    /// omitted if no authentication is provided.
+    pub authentication: Option<Authentication>,
+}
+
+impl LeaderboardRequest {
+    const_setter!(with_base, base, BaseRequest);
+
+    const_setter!(count: u32);
+
+    pub const fn new(leaderboard_type: LeaderboardType) -> LeaderboardRequest {
+        LeaderboardRequest {
+            base: GD_21,
+            leaderboard_type,
+            count: 100,
+            authentication: None,
+        }
+    }
+
+    pub const fn top() -> LeaderboardRequest {
+        LeaderboardRequest::new(LeaderboardType::Top)
+    }
+
+    pub const fn creators() -> LeaderboardRequest {
+        LeaderboardRequest::new(LeaderboardType::Creators)
+    }
+
+    /// Makes this request on behalf of the account with the given credentials
+    pub fn authenticate(mut self, authentication: Authentication) -> Self {
+        self.authentication = Some(authentication);
+        self
+    }
+}
+
+impl Display for LeaderboardRequest {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "LeaderboardRequest({}, {})", self.leaderboard_type, self.count)
+    }
+}
+
+impl Hash for LeaderboardRequest {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.leaderboard_type.hash(state);
+        self.count.hash(state);
+        self.authentication.hash(state);
+    }
+}
+
+eq_by_hashed_fields!(LeaderboardRequest: leaderboard_type, count, authentication);
+
+impl Request for LeaderboardRequest {
+    type Result = Vec<LeaderboardEntry>;
+}
+
+impl Into<LeaderboardRequest> for LeaderboardType {
+    fn into(self) -> LeaderboardRequest {
+        LeaderboardRequest::new(self)
+    }
+}
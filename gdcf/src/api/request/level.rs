@@ -1,7 +1,7 @@
 //! Module containing request definitions for retrieving levels
 
-use crate::api::request::{BaseRequest, PaginatableRequest, Request, GD_21};
-use gdcf_model::level::{DemonRating, Level, LevelLength, LevelRating, PartialLevel};
+use crate::api::request::{Authentication, BaseRequest, PaginatableRequest, Request, GD_21};
+use gdcf_model::level::{DailyLevel, DemonRating, Level, LevelLength, LevelRating, LevelScore, PartialLevel};
 use std::{
     fmt::{Display, Error, Formatter},
     hash::{Hash, Hasher},
@@ -19,10 +19,20 @@ pub struct LevelRequest {
 
     /// The ID of the level to download
     ///
+    /// This value is ignored if [`LevelRequest::special`] is set
+    ///
     /// ## GD Internals:
     /// This field is called `levelID` in the boomlings API
     pub level_id: u64,
 
+    /// Optionally, the special level to download instead of the level with the given ID, see
+    /// [`LevelRequest::daily`] and [`LevelRequest::weekly`]
+    ///
+    /// ## GD Internals:
+    /// The boomlings API has no separate field for this. Instead, the special level is requested
+    /// by setting `levelID` to a negative value, see [`SpecialLevel`]
+    pub special: Option<SpecialLevel>,
+
     /// Some weird field the Geometry Dash Client sends along
     ///
     /// ## GD Internals:
@@ -38,14 +48,40 @@ pub struct LevelRequest {
 }
 
 /// Manual `Hash` impl that doesn't hash `base`.
+///
+/// `special` is only hashed if set, so that requests for ordinary levels hash the same as before
+/// it was added
 impl Hash for LevelRequest {
     fn hash<H: Hasher>(&self, state: &mut H) {
         self.level_id.hash(state);
         self.inc.hash(state);
         self.extra.hash(state);
+
+        if let Some(special) = self.special {
+            special.hash(state);
+        }
     }
 }
 
+eq_by_hashed_fields!(LevelRequest: level_id, inc, extra, special);
+
+/// Enum modelling the levels that can be downloaded via a [`LevelRequest`] without knowing their
+/// ID
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SpecialLevel {
+    /// The current daily level
+    ///
+    /// ## GD Internals:
+    /// This variant is represented by the level ID `-1` in requests
+    Daily,
+
+    /// The current weekly demon
+    ///
+    /// ## GD Internals:
+    /// This variant is represented by the level ID `-2` in requests
+    Weekly,
+}
+
 /// Struct modelled after a request to `getGJLevels21.php`
 ///
 /// In the Geometry Dash API, this endpoint is used to retrieve a list of
@@ -66,7 +102,7 @@ pub struct LevelsRequest {
     /// A search string to filter the levels by
     ///
     /// This value is ignored unless [`LevelsRequest::request_type`] is set to
-    /// [`LevelRequestType::Search`] or [`LevelRequestType::User`]
+    /// [`LevelRequestType::Search`], [`LevelRequestType::User`] or [`LevelRequestType::MapPack`]
     ///
     /// ## GD Internals:
     /// This field is called `str` in the boomlings API
@@ -123,9 +159,32 @@ pub struct LevelsRequest {
     /// This value is ignored unless [`LevelsRequest::request_type`] is set to
     /// [`LevelRequestType::Search`]
     pub search_filters: SearchFilters,
+
+    /// The account on whose behalf this request is made
+    ///
+    /// This is required if [`LevelsRequest::request_type`] is set to [`LevelRequestType::Friends`]
+    /// and ignored otherwise.
+    ///
+    /// ## GD Internals:
+    /// This value is split into the `accountID` and `gjp` fields in the boomlings API. Both are
+    /// omitted if no authentication is provided.
+    pub authentication: Option<Authentication>,
+
+    /// The accounts whose levels should be retrieved
+    ///
+    /// This value is ignored unless [`LevelsRequest::request_type`] is set to
+    /// [`LevelRequestType::Followed`]
+    ///
+    /// ## GD Internals:
+    /// This field is called `followed` in the boomlings API and needs to be converted to a comma
+    /// separated list of account IDs. It is omitted if empty.
+    pub followed: Vec<u64>,
 }
 
 /// Manual Hash impl which doesn't hash the base
+///
+/// The authentication and list of followed accounts are only hashed if present, so that adding
+/// them didn't change the hash of requests not using them
 impl Hash for LevelsRequest {
     fn hash<H: Hasher>(&self, state: &mut H) {
         self.search_filters.hash(state);
@@ -136,12 +195,22 @@ impl Hash for LevelsRequest {
         self.search_string.hash(state);
         self.request_type.hash(state);
         self.page.hash(state);
+
+        if let Some(ref authentication) = self.authentication {
+            authentication.hash(state);
+        }
+
+        if !self.followed.is_empty() {
+            self.followed.hash(state);
+        }
     }
 }
 
+eq_by_hashed_fields!(LevelsRequest: search_filters, total, demon_rating, ratings, lengths, search_string, request_type, page, authentication, followed);
+
 /// Enum representing the various filter states that can be achieved using the
 /// `completed` and `uncompleted` options in the Geometry Dash client
-#[derive(Debug, Clone, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum CompletionFilter {
     /// No filtering based upon completion
     None,
@@ -186,7 +255,7 @@ impl CompletionFilter {
 
 /// Struct containing the various search filters provided by the Geometry Dash
 /// client.
-#[derive(Debug, Default, Clone, Hash)]
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
 pub struct SearchFilters {
     /// In- or excluding levels that have already been beaten. Since the GDCF
     /// client doesn't really have a notion of "completing" a level, this
@@ -267,7 +336,7 @@ pub struct SearchFilters {
 /// + Unused values: `8`, `9`, `14`
 /// + The values `15` and `17` are only used in Geometry Dash World and are the
 /// same as `0` ([`LevelRequestType::Search`]) and `6` ([`LevelRequestType::Featured`]) respectively
-#[derive(Debug, Copy, Clone, PartialEq, Hash)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
 pub enum LevelRequestType {
     /// A search request.
     ///
@@ -338,7 +407,8 @@ pub enum LevelRequestType {
     /// This variant is represented by the value `11` in requests
     Awarded,
 
-    /// Unknown how this works
+    /// Request to retrieve the levels uploaded by a list of accounts, usually the ones the player
+    /// follows. The accounts are set via [`LevelsRequest::followed`]
     ///
     /// ## GD Internals:
     /// This variant is represented by the value `12` in requests
@@ -428,6 +498,95 @@ impl SearchFilters {
     }
 }
 
+/// The different scopes of a level's leaderboard that can be requested
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum LevelScoreScope {
+    /// Only the scores of the requesting player's friends
+    ///
+    /// ## GD Internals:
+    /// This variant is represented by the numeric value `0` in the boomlings API
+    Friends,
+
+    /// The best scores of all time
+    ///
+    /// ## GD Internals:
+    /// This variant is represented by the numeric value `1` in the boomlings API
+    Top,
+
+    /// The best scores set during the past week
+    ///
+    /// ## GD Internals:
+    /// This variant is represented by the numeric value `2` in the boomlings API
+    Week,
+}
+
+/// Struct modelled after a request to `getGJLevelScores211.php`.
+///
+/// In the Geometry Dash API, this endpoint is used to retrieve the leaderboard of a single level.
+/// Note that the servers only return scores if the request is made by a logged-in player.
+#[derive(Debug, Clone)]
+pub struct LevelScoresRequest {
+    /// The base request data
+    pub base: BaseRequest,
+
+    /// The ID of the level whose leaderboard should be retrieved
+    ///
+    /// ## GD Internals:
+    /// This field is called `levelID` in the boomlings API
+    pub level_id: u64,
+
+    /// The scope of the leaderboard to retrieve
+    ///
+    /// ## GD Internals:
+    /// This field is called `type` in the boomlings API
+    pub scope: LevelScoreScope,
+
+    /// The account on whose behalf this request is made
+    ///
+    /// Without authentication, the servers don't return any scores.
+    ///
+    /// ## GD Internals:
+    /// This value is split into the `accountID` and `gjp` fields in the boomlings API
+    pub authentication: Option<Authentication>,
+}
+
+/// Manual `Hash` impl that doesn't hash `base`.
+impl Hash for LevelScoresRequest {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.level_id.hash(state);
+        self.scope.hash(state);
+        self.authentication.hash(state);
+    }
+}
+
+eq_by_hashed_fields!(LevelScoresRequest: level_id, scope, authentication);
+
+/// Struct modelled after a request to `getGJDailyLevel.php`.
+///
+/// In the Geometry Dash API, this endpoint is used to retrieve the number of the current daily
+/// level (or weekly demon) and the time left until it is replaced. The level itself has to be
+/// retrieved via a [`LevelRequest`], see [`DailyLevelRequest::level_request`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DailyLevelRequest {
+    /// The base request data
+    pub base: BaseRequest,
+
+    /// Whether to retrieve information about the weekly demon instead of the daily level
+    ///
+    /// ## GD Internals:
+    /// This field is called `weekly` in the boomlings API and needs to be converted to an integer
+    pub weekly: bool,
+}
+
+/// Manual `Hash` impl that doesn't hash `base`.
+impl Hash for DailyLevelRequest {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.weekly.hash(state);
+    }
+}
+
+eq_by_hashed_fields!(DailyLevelRequest: weekly);
+
 impl LevelRequest {
     const_setter! {
         /// Sets the [`BaseRequest`] to be used
@@ -459,10 +618,40 @@ impl LevelRequest {
         LevelRequest {
             base: GD_21,
             level_id,
+            special: None,
             inc: true,
             extra: false,
         }
     }
+
+    /// Constructs a new `LevelRequest` to retrieve the current daily level
+    pub const fn daily() -> LevelRequest {
+        LevelRequest::special(SpecialLevel::Daily)
+    }
+
+    /// Constructs a new `LevelRequest` to retrieve the current weekly demon
+    pub const fn weekly() -> LevelRequest {
+        LevelRequest::special(SpecialLevel::Weekly)
+    }
+
+    /// Constructs a new `LevelRequest` to retrieve the given [`SpecialLevel`]
+    pub const fn special(special: SpecialLevel) -> LevelRequest {
+        let mut request = LevelRequest::new(0);
+        request.special = Some(special);
+        request
+    }
+
+    /// Constructs the [`DailyLevelRequest`] that retrieves information about the level this request
+    /// downloads, if this request is for a [`SpecialLevel`]
+    pub fn daily_level_request(&self) -> Option<DailyLevelRequest> {
+        self.special.map(|special| {
+            match special {
+                SpecialLevel::Daily => DailyLevelRequest::daily(),
+                SpecialLevel::Weekly => DailyLevelRequest::weekly(),
+            }
+            .with_base(self.base)
+        })
+    }
 }
 
 impl LevelsRequest {
@@ -501,6 +690,88 @@ impl LevelsRequest {
         self.demon_rating = Some(demon_rating);
         self
     }
+
+    /// Makes this request on behalf of the account with the given credentials
+    pub fn authenticate(mut self, authentication: Authentication) -> Self {
+        self.authentication = Some(authentication);
+        self
+    }
+
+    /// Makes this request retrieve the levels uploaded by the accounts with the given IDs
+    pub fn followed(mut self, account_ids: Vec<u64>) -> Self {
+        self.followed = account_ids;
+        self.request_type = LevelRequestType::Followed;
+        self
+    }
+}
+
+impl LevelScoresRequest {
+    const_setter!(with_base, base, BaseRequest);
+
+    const_setter!(scope: LevelScoreScope);
+
+    /// Constructs a new `LevelScoresRequest` to retrieve the all-time leaderboard of the level
+    /// with the given id
+    pub const fn new(level_id: u64) -> LevelScoresRequest {
+        LevelScoresRequest {
+            base: GD_21,
+            level_id,
+            scope: LevelScoreScope::Top,
+            authentication: None,
+        }
+    }
+
+    /// Makes this request on behalf of the account with the given credentials
+    pub fn authenticate(mut self, authentication: Authentication) -> Self {
+        self.authentication = Some(authentication);
+        self
+    }
+}
+
+impl DailyLevelRequest {
+    const_setter!(with_base, base, BaseRequest);
+
+    /// Constructs a new `DailyLevelRequest` to retrieve information about the current daily level
+    pub const fn daily() -> DailyLevelRequest {
+        DailyLevelRequest {
+            base: GD_21,
+            weekly: false,
+        }
+    }
+
+    /// Constructs a new `DailyLevelRequest` to retrieve information about the current weekly demon
+    pub const fn weekly() -> DailyLevelRequest {
+        DailyLevelRequest { base: GD_21, weekly: true }
+    }
+
+    /// Constructs the [`LevelRequest`] that downloads the level this request retrieves information
+    /// about
+    pub const fn level_request(&self) -> LevelRequest {
+        if self.weekly {
+            LevelRequest::weekly().with_base(self.base)
+        } else {
+            LevelRequest::daily().with_base(self.base)
+        }
+    }
+}
+
+impl From<LevelScoreScope> for i32 {
+    fn from(scope: LevelScoreScope) -> Self {
+        match scope {
+            LevelScoreScope::Friends => 0,
+            LevelScoreScope::Top => 1,
+            LevelScoreScope::Week => 2,
+        }
+    }
+}
+
+impl From<SpecialLevel> for i64 {
+    fn from(special: SpecialLevel) -> Self {
+        match special {
+            SpecialLevel::Daily => -1,
+            SpecialLevel::Weekly => -2,
+        }
+    }
 }
 
 impl Default for LevelRequestType {
@@ -545,6 +816,20 @@ impl PaginatableRequest for LevelRequest {
     }
 }
 
+impl From<u64> for LevelScoresRequest {
+    fn from(lid: u64) -> Self {
+        LevelScoresRequest::new(lid)
+    }
+}
+
+impl Request for LevelScoresRequest {
+    type Result = Vec<LevelScore>;
+}
+
+impl Request for DailyLevelRequest {
+    type Result = DailyLevel;
+}
+
 impl Request for LevelsRequest {
     type Result = Vec<PartialLevel<Option<u64>, u64>>;
 }
@@ -557,7 +842,20 @@ impl PaginatableRequest for LevelsRequest {
 
 impl Display for LevelRequest {
     fn fmt(&self, f: &mut Formatter) -> Result<(), Error> {
-        write!(f, "LevelRequest({})", self.level_id)
+        match self.special {
+            Some(special) => write!(f, "LevelRequest({:?})", special),
+            None => write!(f, "LevelRequest({})", self.level_id),
+        }
+    }
+}
+
+impl Display for DailyLevelRequest {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), Error> {
+        if self.weekly {
+            write!(f, "DailyLevelRequest(weekly)")
+        } else {
+            write!(f, "DailyLevelRequest(daily)")
+        }
     }
 }
 
@@ -569,3 +867,9 @@ impl Display for LevelsRequest {
         }
     }
 }
+
+impl Display for LevelScoresRequest {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), Error> {
+        write!(f, "LevelScoresRequest({}, {:?})", self.level_id, self.scope)
+    }
+}
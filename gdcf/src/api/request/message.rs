@@ -0,0 +1,189 @@
+//! Module containing request definitions for retrieving private messages
+
+use crate::api::request::{Authentication, BaseRequest, PaginatableRequest, Request, GD_21};
+use gdcf_model::message::{Message, PartialMessage};
+use std::{
+    fmt::{Display, Formatter},
+    hash::{Hash, Hasher},
+};
+
+/// Struct modelled after a request to `getGJMessages20.php`.
+///
+/// In the Geometry Dash API, this endpoint is used to retrieve the messages in a player's inbox
+/// (or the messages they have sent). Since messages are private, this request has to be made on
+/// behalf of the player whose messages should be retrieved.
+#[derive(Debug, Clone)]
+pub struct MessagesRequest {
+    /// The base request data
+    pub base: BaseRequest,
+
+    /// The account whose messages should be retrieved
+    ///
+    /// ## GD Internals:
+    /// This value is split into the `accountID` and `gjp` fields in the boomlings API
+    pub authentication: Authentication,
+
+    /// The page of messages to retrieve. The first page is page `0`
+    ///
+    /// ## GD Internals:
+    /// This field is called `page` in the boomlings API
+    pub page: u32,
+
+    /// Unknown, probably related to pagination
+    ///
+    /// ## GD Internals:
+    /// This field is called `total` in the boomlings API
+    pub total: u32,
+
+    /// Whether to retrieve the messages sent by the player instead of the ones they received
+    ///
+    /// ## GD Internals:
+    /// This field is called `getSent` in the boomlings API and needs to be converted to an
+    /// integer
+    pub sent: bool,
+}
+
+impl MessagesRequest {
+    const_setter!(with_base, base, BaseRequest);
+
+    const_setter!(page: u32);
+
+    const_setter!(total: u32);
+
+    /// Constructs a new `MessagesRequest` retrieving the first page of the inbox of the given
+    /// account
+    pub fn inbox(authentication: Authentication) -> MessagesRequest {
+        MessagesRequest {
+            base: GD_21,
+            authentication,
+            page: 0,
+            total: 0,
+            sent: false,
+        }
+    }
+
+    /// Constructs a new `MessagesRequest` retrieving the first page of messages sent by the given
+    /// account
+    pub fn sent(authentication: Authentication) -> MessagesRequest {
+        MessagesRequest {
+            sent: true,
+            ..MessagesRequest::inbox(authentication)
+        }
+    }
+}
+
+impl Display for MessagesRequest {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        if self.sent {
+            write!(f, "MessagesRequest(sent by {}, page {})", self.authentication.account_id, self.page)
+        } else {
+            write!(
+                f,
+                "MessagesRequest(inbox of {}, page {})",
+                self.authentication.account_id, self.page
+            )
+        }
+    }
+}
+
+/// Manual `Hash` impl that doesn't hash `base`.
+impl Hash for MessagesRequest {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.authentication.hash(state);
+        self.page.hash(state);
+        self.total.hash(state);
+        self.sent.hash(state);
+    }
+}
+
+eq_by_hashed_fields!(MessagesRequest: authentication, page, total, sent);
+
+impl Request for MessagesRequest {
+    type Result = Vec<PartialMessage>;
+}
+
+impl PaginatableRequest for MessagesRequest {
+    fn next(&mut self) {
+        self.page += 1;
+    }
+}
+
+impl Into<MessagesRequest> for Authentication {
+    fn into(self) -> MessagesRequest {
+        MessagesRequest::inbox(self)
+    }
+}
+
+/// Struct modelled after a request to `downloadGJMessage20.php`.
+///
+/// In the Geometry Dash API, this endpoint is used to retrieve the content of a single private
+/// message. It has to be made on behalf of either the sender or the recipient of the message.
+#[derive(Debug, Clone)]
+pub struct MessageRequest {
+    /// The base request data
+    pub base: BaseRequest,
+
+    /// The account on whose behalf the message is retrieved
+    ///
+    /// ## GD Internals:
+    /// This value is split into the `accountID` and `gjp` fields in the boomlings API
+    pub authentication: Authentication,
+
+    /// The ID of the message to retrieve
+    ///
+    /// ## GD Internals:
+    /// This field is called `messageID` in the boomlings API
+    pub message_id: u64,
+
+    /// Whether the message was sent (as opposed to received) by the account this request is made
+    /// on behalf of
+    ///
+    /// ## GD Internals:
+    /// This field is called `isSender` in the boomlings API and needs to be converted to an
+    /// integer
+    pub sent: bool,
+}
+
+impl MessageRequest {
+    const_setter!(with_base, base, BaseRequest);
+
+    const_setter!(sent: bool);
+
+    /// Constructs a new `MessageRequest` retrieving the received message with the given ID
+    pub fn new(authentication: Authentication, message_id: u64) -> MessageRequest {
+        MessageRequest {
+            base: GD_21,
+            authentication,
+            message_id,
+            sent: false,
+        }
+    }
+
+    /// Constructs the `MessageRequest` retrieving the content of the given [`PartialMessage`],
+    /// which must have been retrieved on behalf of the given account
+    pub fn for_message(authentication: Authentication, message: &PartialMessage) -> MessageRequest {
+        MessageRequest::new(authentication, message.message_id).sent(message.sent)
+    }
+}
+
+impl Display for MessageRequest {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "MessageRequest({})", self.message_id)
+    }
+}
+
+/// Manual `Hash` impl that doesn't hash `base`. The account a message is retrieved on behalf of is
+/// hashed, as only the sender and the recipient of a message are allowed to read it
+impl Hash for MessageRequest {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.authentication.hash(state);
+        self.message_id.hash(state);
+        self.sent.hash(state);
+    }
+}
+
+eq_by_hashed_fields!(MessageRequest: authentication, message_id, sent);
+
+impl Request for MessageRequest {
+    type Result = Message;
+}
@@ -11,15 +11,31 @@
 
 pub use self::{
     comment::{LevelCommentsRequest, ProfileCommentsRequest},
-    level::{LevelRequest, LevelRequestType, LevelsRequest, SearchFilters, SongFilter},
-    user::{UserRequest, UserSearchRequest},
+    leaderboard::{LeaderboardRequest, LeaderboardType},
+    level::{
+        DailyLevelRequest, LevelRequest, LevelRequestType, LevelScoreScope, LevelScoresRequest, LevelsRequest, SearchFilters, SongFilter,
+        SpecialLevel,
+    },
+    message::{MessageRequest, MessagesRequest},
+    pack::{GauntletsRequest, MapPacksRequest},
+    song::SongInfoRequest,
+    user::{BlockedUsersRequest, FriendListRequest, FriendRequestsRequest, UserRequest, UserSearchRequest},
+    write::{LikeRequest, LikeTarget, RateStarsRequest, UploadLevelCommentRequest, UploadLevelRequest, UploadProfileCommentRequest},
 };
 use gdcf_model::GameVersion;
-use std::{fmt::Debug, hash::Hash};
+use std::{
+    fmt::{Debug, Formatter},
+    hash::{Hash, Hasher},
+};
 
 pub mod comment;
+pub mod leaderboard;
 pub mod level;
+pub mod message;
+pub mod pack;
+pub mod song;
 pub mod user;
+pub mod write;
 
 /// A `BaseRequest` instance that has all its fields set to the
 /// same values a Geometry Dash 2.1 client would use
@@ -79,6 +95,55 @@ impl Default for BaseRequest {
     }
 }
 
+/// The credentials of a Geometry Dash account
+///
+/// Some requests, like the ones for friends leaderboards, only return sensible data if they are
+/// made on behalf of a logged-in player. Those requests can optionally carry an `Authentication`.
+///
+/// ## GD Internals:
+/// The boomlings API expects the account ID in a field called `accountID`. The password is never
+/// sent in plain text, but encoded using robtop's "GJP" scheme and sent in a field called `gjp`.
+#[derive(Clone)]
+pub struct Authentication {
+    /// The ID of the account to authenticate as
+    pub account_id: u64,
+
+    /// The GJP-encoded password of the account
+    ///
+    /// See `gdcf_parse::util::encode_gjp` for how to obtain this value from the plain password
+    pub gjp: String,
+}
+
+impl Authentication {
+    /// Constructs a new `Authentication` from the given account ID and **already GJP-encoded**
+    /// password
+    pub fn new(account_id: u64, gjp: String) -> Authentication {
+        Authentication { account_id, gjp }
+    }
+}
+
+/// Manual `Hash` impl hashing both the account ID and the password, as a request made with the
+/// wrong password fails. This ensures cached results of authenticated requests are only ever
+/// served to someone who knows the credentials they were retrieved with.
+impl Hash for Authentication {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.account_id.hash(state);
+        self.gjp.hash(state);
+    }
+}
+
+eq_by_hashed_fields!(Authentication: account_id, gjp);
+
+/// Manual `Debug` impl that leaves out the password, as GJP-encoding is trivially reversible
+impl Debug for Authentication {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        f.debug_struct("Authentication")
+            .field("account_id", &self.account_id)
+            .field("gjp", &"<redacted>")
+            .finish()
+    }
+}
+
 /// Trait for types that are meant to be requests whose results can be cached
 /// by GDCF.
 pub trait Request: Debug + Send + Sync + 'static {
@@ -0,0 +1,93 @@
+//! Module containing request structs for retrieving map packs and gauntlets
+
+use crate::api::request::{BaseRequest, Request, GD_21};
+use gdcf_model::level::{Gauntlet, MapPack};
+use std::{
+    fmt::{Display, Formatter},
+    hash::{Hash, Hasher},
+};
+
+/// Struct modelled after a request to `getGJMapPacks21.php`.
+///
+/// In the Geometry Dash API, this endpoint is used to retrieve the list of map packs, ten at a
+/// time.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct MapPacksRequest {
+    /// The base request data
+    pub base: BaseRequest,
+
+    /// The page of map packs to retrieve
+    ///
+    /// ## GD Internals:
+    /// This field is called `page` in the boomlings API
+    pub page: u32,
+}
+
+impl MapPacksRequest {
+    const_setter!(with_base, base, BaseRequest);
+
+    const_setter!(page: u32);
+
+    pub const fn new() -> MapPacksRequest {
+        MapPacksRequest { base: GD_21, page: 0 }
+    }
+}
+
+impl Display for MapPacksRequest {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "MapPacksRequest(page={})", self.page)
+    }
+}
+
+impl Hash for MapPacksRequest {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.page.hash(state);
+    }
+}
+
+eq_by_hashed_fields!(MapPacksRequest: page);
+
+impl Request for MapPacksRequest {
+    type Result = Vec<MapPack>;
+}
+
+impl Into<MapPacksRequest> for u32 {
+    fn into(self) -> MapPacksRequest {
+        MapPacksRequest::new().page(self)
+    }
+}
+
+/// Struct modelled after a request to `getGJGauntlets21.php`.
+///
+/// In the Geometry Dash API, this endpoint is used to retrieve the list of all gauntlets at once.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct GauntletsRequest {
+    /// The base request data
+    pub base: BaseRequest,
+}
+
+impl GauntletsRequest {
+    const_setter!(with_base, base, BaseRequest);
+
+    pub const fn new() -> GauntletsRequest {
+        GauntletsRequest { base: GD_21 }
+    }
+}
+
+impl Display for GauntletsRequest {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "GauntletsRequest")
+    }
+}
+
+impl Hash for GauntletsRequest {
+    fn hash<H: Hasher>(&self, _state: &mut H) {
+        // There is only ever one list of gauntlets
+    }
+}
+
+eq_by_hashed_fields!(GauntletsRequest);
+
+impl Request for GauntletsRequest {
+    type Result = Vec<Gauntlet>;
+}
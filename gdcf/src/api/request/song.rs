@@ -0,0 +1,57 @@
+//! Module containing request definitions for retrieving newgrounds songs
+
+use crate::api::request::{BaseRequest, Request, GD_21};
+use gdcf_model::song::NewgroundsSong;
+use std::{
+    fmt::{Display, Error, Formatter},
+    hash::{Hash, Hasher},
+};
+
+/// Struct modelled after a request to `getGJSongInfo.php`.
+///
+/// In the Geometry Dash API, this endpoint is used to retrieve the metadata of a single newgrounds
+/// song by its ID, for instance when a user enters a song ID into the custom song menu of the level
+/// editor.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct SongInfoRequest {
+    /// The base request data
+    pub base: BaseRequest,
+
+    /// The newgrounds ID of the song whose data to retrieve
+    ///
+    /// ## GD Internals:
+    /// This field is called `songID` in the boomlings API
+    pub song_id: u64,
+}
+
+impl SongInfoRequest {
+    const_setter!(with_base, base, BaseRequest);
+
+    pub const fn new(song_id: u64) -> SongInfoRequest {
+        SongInfoRequest { base: GD_21, song_id }
+    }
+}
+
+impl Hash for SongInfoRequest {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.song_id.hash(state)
+    }
+}
+
+eq_by_hashed_fields!(SongInfoRequest: song_id);
+
+impl Into<SongInfoRequest> for u64 {
+    fn into(self) -> SongInfoRequest {
+        SongInfoRequest::new(self)
+    }
+}
+
+impl Display for SongInfoRequest {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), Error> {
+        write!(f, "SongInfoRequest({})", self.song_id)
+    }
+}
+
+impl Request for SongInfoRequest {
+    type Result = NewgroundsSong;
+}
@@ -1,7 +1,7 @@
 //! Module containing request definitions for retrieving users
 
-use crate::api::request::{BaseRequest, PaginatableRequest, Request, GD_21};
-use gdcf_model::user::{Creator, SearchedUser, User};
+use crate::api::request::{Authentication, BaseRequest, PaginatableRequest, Request, GD_21};
+use gdcf_model::user::{Creator, FriendRequest, ListedUser, SearchedUser, User};
 use std::{
     fmt::{Display, Error, Formatter},
     hash::{Hash, Hasher},
@@ -40,6 +40,8 @@ impl Hash for UserRequest {
     }
 }
 
+eq_by_hashed_fields!(UserRequest: user);
+
 impl Into<UserRequest> for u64 {
     fn into(self) -> UserRequest {
         UserRequest::new(self)
@@ -58,6 +60,18 @@ impl Into<UserRequest> for &SearchedUser {
     }
 }
 
+impl Into<UserRequest> for ListedUser {
+    fn into(self) -> UserRequest {
+        UserRequest::new(self.account_id)
+    }
+}
+
+impl Into<UserRequest> for &ListedUser {
+    fn into(self) -> UserRequest {
+        UserRequest::new(self.account_id)
+    }
+}
+
 impl Display for UserRequest {
     fn fmt(&self, f: &mut Formatter) -> Result<(), Error> {
         write!(f, "UserRequest({})", self.user)
@@ -168,6 +182,8 @@ impl Hash for UserSearchRequest {
     }
 }
 
+eq_by_hashed_fields!(UserSearchRequest: search_string);
+
 impl Request for UserSearchRequest {
     type Result = SearchedUser;
 }
@@ -177,3 +193,218 @@ impl PaginatableRequest for UserSearchRequest {
         self.page += 1;
     }
 }
+
+/// Struct modelled after a request to `getGJUserList20.php` retrieving a player's friend list.
+///
+/// Since friend lists are private, this request has to be made on behalf of the player whose
+/// friend list should be retrieved.
+#[derive(Debug, Clone)]
+pub struct FriendListRequest {
+    /// The base request data
+    pub base: BaseRequest,
+
+    /// The account whose friend list should be retrieved
+    ///
+    /// ## GD Internals:
+    /// This value is split into the `accountID` and `gjp` fields in the boomlings API
+    pub authentication: Authentication,
+}
+
+impl FriendListRequest {
+    const_setter!(with_base, base, BaseRequest);
+
+    pub fn new(authentication: Authentication) -> FriendListRequest {
+        FriendListRequest {
+            base: GD_21,
+            authentication,
+        }
+    }
+}
+
+impl Into<FriendListRequest> for Authentication {
+    fn into(self) -> FriendListRequest {
+        FriendListRequest::new(self)
+    }
+}
+
+impl Display for FriendListRequest {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), Error> {
+        write!(f, "FriendListRequest({})", self.authentication.account_id)
+    }
+}
+
+/// Manual `Hash` impl that doesn't hash `base`.
+impl Hash for FriendListRequest {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.authentication.hash(state)
+    }
+}
+
+eq_by_hashed_fields!(FriendListRequest: authentication);
+
+impl Request for FriendListRequest {
+    type Result = Vec<ListedUser>;
+}
+
+/// Struct modelled after a request to `getGJUserList20.php` retrieving the users a player has
+/// blocked.
+///
+/// This request has to be made on behalf of the player whose blocked users should be retrieved.
+#[derive(Debug, Clone)]
+pub struct BlockedUsersRequest {
+    /// The base request data
+    pub base: BaseRequest,
+
+    /// The account whose blocked users should be retrieved
+    ///
+    /// ## GD Internals:
+    /// This value is split into the `accountID` and `gjp` fields in the boomlings API
+    pub authentication: Authentication,
+}
+
+impl BlockedUsersRequest {
+    const_setter!(with_base, base, BaseRequest);
+
+    pub fn new(authentication: Authentication) -> BlockedUsersRequest {
+        BlockedUsersRequest {
+            base: GD_21,
+            authentication,
+        }
+    }
+}
+
+impl Into<BlockedUsersRequest> for Authentication {
+    fn into(self) -> BlockedUsersRequest {
+        BlockedUsersRequest::new(self)
+    }
+}
+
+impl Display for BlockedUsersRequest {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), Error> {
+        write!(f, "BlockedUsersRequest({})", self.authentication.account_id)
+    }
+}
+
+/// Manual `Hash` impl that doesn't hash `base`.
+impl Hash for BlockedUsersRequest {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.authentication.hash(state)
+    }
+}
+
+eq_by_hashed_fields!(BlockedUsersRequest: authentication);
+
+impl Request for BlockedUsersRequest {
+    type Result = Vec<ListedUser>;
+}
+
+/// Struct modelled after a request to `getGJFriendRequests20.php`.
+///
+/// In the Geometry Dash API, this endpoint is used to retrieve the friend requests a player has
+/// received (or sent). It has to be made on behalf of that player.
+#[derive(Debug, Clone)]
+pub struct FriendRequestsRequest {
+    /// The base request data
+    pub base: BaseRequest,
+
+    /// The account whose friend requests should be retrieved
+    ///
+    /// ## GD Internals:
+    /// This value is split into the `accountID` and `gjp` fields in the boomlings API
+    pub authentication: Authentication,
+
+    /// The page of friend requests to retrieve. The first page is page `0`
+    ///
+    /// ## GD Internals:
+    /// This field is called `page` in the boomlings API
+    pub page: u32,
+
+    /// Unknown, probably related to pagination
+    ///
+    /// ## GD Internals:
+    /// This field is called `total` in the boomlings API
+    pub total: u32,
+
+    /// Whether to retrieve the friend requests sent by the player instead of the ones they
+    /// received
+    ///
+    /// ## GD Internals:
+    /// This field is called `getSent` in the boomlings API and needs to be converted to an
+    /// integer
+    pub sent: bool,
+}
+
+impl FriendRequestsRequest {
+    const_setter!(with_base, base, BaseRequest);
+
+    const_setter!(page: u32);
+
+    const_setter!(total: u32);
+
+    /// Constructs a new `FriendRequestsRequest` retrieving the first page of friend requests
+    /// received by the given account
+    pub fn received(authentication: Authentication) -> FriendRequestsRequest {
+        FriendRequestsRequest {
+            base: GD_21,
+            authentication,
+            page: 0,
+            total: 0,
+            sent: false,
+        }
+    }
+
+    /// Constructs a new `FriendRequestsRequest` retrieving the first page of friend requests sent
+    /// by the given account
+    pub fn sent(authentication: Authentication) -> FriendRequestsRequest {
+        FriendRequestsRequest {
+            sent: true,
+            ..FriendRequestsRequest::received(authentication)
+        }
+    }
+}
+
+impl Into<FriendRequestsRequest> for Authentication {
+    fn into(self) -> FriendRequestsRequest {
+        FriendRequestsRequest::received(self)
+    }
+}
+
+impl Display for FriendRequestsRequest {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), Error> {
+        if self.sent {
+            write!(
+                f,
+                "FriendRequestsRequest(sent by {}, page {})",
+                self.authentication.account_id, self.page
+            )
+        } else {
+            write!(
+                f,
+                "FriendRequestsRequest(received by {}, page {})",
+                self.authentication.account_id, self.page
+            )
+        }
+    }
+}
+
+/// Manual `Hash` impl that doesn't hash `base`.
+impl Hash for FriendRequestsRequest {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.authentication.hash(state);
+        self.page.hash(state);
+        self.total.hash(state);
+        self.sent.hash(state);
+    }
+}
+
+eq_by_hashed_fields!(FriendRequestsRequest: authentication, page, total, sent);
+
+impl Request for FriendRequestsRequest {
+    type Result = Vec<FriendRequest>;
+}
+
+impl PaginatableRequest for FriendRequestsRequest {
+    fn next(&mut self) {
+        self.page += 1;
+    }
+}
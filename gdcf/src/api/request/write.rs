@@ -0,0 +1,514 @@
+//! Module containing request definitions for requests that modify data on the Geometry Dash
+//! servers, like uploading levels or posting comments.
+//!
+//! All of these have to be made on behalf of some account. Unlike all other requests, their results
+//! are never cached. Instead, a successful write invalidates (or updates) the cache entries it
+//! affects.
+
+use crate::api::request::{Authentication, BaseRequest, Request, GD_21};
+use gdcf_model::level::{LevelLength, Password};
+use std::{
+    fmt::{Display, Formatter},
+    hash::{Hash, Hasher},
+};
+
+/// Struct modelled after a request to `uploadGJLevel21.php`.
+///
+/// In the Geometry Dash API, this endpoint is used to upload new levels, or to update levels that
+/// were previously uploaded by the same account. The result of this request is the ID of the
+/// uploaded level.
+#[derive(Debug, Clone)]
+pub struct UploadLevelRequest {
+    /// The base request data
+    pub base: BaseRequest,
+
+    /// The account the level should be uploaded by
+    ///
+    /// ## GD Internals:
+    /// This value is split into the `accountID` and `gjp` fields in the boomlings API
+    pub authentication: Authentication,
+
+    /// The name of the account the level should be uploaded by
+    ///
+    /// ## GD Internals:
+    /// This field is called `userName` in the boomlings API
+    pub user_name: String,
+
+    /// The ID of the level to update, or `0` if a new level should be uploaded
+    ///
+    /// ## GD Internals:
+    /// This field is called `levelID` in the boomlings API
+    pub level_id: u64,
+
+    /// The name of the level
+    ///
+    /// ## GD Internals:
+    /// This field is called `levelName` in the boomlings API
+    pub name: String,
+
+    /// The description of the level, if any
+    ///
+    /// ## GD Internals:
+    /// This field is called `levelDesc` in the boomlings API and needs to be urlsafe base64
+    /// encoded
+    pub description: Option<String>,
+
+    /// The version of the level. Should be incremented each time a level is updated
+    ///
+    /// ## GD Internals:
+    /// This field is called `levelVersion` in the boomlings API
+    pub version: u32,
+
+    /// The length of the level
+    ///
+    /// ## GD Internals:
+    /// This field is called `levelLength` in the boomlings API
+    pub length: LevelLength,
+
+    /// The index of the main song the level uses. Ignored if `custom_song` is set.
+    ///
+    /// ## GD Internals:
+    /// This field is called `audioTrack` in the boomlings API
+    pub main_song: u8,
+
+    /// The ID of the newgrounds song the level uses, if any
+    ///
+    /// ## GD Internals:
+    /// This field is called `songID` in the boomlings API. A value of `0` means no custom song is
+    /// used
+    pub custom_song: Option<u64>,
+
+    /// The copy protection of the level
+    ///
+    /// ## GD Internals:
+    /// This field is called `password` in the boomlings API. `0` means the level cannot be copied,
+    /// `1` means it can be copied freely, and any other value is the password required to copy it.
+    pub password: Password,
+
+    /// The ID of the level this level is a copy of, if any
+    ///
+    /// ## GD Internals:
+    /// This field is called `original` in the boomlings API
+    pub copy_of: Option<u64>,
+
+    /// Whether the level is a two player level
+    ///
+    /// ## GD Internals:
+    /// This field is called `twoPlayer` in the boomlings API and needs to be converted to an
+    /// integer
+    pub two_player: bool,
+
+    /// The amount of objects in the level
+    ///
+    /// ## GD Internals:
+    /// This field is called `objects` in the boomlings API
+    pub object_amount: u32,
+
+    /// The amount of user coins in the level
+    ///
+    /// ## GD Internals:
+    /// This field is called `coins` in the boomlings API
+    pub coin_amount: u8,
+
+    /// The amount of stars requested for the level
+    ///
+    /// ## GD Internals:
+    /// This field is called `requestedStars` in the boomlings API
+    pub requested_stars: u8,
+
+    /// Whether the level should be unlisted
+    ///
+    /// ## GD Internals:
+    /// This field is called `unlisted` in the boomlings API and needs to be converted to an
+    /// integer
+    pub unlisted: bool,
+
+    /// The level data, in the compressed and encoded form the servers store it in
+    ///
+    /// ## GD Internals:
+    /// This field is called `levelString` in the boomlings API. A checksum over it has to be sent
+    /// alongside it in the `seed2` field.
+    pub level_data: String,
+}
+
+impl UploadLevelRequest {
+    const_setter!(with_base, base, BaseRequest);
+
+    const_setter!(level_id: u64);
+
+    setter!(description: Option<String>);
+
+    const_setter!(version: u32);
+
+    const_setter!(length: LevelLength);
+
+    const_setter!(main_song: u8);
+
+    const_setter!(custom_song: Option<u64>);
+
+    setter!(password: Password);
+
+    const_setter!(copy_of: Option<u64>);
+
+    const_setter!(two_player: bool);
+
+    const_setter!(object_amount: u32);
+
+    const_setter!(coin_amount: u8);
+
+    const_setter!(requested_stars: u8);
+
+    const_setter!(unlisted: bool);
+
+    /// Constructs a new `UploadLevelRequest` uploading a new level with the given name and data on
+    /// behalf of the given account
+    pub fn new(authentication: Authentication, user_name: String, name: String, level_data: String) -> UploadLevelRequest {
+        UploadLevelRequest {
+            base: GD_21,
+            authentication,
+            user_name,
+            level_id: 0,
+            name,
+            description: None,
+            version: 1,
+            length: LevelLength::Tiny,
+            main_song: 0,
+            custom_song: None,
+            password: Password::NoCopy,
+            copy_of: None,
+            two_player: false,
+            object_amount: 0,
+            coin_amount: 0,
+            requested_stars: 0,
+            unlisted: false,
+            level_data,
+        }
+    }
+}
+
+impl Display for UploadLevelRequest {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "UploadLevelRequest({}, {})", self.level_id, self.name)
+    }
+}
+
+/// Manual `Hash` impl that doesn't hash `base`.
+impl Hash for UploadLevelRequest {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.authentication.hash(state);
+        self.level_id.hash(state);
+        self.name.hash(state);
+        self.version.hash(state);
+        self.level_data.hash(state);
+    }
+}
+
+impl Request for UploadLevelRequest {
+    type Result = u64;
+}
+
+/// Struct modelled after a request to `uploadGJComment21.php`.
+///
+/// In the Geometry Dash API, this endpoint is used to post a comment on a level. The result of
+/// this request is the ID of the new comment.
+#[derive(Debug, Clone)]
+pub struct UploadLevelCommentRequest {
+    /// The base request data
+    pub base: BaseRequest,
+
+    /// The account the comment should be posted by
+    ///
+    /// ## GD Internals:
+    /// This value is split into the `accountID` and `gjp` fields in the boomlings API
+    pub authentication: Authentication,
+
+    /// The name of the account the comment should be posted by
+    ///
+    /// ## GD Internals:
+    /// This field is called `userName` in the boomlings API
+    pub user_name: String,
+
+    /// The ID of the level to comment on
+    ///
+    /// ## GD Internals:
+    /// This field is called `levelID` in the boomlings API
+    pub level_id: u64,
+
+    /// The content of the comment
+    ///
+    /// ## GD Internals:
+    /// This field is called `comment` in the boomlings API and needs to be urlsafe base64 encoded
+    pub content: String,
+
+    /// The progress on the level to display alongside the comment, if any
+    ///
+    /// ## GD Internals:
+    /// This field is called `percent` in the boomlings API
+    pub progress: Option<u8>,
+}
+
+impl UploadLevelCommentRequest {
+    const_setter!(with_base, base, BaseRequest);
+
+    const_setter!(progress: Option<u8>);
+
+    /// Constructs a new `UploadLevelCommentRequest` posting the given comment on the given level on
+    /// behalf of the given account
+    pub fn new(authentication: Authentication, user_name: String, level_id: u64, content: String) -> UploadLevelCommentRequest {
+        UploadLevelCommentRequest {
+            base: GD_21,
+            authentication,
+            user_name,
+            level_id,
+            content,
+            progress: None,
+        }
+    }
+}
+
+/// Manual `Display` impl that only prints the length of the comment, as requests are logged
+impl Display for UploadLevelCommentRequest {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "UploadLevelCommentRequest({}, {} characters)",
+            self.level_id,
+            self.content.chars().count()
+        )
+    }
+}
+
+/// Manual `Hash` impl that doesn't hash `base`.
+impl Hash for UploadLevelCommentRequest {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.authentication.hash(state);
+        self.level_id.hash(state);
+        self.content.hash(state);
+        self.progress.hash(state);
+    }
+}
+
+impl Request for UploadLevelCommentRequest {
+    type Result = u64;
+}
+
+/// Struct modelled after a request to `uploadGJAccComment20.php`.
+///
+/// In the Geometry Dash API, this endpoint is used to post a comment on the profile of the account
+/// making the request. The result of this request is the ID of the new comment.
+#[derive(Debug, Clone)]
+pub struct UploadProfileCommentRequest {
+    /// The base request data
+    pub base: BaseRequest,
+
+    /// The account the comment should be posted by
+    ///
+    /// ## GD Internals:
+    /// This value is split into the `accountID` and `gjp` fields in the boomlings API
+    pub authentication: Authentication,
+
+    /// The name of the account the comment should be posted by
+    ///
+    /// ## GD Internals:
+    /// This field is called `userName` in the boomlings API
+    pub user_name: String,
+
+    /// The content of the comment
+    ///
+    /// ## GD Internals:
+    /// This field is called `comment` in the boomlings API and needs to be urlsafe base64 encoded
+    pub content: String,
+}
+
+impl UploadProfileCommentRequest {
+    const_setter!(with_base, base, BaseRequest);
+
+    /// Constructs a new `UploadProfileCommentRequest` posting the given comment on the profile of
+    /// the given account
+    pub fn new(authentication: Authentication, user_name: String, content: String) -> UploadProfileCommentRequest {
+        UploadProfileCommentRequest {
+            base: GD_21,
+            authentication,
+            user_name,
+            content,
+        }
+    }
+}
+
+/// Manual `Display` impl that only prints the length of the comment, as requests are logged
+impl Display for UploadProfileCommentRequest {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "UploadProfileCommentRequest({}, {} characters)",
+            self.authentication.account_id,
+            self.content.chars().count()
+        )
+    }
+}
+
+/// Manual `Hash` impl that doesn't hash `base`.
+impl Hash for UploadProfileCommentRequest {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.authentication.hash(state);
+        self.content.hash(state);
+    }
+}
+
+impl Request for UploadProfileCommentRequest {
+    type Result = u64;
+}
+
+/// The different things that can be liked or disliked
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LikeTarget {
+    /// The level with the given ID
+    ///
+    /// ## GD Internals:
+    /// This variant is represented by the value `1` in the `type` field of the boomlings API
+    Level(u64),
+
+    /// A comment on a level
+    ///
+    /// ## GD Internals:
+    /// This variant is represented by the value `2` in the `type` field of the boomlings API. The
+    /// level ID is provided in the `special` field.
+    LevelComment { level_id: u64, comment_id: u64 },
+
+    /// The profile comment with the given ID
+    ///
+    /// ## GD Internals:
+    /// This variant is represented by the value `3` in the `type` field of the boomlings API
+    ProfileComment(u64),
+}
+
+/// Struct modelled after a request to `likeGJItem211.php`.
+///
+/// In the Geometry Dash API, this endpoint is used to like or dislike levels and comments. The
+/// servers do not provide any result beyond success or failure.
+#[derive(Debug, Clone)]
+pub struct LikeRequest {
+    /// The base request data
+    pub base: BaseRequest,
+
+    /// The account making the like
+    ///
+    /// ## GD Internals:
+    /// This value is split into the `accountID` and `gjp` fields in the boomlings API
+    pub authentication: Authentication,
+
+    /// The thing to like or dislike
+    ///
+    /// ## GD Internals:
+    /// This value is split into the `itemID`, `type` and `special` fields in the boomlings API
+    pub target: LikeTarget,
+
+    /// Whether this is a like (as opposed to a dislike)
+    ///
+    /// ## GD Internals:
+    /// This field is called `like` in the boomlings API and needs to be converted to an integer
+    pub like: bool,
+}
+
+impl LikeRequest {
+    const_setter!(with_base, base, BaseRequest);
+
+    /// Constructs a new `LikeRequest` liking the given target on behalf of the given account
+    pub fn like(authentication: Authentication, target: LikeTarget) -> LikeRequest {
+        LikeRequest {
+            base: GD_21,
+            authentication,
+            target,
+            like: true,
+        }
+    }
+
+    /// Constructs a new `LikeRequest` disliking the given target on behalf of the given account
+    pub fn dislike(authentication: Authentication, target: LikeTarget) -> LikeRequest {
+        LikeRequest {
+            like: false,
+            ..LikeRequest::like(authentication, target)
+        }
+    }
+}
+
+impl Display for LikeRequest {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "LikeRequest({:?}, like: {})", self.target, self.like)
+    }
+}
+
+/// Manual `Hash` impl that doesn't hash `base`.
+impl Hash for LikeRequest {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.authentication.hash(state);
+        self.target.hash(state);
+        self.like.hash(state);
+    }
+}
+
+impl Request for LikeRequest {
+    type Result = ();
+}
+
+/// Struct modelled after a request to `rateGJStars211.php`.
+///
+/// In the Geometry Dash API, this endpoint is used to suggest a star rating for a level. The
+/// servers do not provide any result beyond success or failure.
+#[derive(Debug, Clone)]
+pub struct RateStarsRequest {
+    /// The base request data
+    pub base: BaseRequest,
+
+    /// The account making the rating
+    ///
+    /// ## GD Internals:
+    /// This value is split into the `accountID` and `gjp` fields in the boomlings API
+    pub authentication: Authentication,
+
+    /// The ID of the level to rate
+    ///
+    /// ## GD Internals:
+    /// This field is called `levelID` in the boomlings API
+    pub level_id: u64,
+
+    /// The amount of stars to suggest
+    ///
+    /// ## GD Internals:
+    /// This field is called `stars` in the boomlings API
+    pub stars: u8,
+}
+
+impl RateStarsRequest {
+    const_setter!(with_base, base, BaseRequest);
+
+    /// Constructs a new `RateStarsRequest` suggesting the given amount of stars for the given level
+    /// on behalf of the given account
+    pub fn new(authentication: Authentication, level_id: u64, stars: u8) -> RateStarsRequest {
+        RateStarsRequest {
+            base: GD_21,
+            authentication,
+            level_id,
+            stars,
+        }
+    }
+}
+
+impl Display for RateStarsRequest {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "RateStarsRequest({}, {} stars)", self.level_id, self.stars)
+    }
+}
+
+/// Manual `Hash` impl that doesn't hash `base`.
+impl Hash for RateStarsRequest {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.authentication.hash(state);
+        self.level_id.hash(state);
+        self.stars.hash(state);
+    }
+}
+
+impl Request for RateStarsRequest {
+    type Result = ();
+}
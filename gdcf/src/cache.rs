@@ -1,21 +1,47 @@
 //! Module containing cache related traits/structs
 
-use crate::{api::request::Request, error::CacheError};
+use crate::{
+    api::request::{
+        BlockedUsersRequest, DailyLevelRequest, FriendListRequest, FriendRequestsRequest, GauntletsRequest, LeaderboardRequest,
+        LevelCommentsRequest, LevelRequest, LevelScoresRequest, LevelsRequest, MapPacksRequest, MessageRequest, MessagesRequest,
+        ProfileCommentsRequest, Request, SongInfoRequest, UserRequest, UserSearchRequest,
+    },
+    error::CacheError,
+    Secondary,
+};
 use derive_more::Display;
-use gdcf_model::{song::NewgroundsSong, user::Creator};
+use gdcf_model::{comment::CommentUser, song::NewgroundsSong, user::Creator};
+use log::warn;
 use std::fmt::{Display, Formatter};
 
+pub use self::{
+    daily::{refresh_seconds_left, special_level_expiry},
+    invalidate::InvalidateObjects,
+    path::KeyPath,
+    tiered::{TieredCache, TieredEntryMeta, TieredError, WriteMode},
+};
+
+mod daily;
+mod invalidate;
+pub mod path;
+mod tiered;
+
 pub trait Cache: Clone + Send + Sync + 'static {
     type CacheEntryMeta: CacheEntryMeta;
     type Err: CacheError;
 }
 
-#[derive(Debug, Display)]
+#[derive(Debug, Display, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct NewgroundsSongKey(pub u64);
 
-#[derive(Debug, Display)]
+#[derive(Debug, Display, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct CreatorKey(pub u64);
 
+/// Key for the [`CommentUser`] data of the player with the given user id, as provided alongside
+/// their level comments
+#[derive(Debug, Display, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CommentUserKey(pub u64);
+
 pub trait Key {
     type Result;
 }
@@ -32,14 +58,124 @@ impl Key for CreatorKey {
     type Result = Creator;
 }
 
+impl Key for CommentUserKey {
+    type Result = CommentUser;
+}
+
 pub trait Lookup<K: Key>: Cache {
     // TODO: maybe an exists method?
     fn lookup(&self, key: &K) -> Result<CacheEntry<K::Result, Self::CacheEntryMeta>, Self::Err>;
+
+    /// Looks up the raw response body the entry with the given key was stored from via
+    /// [`Store::store_raw`], if this cache keeps it around
+    ///
+    /// Caches that only store parsed objects don't need to override this method.
+    fn lookup_raw(&self, _key: &K) -> Result<Option<String>, Self::Err> {
+        Ok(None)
+    }
 }
 
 pub trait Store<K: Key>: Cache {
     fn store(&mut self, obj: &K::Result, key: &K) -> Result<Self::CacheEntryMeta, Self::Err>;
     fn mark_absent(&mut self, key: &K) -> Result<Self::CacheEntryMeta, Self::Err>;
+
+    /// Stores the given object, which was parsed from the given raw response body of the servers
+    ///
+    /// This is how GDCF stores the results of requests it made. Caches that would rather keep the
+    /// data exactly as the servers provided it can override this method, all others simply store
+    /// the parsed object.
+    fn store_raw(&mut self, obj: &K::Result, _body: &str, key: &K) -> Result<Self::CacheEntryMeta, Self::Err> {
+        self.store(obj, key)
+    }
+}
+
+/// Trait for caches that need to react to successful requests modifying data on the servers (like
+/// [`UploadLevelCommentRequest`](crate::api::request::UploadLevelCommentRequest)s), by updating or
+/// invalidating the cache entries affected by the request.
+///
+/// Invalidated entries should be treated as expired by future lookups.
+///
+/// This trait is implemented for all caches implementing [`InvalidateObjects`], so there's no need
+/// to implement it manually.
+pub trait Invalidate<R: Request>: Cache {
+    fn invalidate(&mut self, request: &R, result: &R::Result) -> Result<(), Self::Err>;
+}
+
+/// Trait for caches that can store the [`Secondary`] objects the servers provide alongside the
+/// results of requests of type `R`
+///
+/// This trait is implemented for all caches that can store the kinds of secondary objects a request
+/// might yield, so there's no need to implement it manually. Most requests only ever yield
+/// [`Creator`]s and [`NewgroundsSong`]s, however only [`LevelCommentsRequest`]s yield
+/// [`CommentUser`]s, meaning only caches used to retrieve level comments have to be able to store
+/// those.
+pub trait StoreSecondary<R: Request>: Cache {
+    fn store_secondary(&mut self, secondary: &Secondary) -> Result<(), Self::Err>;
+}
+
+impl<C> StoreSecondary<LevelCommentsRequest> for C
+where
+    C: Cache + Store<CreatorKey> + Store<NewgroundsSongKey> + Store<CommentUserKey>,
+{
+    fn store_secondary(&mut self, secondary: &Secondary) -> Result<(), Self::Err> {
+        match secondary {
+            Secondary::CommentUser(uid, user) => self.store(user, &CommentUserKey(*uid)).map(|_| ()),
+            Secondary::MissingCommentUser(uid) => Store::<CommentUserKey>::mark_absent(self, &CommentUserKey(*uid)).map(|_| ()),
+            _ => store_common_secondary(self, secondary),
+        }
+    }
+}
+
+macro_rules! store_common_secondary {
+    ($($request: ty),*) => {
+        $(
+            impl<C> StoreSecondary<$request> for C
+            where
+                C: Cache + Store<CreatorKey> + Store<NewgroundsSongKey>,
+            {
+                fn store_secondary(&mut self, secondary: &Secondary) -> Result<(), Self::Err> {
+                    store_common_secondary(self, secondary)
+                }
+            }
+        )*
+    };
+}
+
+store_common_secondary!(
+    LevelRequest,
+    LevelsRequest,
+    LevelScoresRequest,
+    DailyLevelRequest,
+    SongInfoRequest,
+    UserRequest,
+    UserSearchRequest,
+    ProfileCommentsRequest,
+    MapPacksRequest,
+    GauntletsRequest,
+    MessagesRequest,
+    MessageRequest,
+    FriendListRequest,
+    BlockedUsersRequest,
+    FriendRequestsRequest,
+    LeaderboardRequest
+);
+
+fn store_common_secondary<C>(cache: &mut C, secondary: &Secondary) -> Result<(), C::Err>
+where
+    C: Cache + Store<CreatorKey> + Store<NewgroundsSongKey>,
+{
+    match secondary {
+        Secondary::NewgroundsSong(song) => cache.store(song, &NewgroundsSongKey(song.song_id)),
+        Secondary::Creator(creator) => cache.store(creator, &CreatorKey(creator.user_id)),
+        Secondary::MissingCreator(cid) => Store::<CreatorKey>::mark_absent(cache, &CreatorKey(*cid)),
+        Secondary::MissingNewgroundsSong(nid) => Store::<NewgroundsSongKey>::mark_absent(cache, &NewgroundsSongKey(*nid)),
+        Secondary::CommentUser(..) | Secondary::MissingCommentUser(_) => {
+            warn!("Ignoring unexpected secondary object {}", secondary);
+
+            return Ok(())
+        },
+    }
+    .map(|_| ())
 }
 
 // FIXME: One they are stabilized, use a trait alias here
@@ -0,0 +1,46 @@
+use chrono::{Duration, NaiveDateTime, Utc};
+use gdcf_model::level::DailyLevel;
+
+/// Gets the point in time at which the daily level (or weekly demon) described by the given
+/// [`DailyLevel`], which was cached at the given time, is replaced by the next one
+fn rotation(daily: &DailyLevel, cached_at: NaiveDateTime) -> NaiveDateTime {
+    cached_at + Duration::seconds(daily.seconds_left.into())
+}
+
+/// Updates the time left until the given [`DailyLevel`], which was cached at the given time, is
+/// replaced by the next one, since the amount of seconds stored is relative to the time the
+/// information was cached
+///
+/// Returns whether the daily level has already been replaced, in which case the cached information
+/// is outdated. The daily level doesn't expire after a fixed amount of time, but when the next
+/// daily level replaces it, so caches should use this instead of their configured expiry.
+pub fn refresh_seconds_left(daily: &mut DailyLevel, cached_at: NaiveDateTime) -> bool {
+    let seconds_left = (rotation(daily, cached_at) - Utc::now().naive_utc()).num_seconds();
+
+    daily.seconds_left = seconds_left.max(0) as u32;
+
+    seconds_left <= 0
+}
+
+/// Determines when a level cached at `level_cached_at` via the [`LevelRequest`] for a
+/// [`SpecialLevel`] expires, based on the [`DailyLevel`] cached at `daily_cached_at`
+///
+/// The level expires once the next daily level replaces it. If it was cached before the
+/// information about the daily level, it might have been replaced in between, so it is considered
+/// to have expired when that information was cached. If it was only cached after the daily level
+/// was replaced, the information about the daily level is outdated and [`None`] is returned. In
+/// that case, the level should expire like any other level.
+///
+/// [`LevelRequest`]: crate::api::request::LevelRequest
+/// [`SpecialLevel`]: crate::api::request::SpecialLevel
+pub fn special_level_expiry(daily: &DailyLevel, daily_cached_at: NaiveDateTime, level_cached_at: NaiveDateTime) -> Option<NaiveDateTime> {
+    let rotation = rotation(daily, daily_cached_at);
+
+    if level_cached_at < daily_cached_at {
+        Some(daily_cached_at)
+    } else if level_cached_at < rotation {
+        Some(rotation)
+    } else {
+        None
+    }
+}
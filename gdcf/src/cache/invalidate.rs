@@ -0,0 +1,72 @@
+use crate::{
+    api::request::{LikeRequest, LikeTarget, RateStarsRequest, UploadLevelCommentRequest, UploadLevelRequest, UploadProfileCommentRequest},
+    cache::{Cache, Invalidate},
+};
+use log::debug;
+
+/// Trait for caches that can invalidate all entries containing a specific object
+///
+/// Which entries are affected by which request modifying data on the servers is the same for every
+/// cache, so implementing this trait implements [`Invalidate`] for all such requests. Caches that
+/// don't store some kind of object can simply do nothing in the corresponding method.
+pub trait InvalidateObjects: Cache {
+    /// Invalidates the cached data of the level with the given ID. Cached level lists are left
+    /// alone.
+    fn invalidate_level(&mut self, level_id: u64) -> Result<(), Self::Err>;
+
+    /// Invalidates all cached pages of comments on the level with the given ID
+    fn invalidate_level_comments(&mut self, level_id: u64) -> Result<(), Self::Err>;
+
+    /// Invalidates all cached pages of comments on the profile of the account with the given ID
+    fn invalidate_profile_comments(&mut self, account_id: u64) -> Result<(), Self::Err>;
+}
+
+impl<C: InvalidateObjects> Invalidate<UploadLevelRequest> for C {
+    fn invalidate(&mut self, request: &UploadLevelRequest, level_id: &u64) -> Result<(), Self::Err> {
+        // Newly uploaded levels cannot be cached yet. We cannot know which cached level lists the
+        // level would show up in, so those are left alone
+        if request.level_id != 0 {
+            debug!("Invalidating level {} after it was updated", level_id);
+
+            self.invalidate_level(*level_id)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<C: InvalidateObjects> Invalidate<UploadLevelCommentRequest> for C {
+    fn invalidate(&mut self, request: &UploadLevelCommentRequest, _: &u64) -> Result<(), Self::Err> {
+        debug!("Invalidating comments on level {} after a comment was posted", request.level_id);
+
+        self.invalidate_level_comments(request.level_id)
+    }
+}
+
+impl<C: InvalidateObjects> Invalidate<UploadProfileCommentRequest> for C {
+    fn invalidate(&mut self, request: &UploadProfileCommentRequest, _: &u64) -> Result<(), Self::Err> {
+        let account_id = request.authentication.account_id;
+
+        debug!("Invalidating comments on profile {} after a comment was posted", account_id);
+
+        self.invalidate_profile_comments(account_id)
+    }
+}
+
+impl<C: InvalidateObjects> Invalidate<LikeRequest> for C {
+    fn invalidate(&mut self, request: &LikeRequest, _: &()) -> Result<(), Self::Err> {
+        match request.target {
+            LikeTarget::Level(level_id) => self.invalidate_level(level_id),
+            LikeTarget::LevelComment { level_id, .. } => self.invalidate_level_comments(level_id),
+            // We don't know which profile the comment is on, so we cannot invalidate anything
+            LikeTarget::ProfileComment(_) => Ok(()),
+        }
+    }
+}
+
+// Star suggestions do not have any visible effect on the rated level
+impl<C: InvalidateObjects> Invalidate<RateStarsRequest> for C {
+    fn invalidate(&mut self, _: &RateStarsRequest, _: &()) -> Result<(), Self::Err> {
+        Ok(())
+    }
+}
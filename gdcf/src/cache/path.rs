@@ -0,0 +1,151 @@
+//! Module containing the hierarchical layout used by caches that store their entries under string
+//! keys (like files in a directory, or entries on a redis server)
+
+use crate::{
+    api::request::{
+        BlockedUsersRequest, DailyLevelRequest, FriendListRequest, FriendRequestsRequest, GauntletsRequest, LeaderboardRequest,
+        LevelCommentsRequest, LevelRequest, LevelScoresRequest, LevelsRequest, MapPacksRequest, MessageRequest, MessagesRequest,
+        ProfileCommentsRequest, SongInfoRequest, UserRequest, UserSearchRequest,
+    },
+    cache::{CommentUserKey, CreatorKey, Key, NewgroundsSongKey},
+};
+use std::{
+    collections::hash_map::DefaultHasher,
+    hash::{Hash, Hasher},
+};
+
+/// Trait for keys that can be mapped to a path of segments, e.g. `["level", "1234"]`
+///
+/// Caches join the segments using whatever separator fits their storage. Entries that are
+/// invalidated together share all but their last segment, see [`level_comments`] and
+/// [`profile_comments`].
+pub trait KeyPath: Key {
+    fn key_path(&self) -> Vec<String>;
+}
+
+/// The path shared by all cached pages of comments on the level with the given ID
+pub fn level_comments(level_id: u64) -> Vec<String> {
+    vec!["level_comments".to_string(), level_id.to_string()]
+}
+
+/// The path shared by all cached pages of comments on the profile of the account with the given ID
+pub fn profile_comments(account_id: u64) -> Vec<String> {
+    vec!["profile_comments".to_string(), account_id.to_string()]
+}
+
+fn hash_of(key: &impl Hash) -> String {
+    let mut state = DefaultHasher::new();
+
+    key.hash(&mut state);
+
+    format!("{:016x}", state.finish())
+}
+
+/// Appends the hash of the given key to the given path
+fn hashed(mut path: Vec<String>, key: &impl Hash) -> Vec<String> {
+    path.push(hash_of(key));
+    path
+}
+
+impl KeyPath for LevelRequest {
+    fn key_path(&self) -> Vec<String> {
+        let level_id = match self.special {
+            Some(special) => i64::from(special).to_string(),
+            None => self.level_id.to_string(),
+        };
+
+        vec!["level".to_string(), level_id]
+    }
+}
+
+impl KeyPath for DailyLevelRequest {
+    fn key_path(&self) -> Vec<String> {
+        vec![if self.weekly { "weekly" } else { "daily" }.to_string()]
+    }
+}
+
+impl KeyPath for NewgroundsSongKey {
+    fn key_path(&self) -> Vec<String> {
+        vec!["song".to_string(), self.0.to_string()]
+    }
+}
+
+// A `SongInfoRequest` retrieves exactly the song identified by its `NewgroundsSongKey`, so both
+// share their entries
+impl KeyPath for SongInfoRequest {
+    fn key_path(&self) -> Vec<String> {
+        NewgroundsSongKey(self.song_id).key_path()
+    }
+}
+
+impl KeyPath for CreatorKey {
+    fn key_path(&self) -> Vec<String> {
+        vec!["creator".to_string(), self.0.to_string()]
+    }
+}
+
+impl KeyPath for CommentUserKey {
+    fn key_path(&self) -> Vec<String> {
+        vec!["comment_user".to_string(), self.0.to_string()]
+    }
+}
+
+impl KeyPath for UserRequest {
+    fn key_path(&self) -> Vec<String> {
+        vec!["user".to_string(), self.user.to_string()]
+    }
+}
+
+// Comment lists are grouped by the level/profile they belong to, so that all of them can be
+// invalidated at once when a new comment is posted
+
+impl KeyPath for LevelCommentsRequest {
+    fn key_path(&self) -> Vec<String> {
+        hashed(level_comments(self.level_id), self)
+    }
+}
+
+impl KeyPath for ProfileCommentsRequest {
+    fn key_path(&self) -> Vec<String> {
+        hashed(profile_comments(self.account_id), self)
+    }
+}
+
+// Messages can only be read by their sender and their recipient, so they are grouped by the account
+// that retrieved them
+
+impl KeyPath for MessagesRequest {
+    fn key_path(&self) -> Vec<String> {
+        hashed(vec!["messages".to_string(), self.authentication.account_id.to_string()], self)
+    }
+}
+
+impl KeyPath for MessageRequest {
+    fn key_path(&self) -> Vec<String> {
+        hashed(vec!["message".to_string(), self.authentication.account_id.to_string()], self)
+    }
+}
+
+macro_rules! hashed_path {
+    ($($key_type: ty => $kind: expr),*) => {
+        $(
+            impl KeyPath for $key_type {
+                fn key_path(&self) -> Vec<String> {
+                    hashed(vec![$kind.to_string()], self)
+                }
+            }
+        )*
+    };
+}
+
+hashed_path!(
+    LevelsRequest => "levels",
+    LevelScoresRequest => "level_scores",
+    MapPacksRequest => "map_packs",
+    GauntletsRequest => "gauntlets",
+    LeaderboardRequest => "leaderboard",
+    UserSearchRequest => "user_search",
+    FriendListRequest => "friends",
+    BlockedUsersRequest => "blocked",
+    FriendRequestsRequest => "friend_requests"
+);
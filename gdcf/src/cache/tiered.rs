@@ -0,0 +1,382 @@
+//! Module containing a cache combinator that layers a fast cache in front of a slower one
+
+use crate::{
+    cache::{Cache, CacheEntry, CacheEntryMeta, InvalidateObjects, Key, Lookup, Store},
+    error::CacheError,
+};
+use failure::Fail;
+use log::{debug, trace, warn};
+use std::{
+    fmt::{Debug, Display, Formatter},
+    sync::{Arc, Mutex, MutexGuard, PoisonError},
+};
+
+/// How writes to a [`TieredCache`] are propagated to its second tier
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WriteMode {
+    /// Every write goes to both tiers immediately. The second tier is written first, so that the
+    /// first tier never contains data the second tier doesn't know about
+    WriteThrough,
+
+    /// Writes only go to the first tier immediately, and are queued up for the second tier.
+    ///
+    /// The queue is flushed once it holds `batch_size` writes, before any cache entries are
+    /// invalidated, before a lookup falls back to the second tier, and whenever
+    /// [`TieredCache::flush`] is called. Queued writes are lost if the
+    /// cache is dropped without flushing.
+    WriteBack { batch_size: usize },
+}
+
+impl Default for WriteMode {
+    fn default() -> Self {
+        WriteMode::WriteThrough
+    }
+}
+
+/// A cache consulting a fast cache (the first tier) before falling back to a slower one (the
+/// second tier)
+///
+/// Lookups that can't be answered from the first tier are passed on to the second one. If the
+/// second tier has an up-to-date entry, that entry is promoted to the first tier. Note that
+/// promoted entries count as freshly cached in the first tier, so they can be served from there
+/// for up to the first tier's expiry after having been promoted.
+///
+/// If the second tier kept the raw response body of a promoted entry (see [`Lookup::lookup_raw`]),
+/// the entry is promoted via [`Store::store_raw`], so that caches which can only store raw
+/// response bodies work as the first tier. Promotion is an optimization only: if it fails, the
+/// error is logged and the entry is still returned from the second tier.
+///
+/// Since both tiers are [`Cache`]s themselves, this type can be nested to build caches with more
+/// than two tiers.
+#[derive(Debug, Clone)]
+pub struct TieredCache<L1, L2>
+where
+    L1: Cache,
+    L2: Cache,
+{
+    first: L1,
+    second: L2,
+    mode: WriteMode,
+    pending: PendingWrites<L2>,
+}
+
+impl<L1, L2> TieredCache<L1, L2>
+where
+    L1: Cache,
+    L2: Cache,
+{
+    /// Constructs a new [`TieredCache`] in [`WriteMode::WriteThrough`] mode
+    pub fn new(first: L1, second: L2) -> Self {
+        TieredCache {
+            first,
+            second,
+            mode: WriteMode::default(),
+            pending: PendingWrites::default(),
+        }
+    }
+
+    /// Sets the [`WriteMode`] of this cache
+    ///
+    /// Allows builder-style configuration of the cache
+    pub fn with_write_mode(mut self, mode: WriteMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    pub fn write_mode(&self) -> WriteMode {
+        self.mode
+    }
+
+    pub fn first(&self) -> &L1 {
+        &self.first
+    }
+
+    pub fn second(&self) -> &L2 {
+        &self.second
+    }
+
+    /// Gets the amount of writes that are queued up for the second tier
+    pub fn pending_writes(&self) -> usize {
+        self.pending.lock().len()
+    }
+
+    /// Writes all queued up writes to the second tier, in the order they were made
+    ///
+    /// If one of the writes fails, it is discarded and the writes after it stay queued up.
+    pub fn flush(&mut self) -> Result<(), TieredError<L1::Err, L2::Err>> {
+        self.flush_into(&mut self.second.clone())
+    }
+
+    /// Writes all queued up writes to the given handle to the second tier. This allows flushing
+    /// from methods that only have shared access to the cache.
+    fn flush_into(&self, second: &mut L2) -> Result<(), TieredError<L1::Err, L2::Err>> {
+        let pending = std::mem::take(&mut *self.pending.lock());
+
+        if pending.is_empty() {
+            return Ok(())
+        }
+
+        debug!("Flushing {} pending writes to second cache tier", pending.len());
+
+        let mut pending = pending.into_iter();
+
+        while let Some(write) = pending.next() {
+            if let Err(err) = write(second) {
+                let mut queue = self.pending.lock();
+                let newer = std::mem::replace(&mut *queue, pending.collect());
+
+                queue.extend(newer);
+
+                return Err(TieredError::Second(err))
+            }
+        }
+
+        Ok(())
+    }
+
+    fn write_back(&mut self, write: PendingWrite<L2>, batch_size: usize) -> Result<(), TieredError<L1::Err, L2::Err>> {
+        let queued = {
+            let mut queue = self.pending.lock();
+
+            queue.push(write);
+            queue.len()
+        };
+
+        if queued >= batch_size {
+            self.flush()
+        } else {
+            Ok(())
+        }
+    }
+}
+
+type PendingWrite<C> = Box<dyn FnOnce(&mut C) -> Result<(), <C as Cache>::Err> + Send>;
+
+/// Writes queued up for the second tier of a [`TieredCache`] in [`WriteMode::WriteBack`] mode,
+/// shared between all clones of the cache
+struct PendingWrites<C: Cache>(Arc<Mutex<Vec<PendingWrite<C>>>>);
+
+impl<C: Cache> PendingWrites<C> {
+    fn lock(&self) -> MutexGuard<'_, Vec<PendingWrite<C>>> {
+        // The queue is never left in an inconsistent state, since the queued up writes are only
+        // ever executed after they have been taken out of it
+        self.0.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl<C: Cache> Default for PendingWrites<C> {
+    fn default() -> Self {
+        PendingWrites(Arc::new(Mutex::new(Vec::new())))
+    }
+}
+
+impl<C: Cache> Clone for PendingWrites<C> {
+    fn clone(&self) -> Self {
+        PendingWrites(Arc::clone(&self.0))
+    }
+}
+
+impl<C: Cache> Debug for PendingWrites<C> {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "{} pending writes", self.lock().len())
+    }
+}
+
+/// Cache entry metadata of a [`TieredCache`], remembering which tier the entry came from
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TieredEntryMeta<M1, M2> {
+    First(M1),
+    Second(M2),
+}
+
+impl<M1: CacheEntryMeta, M2: CacheEntryMeta> CacheEntryMeta for TieredEntryMeta<M1, M2> {
+    fn is_expired(&self) -> bool {
+        match self {
+            TieredEntryMeta::First(meta) => meta.is_expired(),
+            TieredEntryMeta::Second(meta) => meta.is_expired(),
+        }
+    }
+
+    fn is_absent(&self) -> bool {
+        match self {
+            TieredEntryMeta::First(meta) => meta.is_absent(),
+            TieredEntryMeta::Second(meta) => meta.is_absent(),
+        }
+    }
+}
+
+impl<M1: Display, M2: Display> Display for TieredEntryMeta<M1, M2> {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            TieredEntryMeta::First(meta) => write!(f, "{} (first tier)", meta),
+            TieredEntryMeta::Second(meta) => write!(f, "{} (second tier)", meta),
+        }
+    }
+}
+
+/// Error of a [`TieredCache`], remembering which tier it occurred in
+#[derive(Debug, Fail)]
+pub enum TieredError<E1: CacheError, E2: CacheError> {
+    #[fail(display = "Error in first cache tier: {}", _0)]
+    First(#[cause] E1),
+
+    #[fail(display = "Error in second cache tier: {}", _0)]
+    Second(#[cause] E2),
+}
+
+impl<E1: CacheError, E2: CacheError> CacheError for TieredError<E1, E2> {}
+
+impl<L1, L2> Cache for TieredCache<L1, L2>
+where
+    L1: Cache,
+    L2: Cache,
+{
+    type CacheEntryMeta = TieredEntryMeta<L1::CacheEntryMeta, L2::CacheEntryMeta>;
+    type Err = TieredError<L1::Err, L2::Err>;
+}
+
+fn map_meta<T, M: CacheEntryMeta, N: CacheEntryMeta>(entry: CacheEntry<T, M>, f: impl FnOnce(M) -> N) -> CacheEntry<T, N> {
+    match entry {
+        CacheEntry::Missing => CacheEntry::Missing,
+        CacheEntry::MarkedAbsent(meta) => CacheEntry::MarkedAbsent(f(meta)),
+        CacheEntry::Cached(object, meta) => CacheEntry::Cached(object, f(meta)),
+    }
+}
+
+impl<K, L1, L2> Lookup<K> for TieredCache<L1, L2>
+where
+    K: Key,
+    L1: Lookup<K> + Store<K>,
+    L2: Lookup<K>,
+{
+    fn lookup(&self, key: &K) -> Result<CacheEntry<K::Result, Self::CacheEntryMeta>, Self::Err> {
+        let first = self.first.lookup(key).map_err(TieredError::First)?;
+
+        if !first.is_expired() {
+            return Ok(map_meta(first, TieredEntryMeta::First))
+        }
+
+        trace!("First cache tier has no up-to-date entry, consulting second tier");
+
+        // The first tier might have evicted an entry whose write to the second tier is still
+        // queued up, in which case the second tier would return outdated data
+        self.flush_into(&mut self.second.clone())?;
+
+        let second = self.second.lookup(key).map_err(TieredError::Second)?;
+
+        // Caches are cheap handles to shared storage, so storing into a clone promotes the entry
+        // for everyone
+        let promoted = match second {
+            CacheEntry::Missing => return Ok(map_meta(first, TieredEntryMeta::First)),
+            CacheEntry::Cached(ref object, meta) if !meta.is_expired() =>
+                match self.second.lookup_raw(key).map_err(TieredError::Second)? {
+                    Some(body) => self.first.clone().store_raw(object, &body, key),
+                    None => self.first.clone().store(object, key),
+                },
+            CacheEntry::MarkedAbsent(meta) if !meta.is_expired() => self.first.clone().mark_absent(key),
+            _ => return Ok(map_meta(second, TieredEntryMeta::Second)),
+        };
+
+        if let Err(err) = promoted {
+            warn!("Failed to promote entry to first cache tier: {}", err);
+        }
+
+        Ok(map_meta(second, TieredEntryMeta::Second))
+    }
+
+    fn lookup_raw(&self, key: &K) -> Result<Option<String>, Self::Err> {
+        match self.first.lookup_raw(key).map_err(TieredError::First)? {
+            Some(body) => Ok(Some(body)),
+            None => self.second.lookup_raw(key).map_err(TieredError::Second),
+        }
+    }
+}
+
+impl<K, L1, L2> Store<K> for TieredCache<L1, L2>
+where
+    K: Key + Clone + Send + 'static,
+    K::Result: Clone + Send + 'static,
+    L1: Store<K>,
+    L2: Store<K>,
+{
+    fn store(&mut self, obj: &K::Result, key: &K) -> Result<Self::CacheEntryMeta, Self::Err> {
+        match self.mode {
+            WriteMode::WriteThrough => {
+                self.second.store(obj, key).map_err(TieredError::Second)?;
+            },
+            WriteMode::WriteBack { batch_size } => {
+                let (obj, key) = (obj.clone(), key.clone());
+
+                self.write_back(Box::new(move |second: &mut L2| second.store(&obj, &key).map(|_| ())), batch_size)?;
+            },
+        }
+
+        self.first.store(obj, key).map(TieredEntryMeta::First).map_err(TieredError::First)
+    }
+
+    fn mark_absent(&mut self, key: &K) -> Result<Self::CacheEntryMeta, Self::Err> {
+        match self.mode {
+            WriteMode::WriteThrough => {
+                self.second.mark_absent(key).map_err(TieredError::Second)?;
+            },
+            WriteMode::WriteBack { batch_size } => {
+                let key = key.clone();
+
+                self.write_back(Box::new(move |second: &mut L2| second.mark_absent(&key).map(|_| ())), batch_size)?;
+            },
+        }
+
+        self.first.mark_absent(key).map(TieredEntryMeta::First).map_err(TieredError::First)
+    }
+
+    fn store_raw(&mut self, obj: &K::Result, body: &str, key: &K) -> Result<Self::CacheEntryMeta, Self::Err> {
+        match self.mode {
+            WriteMode::WriteThrough => {
+                self.second.store_raw(obj, body, key).map_err(TieredError::Second)?;
+            },
+            WriteMode::WriteBack { batch_size } => {
+                let (obj, body, key) = (obj.clone(), body.to_string(), key.clone());
+
+                self.write_back(
+                    Box::new(move |second: &mut L2| second.store_raw(&obj, &body, &key).map(|_| ())),
+                    batch_size,
+                )?;
+            },
+        }
+
+        self.first
+            .store_raw(obj, body, key)
+            .map(TieredEntryMeta::First)
+            .map_err(TieredError::First)
+    }
+}
+
+impl<L1, L2> InvalidateObjects for TieredCache<L1, L2>
+where
+    L1: InvalidateObjects,
+    L2: InvalidateObjects,
+{
+    // Queued up writes might contain the very entries we're about to invalidate, so they are
+    // flushed first
+
+    fn invalidate_level(&mut self, level_id: u64) -> Result<(), Self::Err> {
+        self.flush()?;
+
+        self.second.invalidate_level(level_id).map_err(TieredError::Second)?;
+        self.first.invalidate_level(level_id).map_err(TieredError::First)
+    }
+
+    fn invalidate_level_comments(&mut self, level_id: u64) -> Result<(), Self::Err> {
+        self.flush()?;
+
+        self.second.invalidate_level_comments(level_id).map_err(TieredError::Second)?;
+        self.first.invalidate_level_comments(level_id).map_err(TieredError::First)
+    }
+
+    fn invalidate_profile_comments(&mut self, account_id: u64) -> Result<(), Self::Err> {
+        self.flush()?;
+
+        self.second.invalidate_profile_comments(account_id).map_err(TieredError::Second)?;
+        self.first.invalidate_profile_comments(account_id).map_err(TieredError::First)
+    }
+}
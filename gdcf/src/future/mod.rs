@@ -8,6 +8,7 @@ pub mod process;
 pub(crate) mod refresh;
 pub mod stream;
 pub mod upgrade;
+pub mod write;
 
 pub trait PeekableFuture: Future + Sized {
     fn peek<F: FnOnce(Self::Item) -> Result<Self::Item, Self::Error>>(self, f: F) -> Result<Self, Self::Error>;
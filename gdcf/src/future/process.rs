@@ -7,7 +7,7 @@ use crate::{
         request::{PaginatableRequest, Request},
         ApiClient,
     },
-    cache::{Cache, CacheEntry, CanCache, CreatorKey, Lookup, NewgroundsSongKey, Store},
+    cache::{Cache, CacheEntry, CanCache, Lookup, StoreSecondary},
     error::Error,
     future::{refresh::RefreshCacheFuture, upgrade::UpgradeFuture, CloneablePeekFuture, PeekableFuture, StreamableFuture},
     upgrade::Upgradable,
@@ -17,7 +17,7 @@ use crate::{
 pub struct ProcessRequestFuture<Req, A, C>
 where
     A: ApiClient + MakeRequest<Req>,
-    C: Cache + Store<CreatorKey> + Store<NewgroundsSongKey> + CanCache<Req>,
+    C: Cache + CanCache<Req> + StoreSecondary<Req>,
     Req: Request,
 {
     gdcf: Gdcf<A, C>,
@@ -28,7 +28,7 @@ where
 impl<Req, A, C> CloneablePeekFuture for ProcessRequestFuture<Req, A, C>
 where
     A: ApiClient + MakeRequest<Req>,
-    C: Cache + Store<CreatorKey> + Store<NewgroundsSongKey> + CanCache<Req>,
+    C: Cache + CanCache<Req> + StoreSecondary<Req>,
     Req: Request,
     Req::Result: Clone,
 {
@@ -44,7 +44,7 @@ where
 impl<Req, A, C> ProcessRequestFuture<Req, A, C>
 where
     A: ApiClient + MakeRequest<Req>,
-    C: Cache + Store<CreatorKey> + Store<NewgroundsSongKey> + CanCache<Req>,
+    C: Cache + CanCache<Req> + StoreSecondary<Req>,
     Req: Request,
 {
     pub(crate) fn new(gdcf: Gdcf<A, C>, request: Req, forces_refresh: bool) -> Result<Self, C::Err> {
@@ -59,7 +59,7 @@ where
 impl<Req, A, C> StreamableFuture<A, C> for ProcessRequestFuture<Req, A, C>
 where
     A: ApiClient + MakeRequest<Req>,
-    C: Cache + Store<CreatorKey> + Store<NewgroundsSongKey> + CanCache<Req>,
+    C: Cache + CanCache<Req> + StoreSecondary<Req>,
     Req: PaginatableRequest,
 {
     fn next(self) -> Result<Self, Self::Error> {
@@ -79,7 +79,7 @@ where
 impl<Req, A, C> PeekableFuture for ProcessRequestFuture<Req, A, C>
 where
     A: ApiClient + MakeRequest<Req>,
-    C: Cache + Store<CreatorKey> + Store<NewgroundsSongKey> + CanCache<Req>,
+    C: Cache + CanCache<Req> + StoreSecondary<Req>,
     Req: Request,
 {
     fn peek<F: FnOnce(Self::Item) -> Result<Self::Item, Self::Error>>(self, f: F) -> Result<Self, Self::Error> {
@@ -118,7 +118,7 @@ where
 impl<Req, A, C> Future for ProcessRequestFuture<Req, A, C>
 where
     A: ApiClient + MakeRequest<Req>,
-    C: Cache + Store<CreatorKey> + Store<NewgroundsSongKey> + CanCache<Req>,
+    C: Cache + CanCache<Req> + StoreSecondary<Req>,
     Req: Request,
 {
     type Error = Error<A::Err, C::Err>;
@@ -137,7 +137,7 @@ where
 impl<Req, A, C> std::fmt::Debug for ProcessRequestFuture<Req, A, C>
 where
     A: ApiClient + MakeRequest<Req>,
-    C: Cache + Store<CreatorKey> + Store<NewgroundsSongKey> + CanCache<Req>,
+    C: Cache + CanCache<Req> + StoreSecondary<Req>,
     Req: Request + std::fmt::Debug,
 {
     fn fmt(&self, fmt: &mut std::fmt::Formatter) -> std::fmt::Result {
@@ -151,7 +151,7 @@ where
 pub(crate) enum ProcessRequestFutureState<Req, A, C>
 where
     A: ApiClient + MakeRequest<Req>,
-    C: Cache + Store<CreatorKey> + Store<NewgroundsSongKey> + CanCache<Req>,
+    C: Cache + CanCache<Req> + StoreSecondary<Req>,
     Req: Request,
 {
     Uncached(RefreshCacheFuture<Req, A, C>),
@@ -163,7 +163,7 @@ where
 impl<Req, A, C> std::fmt::Debug for ProcessRequestFutureState<Req, A, C>
 where
     A: ApiClient + MakeRequest<Req>,
-    C: Cache + Store<CreatorKey> + Store<NewgroundsSongKey> + CanCache<Req>,
+    C: Cache + CanCache<Req> + StoreSecondary<Req>,
     Req: Request + std::fmt::Debug,
 {
     fn fmt(&self, fmt: &mut std::fmt::Formatter) -> std::fmt::Result {
@@ -178,14 +178,16 @@ where
 impl<Req, A, C> ProcessRequestFuture<Req, A, C>
 where
     A: ApiClient + MakeRequest<Req>,
-    C: Cache + CanCache<Req> + CanCache<CreatorKey> + CanCache<NewgroundsSongKey>,
+    C: Cache + CanCache<Req> + StoreSecondary<Req>,
     Req: Request,
 {
     pub fn upgrade<Into>(self) -> UpgradeFuture<A, C, Self, Into, Req::Result>
     where
         Req::Result: Upgradable<Into>,
         A: MakeRequest<<Req::Result as Upgradable<Into>>::Request>,
-        C: CanCache<<Req::Result as Upgradable<Into>>::Request> + Lookup<<Req::Result as Upgradable<Into>>::LookupKey>,
+        C: CanCache<<Req::Result as Upgradable<Into>>::Request>
+            + StoreSecondary<<Req::Result as Upgradable<Into>>::Request>
+            + Lookup<<Req::Result as Upgradable<Into>>::LookupKey>,
     {
         UpgradeFuture::new(self.gdcf.clone(), self.forces_refresh, self)
     }
@@ -194,14 +196,16 @@ where
 impl<Req, A, C> ProcessRequestFuture<Req, A, C>
 where
     A: ApiClient + MakeRequest<Req>,
-    C: Cache + CanCache<Req> + CanCache<CreatorKey> + CanCache<NewgroundsSongKey>,
+    C: Cache + CanCache<Req> + StoreSecondary<Req>,
     Req: Request,
 {
     pub fn upgrade_all<Into>(self) -> UpgradeFuture<A, C, Self, Vec<Into>, Req::Result>
     where
         Req::Result: Upgradable<Vec<Into>>,
         A: MakeRequest<<Req::Result as Upgradable<Vec<Into>>>::Request>,
-        C: CanCache<<Req::Result as Upgradable<Vec<Into>>>::Request> + Lookup<<Req::Result as Upgradable<Vec<Into>>>::LookupKey>,
+        C: CanCache<<Req::Result as Upgradable<Vec<Into>>>::Request>
+            + StoreSecondary<<Req::Result as Upgradable<Vec<Into>>>::Request>
+            + Lookup<<Req::Result as Upgradable<Vec<Into>>>::LookupKey>,
     {
         self.upgrade()
     }
@@ -1,12 +1,12 @@
 use crate::{
     api::{
-        client::{MakeRequest, Response},
+        client::{MakeRequest, RawResponse, Response},
         request::Request,
         ApiClient,
     },
-    cache::{Cache, CacheEntry, CanCache, CreatorKey, NewgroundsSongKey, Store},
+    cache::{Cache, CacheEntry, CanCache, Store, StoreSecondary},
     error::{ApiError, Error},
-    Gdcf, Secondary,
+    Gdcf,
 };
 use futures::{Async, Future};
 use log::{info, warn};
@@ -15,7 +15,7 @@ pub(crate) struct RefreshCacheFuture<Req, A, C>
 where
     Req: Request,
     A: ApiClient + MakeRequest<Req>,
-    C: Cache + Store<CreatorKey> + Store<NewgroundsSongKey> + CanCache<Req>,
+    C: Cache + CanCache<Req> + StoreSecondary<Req>,
 {
     inner: <A as MakeRequest<Req>>::Future,
     cache: C,
@@ -26,7 +26,7 @@ impl<Req, A, C> std::fmt::Debug for RefreshCacheFuture<Req, A, C>
 where
     Req: Request + std::fmt::Debug,
     A: ApiClient + MakeRequest<Req>,
-    C: Cache + Store<CreatorKey> + Store<NewgroundsSongKey> + CanCache<Req>,
+    C: Cache + CanCache<Req> + StoreSecondary<Req>,
 {
     fn fmt(&self, fmt: &mut std::fmt::Formatter) -> std::fmt::Result {
         fmt.debug_struct("RefreshCacheFuture").field("request", &self.request).finish()
@@ -37,7 +37,7 @@ impl<Req, A, C> RefreshCacheFuture<Req, A, C>
 where
     Req: Request,
     A: ApiClient + MakeRequest<Req>,
-    C: Cache + Store<CreatorKey> + Store<NewgroundsSongKey> + CanCache<Req>,
+    C: Cache + CanCache<Req> + StoreSecondary<Req>,
 {
     pub(crate) fn new(gdcf: &Gdcf<A, C>, request: Req) -> Self {
         info!("Performing refresh on request {:?}", request);
@@ -54,7 +54,7 @@ impl<Req, A, C> Future for RefreshCacheFuture<Req, A, C>
 where
     Req: Request,
     A: ApiClient + MakeRequest<Req>,
-    C: Cache + Store<CreatorKey> + Store<NewgroundsSongKey> + CanCache<Req>,
+    C: Cache + CanCache<Req> + StoreSecondary<Req>,
 {
     type Error = Error<A::Err, C::Err>;
     type Item = CacheEntry<Req::Result, C::CacheEntryMeta>;
@@ -72,31 +72,23 @@ where
                     .map_err(Error::Cache)
             },
             Err(api_error) => Err(Error::Api(api_error)),
-            Ok(Async::Ready(response)) =>
-                match response {
-                    Response::Exact(what_we_want) =>
-                        self.cache
-                            .store(&what_we_want, &self.request)
-                            .map(|entry_info| Async::Ready(CacheEntry::Cached(what_we_want, entry_info)))
-                            .map_err(Error::Cache),
+            Ok(Async::Ready(RawResponse { body, response })) => {
+                let what_we_want = match response {
+                    Response::Exact(what_we_want) => what_we_want,
                     Response::More(what_we_want, excess) => {
                         for object in &excess {
-                            match object {
-                                Secondary::NewgroundsSong(song) => self.cache.store(song, &NewgroundsSongKey(song.song_id)),
-                                Secondary::Creator(creator) => self.cache.store(creator, &CreatorKey(creator.user_id)),
-                                Secondary::MissingCreator(cid) => Store::<CreatorKey>::mark_absent(&mut self.cache, &CreatorKey(*cid)),
-                                Secondary::MissingNewgroundsSong(nid) =>
-                                    Store::<NewgroundsSongKey>::mark_absent(&mut self.cache, &NewgroundsSongKey(*nid)),
-                            }
-                            .map_err(Error::Cache)?;
+                            self.cache.store_secondary(object).map_err(Error::Cache)?;
                         }
 
-                        self.cache
-                            .store(&what_we_want, &self.request)
-                            .map(|entry_info| Async::Ready(CacheEntry::Cached(what_we_want, entry_info)))
-                            .map_err(Error::Cache)
+                        what_we_want
                     },
-                },
+                };
+
+                self.cache
+                    .store_raw(&what_we_want, &body, &self.request)
+                    .map(|entry_info| Async::Ready(CacheEntry::Cached(what_we_want, entry_info)))
+                    .map_err(Error::Cache)
+            },
         }
     }
 }
@@ -2,7 +2,7 @@ use futures::{Async, Future};
 
 use crate::{
     api::{client::MakeRequest, ApiClient},
-    cache::{Cache, CacheEntry, CanCache, CreatorKey, Lookup, NewgroundsSongKey, Store},
+    cache::{Cache, CacheEntry, CanCache, Lookup, StoreSecondary},
     error::Error,
     future::{refresh::RefreshCacheFuture, CloneablePeekFuture, PeekableFuture, StreamableFuture},
     upgrade::{Upgradable, UpgradeQueryFuture},
@@ -13,7 +13,7 @@ use std::fmt::Debug;
 struct PendingUpgrade<A, C, Into, U>
 where
     A: MakeRequest<U::Request>,
-    C: Cache + Store<CreatorKey> + Store<NewgroundsSongKey> + CanCache<U::Request>,
+    C: Cache + CanCache<U::Request> + StoreSecondary<U::Request>,
     U: Upgradable<Into>,
 {
     to_upgrade: U,
@@ -24,7 +24,7 @@ where
 impl<A, C, Into, U> Debug for PendingUpgrade<A, C, Into, U>
 where
     A: MakeRequest<U::Request>,
-    C: Cache + Store<CreatorKey> + Store<NewgroundsSongKey> + CanCache<U::Request>,
+    C: Cache + CanCache<U::Request> + StoreSecondary<U::Request>,
     U: Upgradable<Into> + Debug,
     U::Upgrade: Debug,
 {
@@ -40,7 +40,7 @@ where
 pub struct UpgradeFuture<A, C, From, Into, U>
 where
     A: ApiClient + MakeRequest<U::Request>,
-    C: Cache + CanCache<U::Request> + CanCache<CreatorKey> + CanCache<NewgroundsSongKey> + Lookup<U::LookupKey>,
+    C: Cache + CanCache<U::Request> + StoreSecondary<U::Request> + Lookup<U::LookupKey>,
     From: PeekableFuture<Item = CacheEntry<U, C::CacheEntryMeta>, Error = Error<A::Err, C::Err>>,
     U: Upgradable<Into>,
 {
@@ -53,7 +53,7 @@ where
 impl<A, C, From, Into, U> UpgradeFuture<A, C, From, Into, U>
 where
     A: ApiClient + MakeRequest<U::Request>,
-    C: Cache + CanCache<U::Request> + CanCache<CreatorKey> + CanCache<NewgroundsSongKey> + Lookup<U::LookupKey>,
+    C: Cache + CanCache<U::Request> + StoreSecondary<U::Request> + Lookup<U::LookupKey>,
     From: PeekableFuture<Item = CacheEntry<U, C::CacheEntryMeta>, Error = Error<A::Err, C::Err>>,
     U: Upgradable<Into>,
 {
@@ -69,7 +69,7 @@ where
 impl<A, C, From, Into, U> StreamableFuture<A, C> for UpgradeFuture<A, C, From, Into, U>
 where
     A: ApiClient + MakeRequest<U::Request>,
-    C: Cache + CanCache<U::Request> + CanCache<CreatorKey> + CanCache<NewgroundsSongKey> + Lookup<U::LookupKey>,
+    C: Cache + CanCache<U::Request> + StoreSecondary<U::Request> + Lookup<U::LookupKey>,
     From: PeekableFuture<Item = CacheEntry<U, C::CacheEntryMeta>, Error = Error<A::Err, C::Err>> + StreamableFuture<A, C>,
     U: Upgradable<Into>,
 {
@@ -85,7 +85,7 @@ where
 impl<A, C, From, Into, U> UpgradeFuture<A, C, From, Into, U>
 where
     A: ApiClient + MakeRequest<U::Request>,
-    C: Cache + CanCache<U::Request> + CanCache<CreatorKey> + CanCache<NewgroundsSongKey> + Lookup<U::LookupKey>,
+    C: Cache + CanCache<U::Request> + StoreSecondary<U::Request> + Lookup<U::LookupKey>,
     From: PeekableFuture<Item = CacheEntry<U, C::CacheEntryMeta>, Error = Error<A::Err, C::Err>>,
     U: Upgradable<Into>,
 {
@@ -93,7 +93,7 @@ where
     where
         Into: Upgradable<Into2>,
         A: MakeRequest<Into::Request>,
-        C: CanCache<Into::Request> + Lookup<Into::LookupKey>,
+        C: CanCache<Into::Request> + StoreSecondary<Into::Request> + Lookup<Into::LookupKey>,
     {
         UpgradeFuture {
             forced_refresh: self.forced_refresh,
@@ -106,7 +106,7 @@ where
 impl<A, C, From, Into, U> UpgradeFuture<A, C, From, Vec<Into>, Vec<U>>
 where
     A: ApiClient + MakeRequest<U::Request>,
-    C: Cache + CanCache<U::Request> + CanCache<CreatorKey> + CanCache<NewgroundsSongKey> + Lookup<U::LookupKey>,
+    C: Cache + CanCache<U::Request> + StoreSecondary<U::Request> + Lookup<U::LookupKey>,
     From: PeekableFuture<Item = CacheEntry<Vec<U>, C::CacheEntryMeta>, Error = Error<A::Err, C::Err>>,
     U: Upgradable<Into>,
 {
@@ -114,7 +114,7 @@ where
     where
         Into: Upgradable<Into2>,
         A: MakeRequest<Into::Request>,
-        C: Lookup<Into::LookupKey> + CanCache<Into::Request>,
+        C: Lookup<Into::LookupKey> + CanCache<Into::Request> + StoreSecondary<Into::Request>,
     {
         UpgradeFuture {
             forced_refresh: self.forced_refresh,
@@ -128,7 +128,7 @@ where
 impl<A, C, From, Into, U> Future for UpgradeFuture<A, C, From, Into, U>
 where
     A: ApiClient + MakeRequest<U::Request>,
-    C: Cache + CanCache<U::Request> + CanCache<CreatorKey> + CanCache<NewgroundsSongKey> + Lookup<U::LookupKey>,
+    C: Cache + CanCache<U::Request> + StoreSecondary<U::Request> + Lookup<U::LookupKey>,
     From: PeekableFuture<Item = CacheEntry<U, C::CacheEntryMeta>, Error = Error<A::Err, C::Err>>,
     U: Upgradable<Into>,
 {
@@ -173,7 +173,7 @@ where
 impl<A, C, From, Into, U> PeekableFuture for UpgradeFuture<A, C, From, Into, U>
 where
     A: ApiClient + MakeRequest<U::Request>,
-    C: Cache + CanCache<U::Request> + CanCache<CreatorKey> + CanCache<NewgroundsSongKey> + Lookup<U::LookupKey>,
+    C: Cache + CanCache<U::Request> + StoreSecondary<U::Request> + Lookup<U::LookupKey>,
     From: PeekableFuture<Item = CacheEntry<U, C::CacheEntryMeta>, Error = Error<A::Err, C::Err>>,
     U: Upgradable<Into>,
 {
@@ -230,7 +230,7 @@ where
 impl<A, C, From, Into, U> CloneablePeekFuture for UpgradeFuture<A, C, From, Into, U>
 where
     A: ApiClient + MakeRequest<U::Request>,
-    C: Cache + CanCache<U::Request> + CanCache<CreatorKey> + CanCache<NewgroundsSongKey> + Lookup<U::LookupKey>,
+    C: Cache + CanCache<U::Request> + StoreSecondary<U::Request> + Lookup<U::LookupKey>,
     From: CloneablePeekFuture<Item = CacheEntry<U, C::CacheEntryMeta>, Error = Error<A::Err, C::Err>>,
     U: Upgradable<Into> + Clone,
     U::Upgrade: Clone,
@@ -273,7 +273,7 @@ where
 impl<A, C, From, Into, U> Debug for UpgradeFuture<A, C, From, Into, U>
 where
     A: ApiClient + MakeRequest<U::Request>,
-    C: Cache + CanCache<U::Request> + CanCache<CreatorKey> + CanCache<NewgroundsSongKey> + Lookup<U::LookupKey>,
+    C: Cache + CanCache<U::Request> + StoreSecondary<U::Request> + Lookup<U::LookupKey>,
     From: PeekableFuture<Item = CacheEntry<U, C::CacheEntryMeta>, Error = Error<A::Err, C::Err>> + Debug,
     U: Upgradable<Into> + Debug,
     U::Upgrade: Debug,
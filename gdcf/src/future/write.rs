@@ -0,0 +1,83 @@
+use crate::{
+    api::{
+        client::{MakeRequest, RawResponse, Response},
+        request::Request,
+        ApiClient,
+    },
+    cache::Invalidate,
+    error::Error,
+    Gdcf,
+};
+use futures::{Async, Future};
+use log::info;
+use std::fmt::Display;
+
+/// Future performing a request that modifies data on the servers.
+///
+/// Unlike [`ProcessRequestFuture`](crate::future::process::ProcessRequestFuture), this future never
+/// consults the cache. Its result is returned directly from the API client, and the cache entries
+/// affected by the request are invalidated once it succeeds.
+pub struct WriteRequestFuture<Req, A, C>
+where
+    Req: Request,
+    A: ApiClient + MakeRequest<Req>,
+    C: Invalidate<Req>,
+{
+    inner: <A as MakeRequest<Req>>::Future,
+    cache: C,
+    request: Req,
+}
+
+impl<Req, A, C> WriteRequestFuture<Req, A, C>
+where
+    Req: Request + Display,
+    A: ApiClient + MakeRequest<Req>,
+    C: Invalidate<Req>,
+{
+    pub(crate) fn new(gdcf: &Gdcf<A, C>, request: Req) -> Self {
+        // Write requests can carry large amounts of data (like the level data of an uploaded
+        // level), so only their summary is logged
+        info!("Performing write request {}", request);
+
+        WriteRequestFuture {
+            inner: gdcf.client().make(&request),
+            cache: gdcf.cache(),
+            request,
+        }
+    }
+}
+
+impl<Req, A, C> std::fmt::Debug for WriteRequestFuture<Req, A, C>
+where
+    Req: Request,
+    A: ApiClient + MakeRequest<Req>,
+    C: Invalidate<Req>,
+{
+    fn fmt(&self, fmt: &mut std::fmt::Formatter) -> std::fmt::Result {
+        fmt.debug_struct("WriteRequestFuture").field("request", &self.request).finish()
+    }
+}
+
+impl<Req, A, C> Future for WriteRequestFuture<Req, A, C>
+where
+    Req: Request,
+    A: ApiClient + MakeRequest<Req>,
+    C: Invalidate<Req>,
+{
+    type Error = Error<A::Err, C::Err>;
+    type Item = Req::Result;
+
+    fn poll(&mut self) -> Result<Async<Self::Item>, Self::Error> {
+        let result = match self.inner.poll().map_err(Error::Api)? {
+            Async::NotReady => return Ok(Async::NotReady),
+            Async::Ready(RawResponse { response, .. }) =>
+                match response {
+                    Response::Exact(result) | Response::More(result, _) => result,
+                },
+        };
+
+        self.cache.invalidate(&self.request, &result).map_err(Error::Cache)?;
+
+        Ok(Async::Ready(result))
+    }
+}
@@ -13,19 +13,37 @@
 use crate::{
     api::{
         client::MakeRequest,
-        request::{comment::ProfileCommentsRequest, user::UserSearchRequest, LevelRequest, LevelsRequest, Request, UserRequest},
+        request::{
+            comment::{LevelCommentsRequest, ProfileCommentsRequest},
+            user::UserSearchRequest,
+            BlockedUsersRequest, DailyLevelRequest, FriendListRequest, FriendRequestsRequest, GauntletsRequest, LeaderboardRequest,
+            LevelRequest, LevelScoresRequest, LevelsRequest, LikeRequest, MapPacksRequest, MessageRequest, MessagesRequest,
+            RateStarsRequest, Request, SongInfoRequest, UploadLevelCommentRequest, UploadLevelRequest, UploadProfileCommentRequest,
+            UserRequest,
+        },
         ApiClient,
     },
-    cache::{Cache, CacheEntry, CanCache, CreatorKey, NewgroundsSongKey, Store},
+    cache::{Cache, CacheEntry, CanCache, CommentUserKey, CreatorKey, Invalidate, NewgroundsSongKey, Store, StoreSecondary},
     future::{
         process::{ProcessRequestFuture, ProcessRequestFutureState},
         refresh::RefreshCacheFuture,
+        upgrade::UpgradeFuture,
+        write::WriteRequestFuture,
     },
 };
 pub use error::Error;
-use gdcf_model::{song::NewgroundsSong, user::Creator};
+use gdcf_model::{
+    comment::CommentUser,
+    level::{DailyLevel, Level},
+    song::NewgroundsSong,
+    user::Creator,
+};
 use log::{info, trace};
 
+/// The future returned by [`Gdcf::daily_level`]
+pub type DailyLevelFuture<A, C> =
+    UpgradeFuture<A, C, ProcessRequestFuture<DailyLevelRequest, A, C>, DailyLevel<Level<Option<u64>, u64>>, DailyLevel>;
+
 #[macro_use]
 mod macros;
 mod upgrade;
@@ -42,6 +60,8 @@ pub enum Secondary {
     Creator(Creator),
     MissingCreator(u64),
     MissingNewgroundsSong(u64),
+    CommentUser(u64, CommentUser),
+    MissingCommentUser(u64),
 }
 
 impl From<NewgroundsSong> for Secondary {
@@ -63,6 +83,8 @@ impl std::fmt::Display for Secondary {
             Secondary::Creator(inner) => inner.fmt(f),
             Secondary::MissingCreator(cid) => write!(f, "Creator object missing server-sided: {}", cid),
             Secondary::MissingNewgroundsSong(nid) => write!(f, "Newgrounds song object missing server-sided: {}", nid),
+            Secondary::CommentUser(uid, inner) => write!(f, "{} (user id {})", inner, uid),
+            Secondary::MissingCommentUser(uid) => write!(f, "Comment user object missing server-sided: {}", uid),
         }
     }
 }
@@ -93,18 +115,66 @@ where
     pub fn client(&self) -> A {
         self.client.clone()
     }
+
+    /// Performs the given [`UploadLevelRequest`], resolving to the ID of the uploaded level
+    pub fn upload_level(&self, request: impl Into<UploadLevelRequest>) -> WriteRequestFuture<UploadLevelRequest, A, C>
+    where
+        A: MakeRequest<UploadLevelRequest>,
+        C: Invalidate<UploadLevelRequest>,
+    {
+        WriteRequestFuture::new(self, request.into())
+    }
+
+    /// Performs the given [`UploadLevelCommentRequest`], resolving to the ID of the new comment
+    pub fn upload_level_comment(&self, request: impl Into<UploadLevelCommentRequest>) -> WriteRequestFuture<UploadLevelCommentRequest, A, C>
+    where
+        A: MakeRequest<UploadLevelCommentRequest>,
+        C: Invalidate<UploadLevelCommentRequest>,
+    {
+        WriteRequestFuture::new(self, request.into())
+    }
+
+    /// Performs the given [`UploadProfileCommentRequest`], resolving to the ID of the new comment
+    pub fn upload_profile_comment(
+        &self,
+        request: impl Into<UploadProfileCommentRequest>,
+    ) -> WriteRequestFuture<UploadProfileCommentRequest, A, C>
+    where
+        A: MakeRequest<UploadProfileCommentRequest>,
+        C: Invalidate<UploadProfileCommentRequest>,
+    {
+        WriteRequestFuture::new(self, request.into())
+    }
+
+    /// Performs the given [`LikeRequest`]
+    pub fn like(&self, request: impl Into<LikeRequest>) -> WriteRequestFuture<LikeRequest, A, C>
+    where
+        A: MakeRequest<LikeRequest>,
+        C: Invalidate<LikeRequest>,
+    {
+        WriteRequestFuture::new(self, request.into())
+    }
+
+    /// Performs the given [`RateStarsRequest`]
+    pub fn rate_stars(&self, request: impl Into<RateStarsRequest>) -> WriteRequestFuture<RateStarsRequest, A, C>
+    where
+        A: MakeRequest<RateStarsRequest>,
+        C: Invalidate<RateStarsRequest>,
+    {
+        WriteRequestFuture::new(self, request.into())
+    }
 }
 
 impl<A, C> Gdcf<A, C>
 where
     A: ApiClient,
-    C: Cache + Store<CreatorKey> + Store<NewgroundsSongKey>,
+    C: Cache,
 {
     fn process<R>(&self, request: R, force_refresh: bool) -> Result<ProcessRequestFutureState<R, A, C>, C::Err>
     where
         R: Request,
         A: MakeRequest<R>,
-        C: CanCache<R>,
+        C: CanCache<R> + StoreSecondary<R>,
     {
         info!("Processing request {:?}", request);
 
@@ -162,14 +232,9 @@ where
     /// `Song` can currently be one of the following:
     /// + [`u64`] - The custom song is provided only as its newgrounds ID. Causes no additional
     /// requests
-    /// + [`NewgroundsSong`] - Causes an additional [`LevelsRequest`] to be made to
-    /// retrieve the custom song (only if the level actually uses a custom song though)
-    ///
-    /// Note that a call of `Gdcf::level<NewgroundsSong, Creator>` will **not** issue the same
-    /// `LevelsRequest` twice - GDCF will recognize the cache to be up-to-date when it attempts the
-    /// second one and uses the cached value (or at least it will if you set cache-expiry to
-    /// anything larger than 0 seconds - but then again why would you use GDCF if you don't use the
-    /// cache)
+    /// + [`NewgroundsSong`] - Causes an additional [`SongInfoRequest`] to be made to
+    /// retrieve the custom song (only if the level actually uses a custom song and it isn't already
+    /// cached though)
     pub fn level(&self, request: impl Into<LevelRequest>, force_refresh: bool) -> Result<ProcessRequestFuture<LevelRequest, A, C>, C::Err>
     where
         A: MakeRequest<LevelRequest>,
@@ -178,6 +243,20 @@ where
         ProcessRequestFuture::new(self.clone(), request.into(), force_refresh)
     }
 
+    /// Processes the given [`DailyLevelRequest`] and downloads the level it refers to
+    ///
+    /// The level is retrieved via a [`LevelRequest`] with the special level ID `-1` (or `-2` for
+    /// the weekly demon), see [`DailyLevelRequest::level_request`]. Whether cached data about the
+    /// daily level is still up-to-date is up to the cache, but it should consider the time left
+    /// until the daily level is replaced.
+    pub fn daily_level(&self, request: impl Into<DailyLevelRequest>, force_refresh: bool) -> Result<DailyLevelFuture<A, C>, C::Err>
+    where
+        A: MakeRequest<DailyLevelRequest> + MakeRequest<LevelRequest>,
+        C: CanCache<DailyLevelRequest> + CanCache<LevelRequest>,
+    {
+        Ok(ProcessRequestFuture::new(self.clone(), request.into(), force_refresh)?.upgrade())
+    }
+
     /// Processes the given [`LevelsRequest`]
     ///
     /// The `User` and `Song` type parameters determine, which sequence of requests should be made
@@ -205,6 +284,58 @@ where
         ProcessRequestFuture::new(self.clone(), request.into(), force_refresh)
     }
 
+    /// Processes the given [`LevelScoresRequest`]
+    pub fn level_scores(
+        &self,
+        request: impl Into<LevelScoresRequest>,
+        force_refresh: bool,
+    ) -> Result<ProcessRequestFuture<LevelScoresRequest, A, C>, C::Err>
+    where
+        A: MakeRequest<LevelScoresRequest>,
+        C: CanCache<LevelScoresRequest>,
+    {
+        ProcessRequestFuture::new(self.clone(), request.into(), force_refresh)
+    }
+
+    /// Processes the given [`SongInfoRequest`]
+    pub fn song(
+        &self,
+        request: impl Into<SongInfoRequest>,
+        force_refresh: bool,
+    ) -> Result<ProcessRequestFuture<SongInfoRequest, A, C>, C::Err>
+    where
+        A: MakeRequest<SongInfoRequest>,
+        C: CanCache<SongInfoRequest>,
+    {
+        ProcessRequestFuture::new(self.clone(), request.into(), force_refresh)
+    }
+
+    /// Processes the given [`MessagesRequest`]
+    pub fn messages(
+        &self,
+        request: impl Into<MessagesRequest>,
+        force_refresh: bool,
+    ) -> Result<ProcessRequestFuture<MessagesRequest, A, C>, C::Err>
+    where
+        A: MakeRequest<MessagesRequest>,
+        C: CanCache<MessagesRequest>,
+    {
+        ProcessRequestFuture::new(self.clone(), request.into(), force_refresh)
+    }
+
+    /// Processes the given [`MessageRequest`]
+    pub fn message(
+        &self,
+        request: impl Into<MessageRequest>,
+        force_refresh: bool,
+    ) -> Result<ProcessRequestFuture<MessageRequest, A, C>, C::Err>
+    where
+        A: MakeRequest<MessageRequest>,
+        C: CanCache<MessageRequest>,
+    {
+        ProcessRequestFuture::new(self.clone(), request.into(), force_refresh)
+    }
+
     /// Processes the given [`UserRequest`]
     pub fn user(&self, request: impl Into<UserRequest>, force_refresh: bool) -> Result<ProcessRequestFuture<UserRequest, A, C>, C::Err>
     where
@@ -226,6 +357,110 @@ where
         ProcessRequestFuture::new(self.clone(), request.into(), force_refresh)
     }
 
+    /// Processes the given [`FriendListRequest`]
+    ///
+    /// The returned [`ListedUser`](gdcf_model::user::ListedUser)s can be upgraded to full profiles
+    /// using `upgrade_all`
+    pub fn friend_list(
+        &self,
+        request: impl Into<FriendListRequest>,
+        force_refresh: bool,
+    ) -> Result<ProcessRequestFuture<FriendListRequest, A, C>, C::Err>
+    where
+        A: MakeRequest<FriendListRequest>,
+        C: CanCache<FriendListRequest>,
+    {
+        ProcessRequestFuture::new(self.clone(), request.into(), force_refresh)
+    }
+
+    /// Processes the given [`BlockedUsersRequest`]
+    pub fn blocked_users(
+        &self,
+        request: impl Into<BlockedUsersRequest>,
+        force_refresh: bool,
+    ) -> Result<ProcessRequestFuture<BlockedUsersRequest, A, C>, C::Err>
+    where
+        A: MakeRequest<BlockedUsersRequest>,
+        C: CanCache<BlockedUsersRequest>,
+    {
+        ProcessRequestFuture::new(self.clone(), request.into(), force_refresh)
+    }
+
+    /// Processes the given [`FriendRequestsRequest`]
+    pub fn friend_requests(
+        &self,
+        request: impl Into<FriendRequestsRequest>,
+        force_refresh: bool,
+    ) -> Result<ProcessRequestFuture<FriendRequestsRequest, A, C>, C::Err>
+    where
+        A: MakeRequest<FriendRequestsRequest>,
+        C: CanCache<FriendRequestsRequest>,
+    {
+        ProcessRequestFuture::new(self.clone(), request.into(), force_refresh)
+    }
+
+    /// Processes the given [`LeaderboardRequest`]
+    pub fn leaderboard(
+        &self,
+        request: impl Into<LeaderboardRequest>,
+        force_refresh: bool,
+    ) -> Result<ProcessRequestFuture<LeaderboardRequest, A, C>, C::Err>
+    where
+        A: MakeRequest<LeaderboardRequest>,
+        C: CanCache<LeaderboardRequest>,
+    {
+        ProcessRequestFuture::new(self.clone(), request.into(), force_refresh)
+    }
+
+    /// Processes the given [`MapPacksRequest`]
+    pub fn map_packs(
+        &self,
+        request: impl Into<MapPacksRequest>,
+        force_refresh: bool,
+    ) -> Result<ProcessRequestFuture<MapPacksRequest, A, C>, C::Err>
+    where
+        A: MakeRequest<MapPacksRequest>,
+        C: CanCache<MapPacksRequest>,
+    {
+        ProcessRequestFuture::new(self.clone(), request.into(), force_refresh)
+    }
+
+    /// Processes the given [`GauntletsRequest`]
+    pub fn gauntlets(
+        &self,
+        request: impl Into<GauntletsRequest>,
+        force_refresh: bool,
+    ) -> Result<ProcessRequestFuture<GauntletsRequest, A, C>, C::Err>
+    where
+        A: MakeRequest<GauntletsRequest>,
+        C: CanCache<GauntletsRequest>,
+    {
+        ProcessRequestFuture::new(self.clone(), request.into(), force_refresh)
+    }
+
+    /// Processes the given [`LevelCommentsRequest`]
+    ///
+    /// The comments are returned without any user data attached. The [`CommentUser`]s provided
+    /// alongside them are stored in the cache, so they can be attached to the comments by
+    /// upgrading to `LevelComment<Option<CommentUser>>` without causing any additional requests.
+    /// The user is `None` if the comment's author deleted their account.
+    ///
+    /// Previously, [`LevelCommentsRequest`] returned the comments with their users already
+    /// attached. Comments and users are now cached separately, the same way levels and their
+    /// creators are. To get the old `Vec<LevelComment<Option<CommentUser>>>` back, call
+    /// `upgrade_all` on the returned future.
+    pub fn level_comments(
+        &self,
+        request: impl Into<LevelCommentsRequest>,
+        force_refresh: bool,
+    ) -> Result<ProcessRequestFuture<LevelCommentsRequest, A, C>, C::Err>
+    where
+        A: MakeRequest<LevelCommentsRequest>,
+        C: CanCache<LevelCommentsRequest> + Store<CommentUserKey>,
+    {
+        ProcessRequestFuture::new(self.clone(), request.into(), force_refresh)
+    }
+
     pub fn profile_comments(
         &self,
         request: impl Into<ProfileCommentsRequest>,
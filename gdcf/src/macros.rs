@@ -105,3 +105,27 @@ macro_rules! query_upgrade_option {
         }
     }};
 }
+
+/// Implements `PartialEq` and `Eq` for a type by comparing exactly the fields its manual `Hash`
+/// impl hashes, so that two requests are equal if and only if they are the same cache key
+macro_rules! eq_by_hashed_fields {
+    ($request: ty) => {
+        impl PartialEq for $request {
+            fn eq(&self, _: &Self) -> bool {
+                true
+            }
+        }
+
+        impl Eq for $request {}
+    };
+
+    ($request: ty: $($field: ident),*) => {
+        impl PartialEq for $request {
+            fn eq(&self, other: &Self) -> bool {
+                $(self.$field == other.$field)&&*
+            }
+        }
+
+        impl Eq for $request {}
+    };
+}
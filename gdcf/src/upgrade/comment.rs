@@ -0,0 +1,81 @@
+use crate::{
+    api::request::{LevelCommentsRequest, Request},
+    cache::{Cache, CacheEntry, CommentUserKey, Lookup},
+    upgrade::{Upgradable, UpgradeError, UpgradeQuery},
+};
+use gdcf_model::comment::{CommentUser, LevelComment};
+
+/// Attaches the [`CommentUser`] of a comment's author
+///
+/// The upgrade targets `LevelComment<Option<CommentUser>>` instead of `LevelComment<CommentUser>`:
+/// the servers don't provide any user data for comments whose author deleted their account, and
+/// there is no request that could retrieve it afterwards. A non-optional upgrade would thus fail
+/// for every page containing such a comment. This mirrors how levels are upgraded to
+/// `Level<Song, Option<Creator>>`.
+impl Upgradable<LevelComment<Option<CommentUser>>> for LevelComment<()> {
+    type From = ();
+    type LookupKey = CommentUserKey;
+    type Request = LevelCommentsRequest;
+    type Upgrade = Option<CommentUser>;
+
+    fn query_upgrade<C: Cache + Lookup<Self::LookupKey>>(
+        &self,
+        cache: &C,
+        _ignored_cached: bool,
+    ) -> Result<UpgradeQuery<Self::Request, Self::Upgrade>, UpgradeError<C::Err>> {
+        // The user data is only ever provided alongside the comments themselves, so there is no
+        // request we could make to refresh it. Whatever the cache has is the best we can do.
+        match cache.lookup(&CommentUserKey(self.user_id))? {
+            CacheEntry::Cached(user, _) => Ok(UpgradeQuery::One(None, Some(Some(user)))),
+            _ => Ok(UpgradeQuery::One(None, Some(None))),
+        }
+    }
+
+    fn process_query_result<C: Cache + Lookup<Self::LookupKey>>(
+        &self,
+        _cache: &C,
+        resolved_query: UpgradeQuery<CacheEntry<<Self::Request as Request>::Result, C::CacheEntryMeta>, Self::Upgrade>,
+    ) -> Result<UpgradeQuery<(), Self::Upgrade>, UpgradeError<C::Err>> {
+        match resolved_query.one() {
+            (None, Some(user)) => Ok(UpgradeQuery::One(None, Some(user))),
+            _ => Err(UpgradeError::UpgradeFailed),
+        }
+    }
+
+    fn upgrade<State>(
+        self,
+        upgrade: UpgradeQuery<State, Self::Upgrade>,
+    ) -> (LevelComment<Option<CommentUser>>, UpgradeQuery<State, Self::From>) {
+        let (comment, old_user) = change_level_comment_user(self, upgrade.one().1.unwrap());
+
+        (comment, UpgradeQuery::One(None, Some(old_user)))
+    }
+
+    fn downgrade<State>(
+        upgraded: LevelComment<Option<CommentUser>>,
+        _downgrade: UpgradeQuery<State, Self::From>,
+    ) -> (Self, UpgradeQuery<State, Self::Upgrade>) {
+        let (comment, new_user) = change_level_comment_user(upgraded, ());
+
+        (comment, UpgradeQuery::One(None, Some(new_user)))
+    }
+}
+
+fn change_level_comment_user<OldUser, NewUser>(comment: LevelComment<OldUser>, new_user: NewUser) -> (LevelComment<NewUser>, OldUser) {
+    let old_user = comment.user;
+
+    let new_comment = LevelComment {
+        user: new_user,
+        content: comment.content,
+        user_id: comment.user_id,
+        likes: comment.likes,
+        comment_id: comment.comment_id,
+        is_flagged_spam: comment.is_flagged_spam,
+        time_since_post: comment.time_since_post,
+        progress: comment.progress,
+        is_elder_mod: comment.is_elder_mod,
+        special_color: comment.special_color,
+    };
+
+    (new_comment, old_user)
+}
@@ -0,0 +1,72 @@
+use crate::{
+    api::request::{LevelRequest, Request},
+    cache::{Cache, CacheEntry, Lookup},
+    upgrade::{Upgradable, UpgradeError, UpgradeQuery},
+};
+use gdcf_model::level::{DailyLevel, Level};
+
+impl Upgradable<DailyLevel<Level<Option<u64>, u64>>> for DailyLevel {
+    type From = ();
+    type LookupKey = LevelRequest;
+    type Request = LevelRequest;
+    type Upgrade = Level<Option<u64>, u64>;
+
+    fn query_upgrade<C: Cache + Lookup<Self::LookupKey>>(
+        &self,
+        cache: &C,
+        ignored_cached: bool,
+    ) -> Result<UpgradeQuery<Self::Request, Self::Upgrade>, UpgradeError<C::Err>> {
+        query_upgrade!(cache, level_request(self), level_request(self), ignored_cached)
+    }
+
+    fn process_query_result<C: Cache + Lookup<Self::LookupKey>>(
+        &self,
+        _cache: &C,
+        resolved_query: UpgradeQuery<CacheEntry<<Self::Request as Request>::Result, C::CacheEntryMeta>, Self::Upgrade>,
+    ) -> Result<UpgradeQuery<(), Self::Upgrade>, UpgradeError<C::Err>> {
+        match resolved_query.one() {
+            (None, Some(level)) => Ok(UpgradeQuery::One(None, Some(level))),
+            (Some(CacheEntry::Cached(level, _)), _) => Ok(UpgradeQuery::One(None, Some(level))),
+            _ => Err(UpgradeError::UpgradeFailed),
+        }
+    }
+
+    fn upgrade<State>(
+        self,
+        upgrade: UpgradeQuery<State, Self::Upgrade>,
+    ) -> (DailyLevel<Level<Option<u64>, u64>>, UpgradeQuery<State, Self::From>) {
+        let (daily, old_level) = change_daily_level(self, upgrade.one().1.unwrap());
+
+        (daily, UpgradeQuery::One(None, Some(old_level)))
+    }
+
+    fn downgrade<State>(
+        upgraded: DailyLevel<Level<Option<u64>, u64>>,
+        _downgrade: UpgradeQuery<State, Self::From>,
+    ) -> (Self, UpgradeQuery<State, Self::Upgrade>) {
+        let (daily, level) = change_daily_level(upgraded, ());
+
+        (daily, UpgradeQuery::One(None, Some(level)))
+    }
+}
+
+fn level_request(daily: &DailyLevel) -> LevelRequest {
+    if daily.weekly {
+        LevelRequest::weekly()
+    } else {
+        LevelRequest::daily()
+    }
+}
+
+fn change_daily_level<OldLevel, NewLevel>(daily: DailyLevel<OldLevel>, new_level: NewLevel) -> (DailyLevel<NewLevel>, OldLevel) {
+    let old_level = daily.level;
+
+    let new_daily = DailyLevel {
+        level: new_level,
+        index: daily.index,
+        weekly: daily.weekly,
+        seconds_left: daily.seconds_left,
+    };
+
+    (new_daily, old_level)
+}
@@ -1,5 +1,5 @@
 use crate::{
-    api::request::{LevelRequest, LevelRequestType, LevelsRequest, Request, SearchFilters, UserRequest},
+    api::request::{LevelRequest, LevelRequestType, LevelsRequest, Request, SongInfoRequest, UserRequest},
     cache::{Cache, CacheEntry, CreatorKey, Lookup, NewgroundsSongKey},
     upgrade::{Upgradable, UpgradeError, UpgradeQuery},
 };
@@ -77,7 +77,7 @@ impl<Song, User> Upgradable<Level<Song, User>> for PartialLevel<Song, User> {
 impl<User> Upgradable<Level<Option<NewgroundsSong>, User>> for Level<Option<u64>, User> {
     type From = Option<u64>;
     type LookupKey = NewgroundsSongKey;
-    type Request = LevelsRequest;
+    type Request = SongInfoRequest;
     type Upgrade = Option<NewgroundsSong>;
 
     fn query_upgrade<C: Cache + Lookup<Self::LookupKey>>(
@@ -86,31 +86,20 @@ impl<User> Upgradable<Level<Option<NewgroundsSong>, User>> for Level<Option<u64>
         ignored_cached: bool,
     ) -> Result<UpgradeQuery<Self::Request, Self::Upgrade>, UpgradeError<C::Err>> {
         match self.base.custom_song {
-            Some(song_id) =>
-                query_upgrade_option!(
-                    cache,
-                    NewgroundsSongKey(song_id),
-                    LevelsRequest::default()
-                        .filter(SearchFilters::default().custom_song(song_id))
-                        .request_type(LevelRequestType::MostLiked),
-                    ignored_cached
-                ),
+            Some(song_id) => query_upgrade_option!(cache, NewgroundsSongKey(song_id), SongInfoRequest::new(song_id), ignored_cached),
             None => Ok(UpgradeQuery::One(None, Some(None))),
         }
     }
 
     fn process_query_result<C: Cache + Lookup<Self::LookupKey>>(
         &self,
-        cache: &C,
+        _cache: &C,
         resolved_query: UpgradeQuery<CacheEntry<<Self::Request as Request>::Result, C::CacheEntryMeta>, Self::Upgrade>,
     ) -> Result<UpgradeQuery<(), Self::Upgrade>, UpgradeError<C::Err>> {
         match resolved_query.one() {
             (None, Some(newgrounds_song)) => Ok(UpgradeQuery::One(None, Some(newgrounds_song))),
-            (Some(_), _) =>
-                match cache.lookup(&NewgroundsSongKey(self.base.custom_song.unwrap()))? {
-                    CacheEntry::Cached(song, _) => Ok(UpgradeQuery::One(None, Some(Some(song)))),
-                    _ => Ok(UpgradeQuery::One(None, Some(None))),
-                },
+            (Some(CacheEntry::Cached(song, _)), _) => Ok(UpgradeQuery::One(None, Some(Some(song)))),
+            (Some(_), _) => Ok(UpgradeQuery::One(None, Some(None))),
             _ => Err(UpgradeError::UpgradeFailed),
         }
     }
@@ -137,7 +126,7 @@ impl<User> Upgradable<Level<Option<NewgroundsSong>, User>> for Level<Option<u64>
 impl<User> Upgradable<PartialLevel<Option<NewgroundsSong>, User>> for PartialLevel<Option<u64>, User> {
     type From = Option<u64>;
     type LookupKey = NewgroundsSongKey;
-    type Request = LevelsRequest;
+    type Request = SongInfoRequest;
     type Upgrade = Option<NewgroundsSong>;
 
     fn query_upgrade<C: Cache + Lookup<Self::LookupKey>>(
@@ -146,31 +135,20 @@ impl<User> Upgradable<PartialLevel<Option<NewgroundsSong>, User>> for PartialLev
         ignored_cached: bool,
     ) -> Result<UpgradeQuery<Self::Request, Self::Upgrade>, UpgradeError<C::Err>> {
         match self.custom_song {
-            Some(song_id) =>
-                query_upgrade_option!(
-                    cache,
-                    NewgroundsSongKey(song_id),
-                    LevelsRequest::default()
-                        .filter(SearchFilters::default().custom_song(song_id))
-                        .request_type(LevelRequestType::MostLiked),
-                    ignored_cached
-                ),
+            Some(song_id) => query_upgrade_option!(cache, NewgroundsSongKey(song_id), SongInfoRequest::new(song_id), ignored_cached),
             None => Ok(UpgradeQuery::One(None, Some(None))),
         }
     }
 
     fn process_query_result<C: Cache + Lookup<Self::LookupKey>>(
         &self,
-        cache: &C,
+        _cache: &C,
         resolved_query: UpgradeQuery<CacheEntry<<Self::Request as Request>::Result, C::CacheEntryMeta>, Self::Upgrade>,
     ) -> Result<UpgradeQuery<(), Self::Upgrade>, UpgradeError<C::Err>> {
         match resolved_query.one() {
             (None, Some(newgrounds_song)) => Ok(UpgradeQuery::One(None, Some(newgrounds_song))),
-            (Some(_), _) =>
-                match cache.lookup(&NewgroundsSongKey(self.custom_song.unwrap()))? {
-                    CacheEntry::Cached(song, _) => Ok(UpgradeQuery::One(None, Some(Some(song)))),
-                    _ => Ok(UpgradeQuery::One(None, Some(None))),
-                },
+            (Some(CacheEntry::Cached(song, _)), _) => Ok(UpgradeQuery::One(None, Some(Some(song)))),
+            (Some(_), _) => Ok(UpgradeQuery::One(None, Some(None))),
             _ => Err(UpgradeError::UpgradeFailed),
         }
     }
@@ -2,7 +2,7 @@
 
 use crate::{
     api::{client::MakeRequest, request::Request},
-    cache::{Cache, CacheEntry, CanCache, CreatorKey, Key, Lookup, NewgroundsSongKey, Store},
+    cache::{Cache, CacheEntry, CanCache, Key, Lookup, StoreSecondary},
     error::{ApiError, CacheError, Error},
     future::refresh::RefreshCacheFuture,
     Gdcf,
@@ -10,7 +10,10 @@ use crate::{
 use futures::{Async, Future};
 use std::fmt::Debug;
 
+pub mod comment;
+pub mod daily;
 pub mod level;
+pub mod pack;
 pub mod user;
 
 #[derive(Debug)]
@@ -85,7 +88,7 @@ impl<R: Request, S> UpgradeQuery<R, S> {
     pub(crate) fn futurize<A, C>(self, gdcf: &Gdcf<A, C>) -> UpgradeQueryFuture<RefreshCacheFuture<R, A, C>, S>
     where
         A: MakeRequest<R>,
-        C: Cache + CanCache<R> + Store<CreatorKey> + Store<NewgroundsSongKey>,
+        C: Cache + CanCache<R> + StoreSecondary<R>,
     {
         match self {
             UpgradeQuery::One(request, data) =>
@@ -0,0 +1,76 @@
+use crate::{
+    api::request::{LevelRequestType, LevelsRequest, Request},
+    cache::{Cache, CacheEntry, Lookup},
+    upgrade::{Upgradable, UpgradeError, UpgradeQuery},
+};
+use gdcf_model::level::{MapPack, PartialLevel};
+
+impl Upgradable<MapPack<PartialLevel<Option<u64>, u64>>> for MapPack {
+    type From = Vec<u64>;
+    type LookupKey = LevelsRequest;
+    type Request = LevelsRequest;
+    type Upgrade = Vec<PartialLevel<Option<u64>, u64>>;
+
+    fn query_upgrade<C: Cache + Lookup<Self::LookupKey>>(
+        &self,
+        cache: &C,
+        ignored_cached: bool,
+    ) -> Result<UpgradeQuery<Self::Request, Self::Upgrade>, UpgradeError<C::Err>> {
+        query_upgrade!(cache, levels_request(self), levels_request(self), ignored_cached)
+    }
+
+    fn process_query_result<C: Cache + Lookup<Self::LookupKey>>(
+        &self,
+        _cache: &C,
+        resolved_query: UpgradeQuery<CacheEntry<<Self::Request as Request>::Result, C::CacheEntryMeta>, Self::Upgrade>,
+    ) -> Result<UpgradeQuery<(), Self::Upgrade>, UpgradeError<C::Err>> {
+        match resolved_query.one() {
+            (None, Some(levels)) => Ok(UpgradeQuery::One(None, Some(levels))),
+            (Some(CacheEntry::Cached(levels, _)), _) => Ok(UpgradeQuery::One(None, Some(levels))),
+            _ => Err(UpgradeError::UpgradeFailed),
+        }
+    }
+
+    fn upgrade<State>(
+        self,
+        upgrade: UpgradeQuery<State, Self::Upgrade>,
+    ) -> (MapPack<PartialLevel<Option<u64>, u64>>, UpgradeQuery<State, Self::From>) {
+        let (pack, level_ids) = change_map_pack_levels(self, upgrade.one().1.unwrap());
+
+        (pack, UpgradeQuery::One(None, Some(level_ids)))
+    }
+
+    fn downgrade<State>(
+        upgraded: MapPack<PartialLevel<Option<u64>, u64>>,
+        downgrade: UpgradeQuery<State, Self::From>,
+    ) -> (Self, UpgradeQuery<State, Self::Upgrade>) {
+        let (pack, levels) = change_map_pack_levels(upgraded, downgrade.one().1.unwrap());
+
+        (pack, UpgradeQuery::One(None, Some(levels)))
+    }
+}
+
+fn levels_request(pack: &MapPack) -> LevelsRequest {
+    let level_ids: Vec<_> = pack.levels.iter().map(ToString::to_string).collect();
+
+    LevelsRequest::default()
+        .search(level_ids.join(","))
+        .request_type(LevelRequestType::MapPack)
+}
+
+fn change_map_pack_levels<OldLevel, NewLevel>(pack: MapPack<OldLevel>, new_levels: Vec<NewLevel>) -> (MapPack<NewLevel>, Vec<OldLevel>) {
+    let old_levels = pack.levels;
+
+    let new_pack = MapPack {
+        levels: new_levels,
+        pack_id: pack.pack_id,
+        name: pack.name,
+        stars: pack.stars,
+        coins: pack.coins,
+        difficulty: pack.difficulty,
+        text_color: pack.text_color,
+        bar_color: pack.bar_color,
+    };
+
+    (new_pack, old_levels)
+}
@@ -3,10 +3,18 @@ use crate::{
     cache::{Cache, CacheEntry, Lookup},
     upgrade::{Upgradable, UpgradeError, UpgradeQuery},
 };
-use gdcf_model::user::{SearchedUser, User};
+use gdcf_model::user::User;
 
-impl Upgradable<User> for SearchedUser {
-    type From = SearchedUser;
+// Any partial user object that identifies a profile (such as a [`SearchedUser`] or a
+// [`ListedUser`]) can be upgraded into that profile
+//
+// [`SearchedUser`]: gdcf_model::user::SearchedUser
+// [`ListedUser`]: gdcf_model::user::ListedUser
+impl<U> Upgradable<User> for U
+where
+    for<'a> &'a U: Into<UserRequest>,
+{
+    type From = U;
     type LookupKey = UserRequest;
     type Request = UserRequest;
     type Upgrade = User;
@@ -16,12 +24,7 @@ impl Upgradable<User> for SearchedUser {
         cache: &C,
         ignored_cached: bool,
     ) -> Result<UpgradeQuery<Self::Request, Self::Upgrade>, UpgradeError<C::Err>> {
-        query_upgrade!(
-            cache,
-            UserRequest::new(self.account_id),
-            UserRequest::new(self.account_id),
-            ignored_cached
-        )
+        query_upgrade!(cache, self.into(), self.into(), ignored_cached)
     }
 
     fn process_query_result<C: Cache + Lookup<Self::LookupKey>>(
@@ -0,0 +1,5 @@
+DROP TABLE comment_user_meta;
+DROP TABLE comment_user;
+DROP TABLE level_comment_list_meta;
+DROP TABLE level_comment_request_results;
+DROP TABLE level_comment;
\ No newline at end of file
@@ -0,0 +1,39 @@
+CREATE TABLE level_comment (
+    comment_id BIGINT PRIMARY KEY,
+    user_id BIGINT NOT NULL,
+    content TEXT,
+    likes INTEGER NOT NULL,
+    is_flagged_spam BOOLEAN NOT NULL,
+    time_since_post TEXT NOT NULL,
+    progress SMALLINT,
+    is_elder_mod BOOLEAN NOT NULL,
+    special_color INTEGER
+);
+
+CREATE TABLE level_comment_request_results (
+    comment_id BIGINT NOT NULL,
+    request_hash BIGINT NOT NULL
+);
+
+CREATE TABLE level_comment_list_meta (
+    request_hash BIGINT PRIMARY KEY,
+    cached_at TIMESTAMP WITHOUT TIME ZONE,
+    absent BOOL NOT NULL DEFAULT FALSE
+);
+
+CREATE TABLE comment_user (
+    user_id BIGINT PRIMARY KEY,
+    name TEXT NOT NULL,
+    icon_index SMALLINT NOT NULL,
+    primary_color INTEGER NOT NULL,
+    secondary_color INTEGER NOT NULL,
+    icon_type SMALLINT NOT NULL,
+    has_glow BOOLEAN NOT NULL,
+    account_id BIGINT
+);
+
+CREATE TABLE comment_user_meta (
+    user_id BIGINT PRIMARY KEY,
+    cached_at TIMESTAMP WITHOUT TIME ZONE,
+    absent BOOL NOT NULL DEFAULT FALSE
+);
@@ -0,0 +1,28 @@
+CREATE TABLE leaderboard_entry (
+    request_hash BIGINT NOT NULL,
+    cached_at TIMESTAMP WITHOUT TIME ZONE NOT NULL,
+    rank INTEGER NOT NULL,
+    name TEXT NOT NULL,
+    user_id BIGINT NOT NULL,
+    stars INTEGER NOT NULL,
+    demons SMALLINT NOT NULL,
+    creator_points SMALLINT NOT NULL,
+    icon_index SMALLINT NOT NULL,
+    primary_color INTEGER NOT NULL,
+    secondary_color INTEGER NOT NULL,
+    secret_coins SMALLINT NOT NULL,
+    icon_type SMALLINT NOT NULL,
+    has_glow BOOLEAN NOT NULL,
+    account_id BIGINT NOT NULL,
+    user_coins SMALLINT NOT NULL,
+    diamonds SMALLINT NOT NULL,
+    PRIMARY KEY (request_hash, cached_at, rank)
+);
+
+CREATE INDEX leaderboard_entry_account_id ON leaderboard_entry (account_id);
+
+CREATE TABLE leaderboard_meta (
+    request_hash BIGINT PRIMARY KEY,
+    cached_at TIMESTAMP WITHOUT TIME ZONE,
+    absent BOOL NOT NULL DEFAULT FALSE
+);
@@ -0,0 +1,22 @@
+CREATE TABLE level_score (
+    request_hash BIGINT NOT NULL,
+    rank INTEGER NOT NULL,
+    name TEXT NOT NULL,
+    user_id BIGINT NOT NULL,
+    percent SMALLINT NOT NULL,
+    icon_index SMALLINT NOT NULL,
+    primary_color INTEGER NOT NULL,
+    secondary_color INTEGER NOT NULL,
+    coins SMALLINT NOT NULL,
+    icon_type SMALLINT NOT NULL,
+    has_glow BOOLEAN NOT NULL,
+    account_id BIGINT NOT NULL,
+    time_since_set TEXT NOT NULL,
+    PRIMARY KEY (request_hash, rank)
+);
+
+CREATE TABLE level_score_meta (
+    request_hash BIGINT PRIMARY KEY,
+    cached_at TIMESTAMP WITHOUT TIME ZONE,
+    absent BOOL NOT NULL DEFAULT FALSE
+);
@@ -0,0 +1,5 @@
+DROP TABLE gauntlet_list_meta;
+DROP TABLE gauntlet;
+DROP TABLE map_pack_list_meta;
+DROP TABLE map_pack_request_results;
+DROP TABLE map_pack;
\ No newline at end of file
@@ -0,0 +1,33 @@
+CREATE TABLE map_pack (
+    pack_id BIGINT PRIMARY KEY,
+    pack_name TEXT NOT NULL,
+    levels TEXT NOT NULL,
+    stars SMALLINT NOT NULL,
+    coins SMALLINT NOT NULL,
+    difficulty TEXT NOT NULL,
+    text_color INTEGER NOT NULL,
+    bar_color INTEGER NOT NULL
+);
+
+CREATE TABLE map_pack_request_results (
+    pack_id BIGINT NOT NULL,
+    request_hash BIGINT NOT NULL,
+    PRIMARY KEY (pack_id, request_hash)
+);
+
+CREATE TABLE map_pack_list_meta (
+    request_hash BIGINT PRIMARY KEY,
+    cached_at TIMESTAMP WITHOUT TIME ZONE,
+    absent BOOL NOT NULL DEFAULT FALSE
+);
+
+CREATE TABLE gauntlet (
+    gauntlet_id BIGINT PRIMARY KEY,
+    levels TEXT NOT NULL
+);
+
+CREATE TABLE gauntlet_list_meta (
+    request_hash BIGINT PRIMARY KEY,
+    cached_at TIMESTAMP WITHOUT TIME ZONE,
+    absent BOOL NOT NULL DEFAULT FALSE
+);
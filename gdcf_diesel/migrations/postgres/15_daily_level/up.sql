@@ -0,0 +1,12 @@
+CREATE TABLE daily_level (
+    request_key BIGINT PRIMARY KEY,
+    daily_index INTEGER NOT NULL,
+    seconds_left INTEGER NOT NULL,
+    level_id BIGINT
+);
+
+CREATE TABLE daily_level_meta (
+    request_key BIGINT PRIMARY KEY,
+    cached_at TIMESTAMP WITHOUT TIME ZONE,
+    absent BOOL NOT NULL DEFAULT FALSE
+);
@@ -0,0 +1,4 @@
+DROP TABLE message_meta;
+DROP TABLE message_list_meta;
+DROP TABLE message_request_results;
+DROP TABLE message;
\ No newline at end of file
@@ -0,0 +1,30 @@
+CREATE TABLE message (
+    message_id BIGINT NOT NULL,
+    sent BOOL NOT NULL,
+    account_id BIGINT NOT NULL,
+    user_id BIGINT NOT NULL,
+    subject TEXT NOT NULL,
+    username TEXT NOT NULL,
+    time_since_sent TEXT NOT NULL,
+    is_read BOOL NOT NULL,
+    body TEXT,
+    PRIMARY KEY (message_id, sent)
+);
+
+CREATE TABLE message_request_results (
+    message_id BIGINT NOT NULL,
+    request_hash BIGINT NOT NULL,
+    PRIMARY KEY (message_id, request_hash)
+);
+
+CREATE TABLE message_list_meta (
+    request_hash BIGINT PRIMARY KEY,
+    cached_at TIMESTAMP WITHOUT TIME ZONE,
+    absent BOOL NOT NULL DEFAULT FALSE
+);
+
+CREATE TABLE message_meta (
+    request_hash BIGINT PRIMARY KEY,
+    cached_at TIMESTAMP WITHOUT TIME ZONE,
+    absent BOOL NOT NULL DEFAULT FALSE
+);
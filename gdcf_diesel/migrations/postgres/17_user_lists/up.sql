@@ -0,0 +1,42 @@
+CREATE TABLE listed_user (
+    request_hash BIGINT NOT NULL,
+    position INTEGER NOT NULL,
+    name TEXT NOT NULL,
+    user_id BIGINT NOT NULL,
+    icon_index SMALLINT NOT NULL,
+    primary_color INTEGER NOT NULL,
+    secondary_color INTEGER NOT NULL,
+    icon_type SMALLINT NOT NULL,
+    has_glow BOOLEAN NOT NULL,
+    account_id BIGINT NOT NULL,
+    PRIMARY KEY (request_hash, position)
+);
+
+CREATE TABLE user_list_meta (
+    request_hash BIGINT PRIMARY KEY,
+    cached_at TIMESTAMP WITHOUT TIME ZONE,
+    absent BOOL NOT NULL DEFAULT FALSE
+);
+
+CREATE TABLE friend_request (
+    request_hash BIGINT NOT NULL,
+    request_id BIGINT NOT NULL,
+    name TEXT NOT NULL,
+    user_id BIGINT NOT NULL,
+    icon_index SMALLINT NOT NULL,
+    primary_color INTEGER NOT NULL,
+    secondary_color INTEGER NOT NULL,
+    icon_type SMALLINT NOT NULL,
+    has_glow BOOLEAN NOT NULL,
+    account_id BIGINT NOT NULL,
+    message TEXT,
+    time_since_sent TEXT NOT NULL,
+    unread BOOLEAN NOT NULL,
+    PRIMARY KEY (request_hash, request_id)
+);
+
+CREATE TABLE friend_request_meta (
+    request_hash BIGINT PRIMARY KEY,
+    cached_at TIMESTAMP WITHOUT TIME ZONE,
+    absent BOOL NOT NULL DEFAULT FALSE
+);
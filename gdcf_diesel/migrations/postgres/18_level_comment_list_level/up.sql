@@ -0,0 +1,4 @@
+CREATE TABLE level_comment_list_level (
+    request_hash BIGINT PRIMARY KEY,
+    level_id BIGINT NOT NULL
+);
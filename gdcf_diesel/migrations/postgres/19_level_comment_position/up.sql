@@ -0,0 +1,11 @@
+-- The previously cached results don't have a position, so they have to be refetched
+DELETE FROM level_comment_list_meta;
+
+DROP TABLE level_comment_request_results;
+
+CREATE TABLE level_comment_request_results (
+    request_hash BIGINT NOT NULL,
+    position INTEGER NOT NULL,
+    comment_id BIGINT NOT NULL,
+    PRIMARY KEY (request_hash, position)
+);
@@ -0,0 +1,4 @@
+DELETE FROM daily_level WHERE daily_index IS NULL OR seconds_left IS NULL;
+
+ALTER TABLE daily_level ALTER COLUMN daily_index SET NOT NULL;
+ALTER TABLE daily_level ALTER COLUMN seconds_left SET NOT NULL;
@@ -0,0 +1,4 @@
+-- The daily level can be downloaded without knowing when it rotates, in which case only the ID of
+-- the downloaded level is known
+ALTER TABLE daily_level ALTER COLUMN daily_index DROP NOT NULL;
+ALTER TABLE daily_level ALTER COLUMN seconds_left DROP NOT NULL;
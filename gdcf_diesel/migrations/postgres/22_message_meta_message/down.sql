@@ -0,0 +1,8 @@
+DROP TABLE message_meta;
+
+CREATE TABLE message_meta (
+    request_hash BIGINT PRIMARY KEY,
+    owner_id BIGINT NOT NULL,
+    cached_at TIMESTAMP WITHOUT TIME ZONE,
+    absent BOOL NOT NULL DEFAULT FALSE
+);
@@ -0,0 +1,15 @@
+-- The meta entries of message contents need to reference the message they belong to, so that the
+-- content can be removed together with its entry. Since we cannot tell which message the existing
+-- entries belong to, they are dropped together with all cached message contents.
+UPDATE message SET body = NULL;
+
+DROP TABLE message_meta;
+
+CREATE TABLE message_meta (
+    request_hash BIGINT PRIMARY KEY,
+    owner_id BIGINT NOT NULL,
+    message_id BIGINT NOT NULL,
+    sent BOOL NOT NULL,
+    cached_at TIMESTAMP WITHOUT TIME ZONE,
+    absent BOOL NOT NULL DEFAULT FALSE
+);
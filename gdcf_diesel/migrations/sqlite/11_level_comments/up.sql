@@ -0,0 +1,39 @@
+CREATE TABLE level_comment (
+    comment_id INTEGER PRIMARY KEY,
+    user_id INTEGER NOT NULL,
+    content TEXT,
+    likes INTEGER NOT NULL,
+    is_flagged_spam BOOLEAN NOT NULL,
+    time_since_post TEXT NOT NULL,
+    progress INTEGER,
+    is_elder_mod BOOLEAN NOT NULL,
+    special_color INTEGER
+);
+
+CREATE TABLE level_comment_request_results (
+    comment_id INTEGER NOT NULL,
+    request_hash INTEGER NOT NULL
+);
+
+CREATE TABLE level_comment_list_meta (
+    request_hash INTEGER PRIMARY KEY,
+    cached_at INTEGER,
+    absent BOOL NOT NULL DEFAULT FALSE
+);
+
+CREATE TABLE comment_user (
+    user_id INTEGER PRIMARY KEY,
+    name TEXT NOT NULL,
+    icon_index INTEGER NOT NULL,
+    primary_color INTEGER NOT NULL,
+    secondary_color INTEGER NOT NULL,
+    icon_type INTEGER NOT NULL,
+    has_glow BOOLEAN NOT NULL,
+    account_id INTEGER
+);
+
+CREATE TABLE comment_user_meta (
+    user_id INTEGER PRIMARY KEY,
+    cached_at INTEGER,
+    absent BOOL NOT NULL DEFAULT FALSE
+);
@@ -0,0 +1,2 @@
+DROP TABLE leaderboard_meta;
+DROP TABLE leaderboard_entry;
\ No newline at end of file
@@ -0,0 +1,28 @@
+CREATE TABLE leaderboard_entry (
+    request_hash INTEGER NOT NULL,
+    cached_at INTEGER NOT NULL,
+    rank INTEGER NOT NULL,
+    name TEXT NOT NULL,
+    user_id INTEGER NOT NULL,
+    stars INTEGER NOT NULL,
+    demons INTEGER NOT NULL,
+    creator_points INTEGER NOT NULL,
+    icon_index INTEGER NOT NULL,
+    primary_color INTEGER NOT NULL,
+    secondary_color INTEGER NOT NULL,
+    secret_coins INTEGER NOT NULL,
+    icon_type INTEGER NOT NULL,
+    has_glow BOOLEAN NOT NULL,
+    account_id INTEGER NOT NULL,
+    user_coins INTEGER NOT NULL,
+    diamonds INTEGER NOT NULL,
+    PRIMARY KEY (request_hash, cached_at, rank)
+);
+
+CREATE INDEX leaderboard_entry_account_id ON leaderboard_entry (account_id);
+
+CREATE TABLE leaderboard_meta (
+    request_hash INTEGER PRIMARY KEY,
+    cached_at INTEGER,
+    absent BOOL NOT NULL DEFAULT FALSE
+);
@@ -0,0 +1,2 @@
+DROP TABLE level_score_meta;
+DROP TABLE level_score;
\ No newline at end of file
@@ -0,0 +1,33 @@
+CREATE TABLE map_pack (
+    pack_id INTEGER PRIMARY KEY,
+    pack_name TEXT NOT NULL,
+    levels TEXT NOT NULL,
+    stars INTEGER NOT NULL,
+    coins INTEGER NOT NULL,
+    difficulty TEXT NOT NULL,
+    text_color INTEGER NOT NULL,
+    bar_color INTEGER NOT NULL
+);
+
+CREATE TABLE map_pack_request_results (
+    pack_id INTEGER NOT NULL,
+    request_hash INTEGER NOT NULL,
+    PRIMARY KEY (pack_id, request_hash)
+);
+
+CREATE TABLE map_pack_list_meta (
+    request_hash INTEGER PRIMARY KEY,
+    cached_at INTEGER,
+    absent BOOL NOT NULL DEFAULT FALSE
+);
+
+CREATE TABLE gauntlet (
+    gauntlet_id INTEGER PRIMARY KEY,
+    levels TEXT NOT NULL
+);
+
+CREATE TABLE gauntlet_list_meta (
+    request_hash INTEGER PRIMARY KEY,
+    cached_at INTEGER,
+    absent BOOL NOT NULL DEFAULT FALSE
+);
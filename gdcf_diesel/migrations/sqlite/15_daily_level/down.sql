@@ -0,0 +1,2 @@
+DROP TABLE daily_level_meta;
+DROP TABLE daily_level;
\ No newline at end of file
@@ -0,0 +1,12 @@
+CREATE TABLE daily_level (
+    request_key INTEGER PRIMARY KEY,
+    daily_index INTEGER NOT NULL,
+    seconds_left INTEGER NOT NULL,
+    level_id INTEGER
+);
+
+CREATE TABLE daily_level_meta (
+    request_key INTEGER PRIMARY KEY,
+    cached_at INTEGER,
+    absent BOOL NOT NULL DEFAULT FALSE
+);
@@ -0,0 +1,4 @@
+DROP TABLE friend_request_meta;
+DROP TABLE friend_request;
+DROP TABLE user_list_meta;
+DROP TABLE listed_user;
@@ -0,0 +1 @@
+DROP TABLE level_comment_list_level;
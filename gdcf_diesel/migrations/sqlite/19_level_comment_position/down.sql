@@ -0,0 +1,8 @@
+DELETE FROM level_comment_list_meta;
+
+DROP TABLE level_comment_request_results;
+
+CREATE TABLE level_comment_request_results (
+    comment_id INTEGER NOT NULL,
+    request_hash INTEGER NOT NULL
+);
@@ -0,0 +1,14 @@
+CREATE TABLE daily_level_old (
+    request_key INTEGER PRIMARY KEY,
+    daily_index INTEGER NOT NULL,
+    seconds_left INTEGER NOT NULL,
+    level_id INTEGER
+);
+
+INSERT INTO daily_level_old
+    SELECT request_key, daily_index, seconds_left, level_id FROM daily_level
+    WHERE daily_index IS NOT NULL AND seconds_left IS NOT NULL;
+
+DROP TABLE daily_level;
+
+ALTER TABLE daily_level_old RENAME TO daily_level;
@@ -0,0 +1,14 @@
+-- The daily level can be downloaded without knowing when it rotates, in which case only the ID of
+-- the downloaded level is known
+CREATE TABLE daily_level_new (
+    request_key INTEGER PRIMARY KEY,
+    daily_index INTEGER,
+    seconds_left INTEGER,
+    level_id INTEGER
+);
+
+INSERT INTO daily_level_new SELECT request_key, daily_index, seconds_left, level_id FROM daily_level;
+
+DROP TABLE daily_level;
+
+ALTER TABLE daily_level_new RENAME TO daily_level;
@@ -0,0 +1,29 @@
+-- Messages can only be read by their sender and their recipient, so they need to be stored
+-- separately for each account that retrieved them. Since we cannot know which account retrieved the
+-- messages that are already cached, they are dropped.
+DELETE FROM message_request_results;
+DELETE FROM message_list_meta;
+
+DROP TABLE message_meta;
+DROP TABLE message;
+
+CREATE TABLE message (
+    owner_id INTEGER NOT NULL,
+    message_id INTEGER NOT NULL,
+    sent BOOL NOT NULL,
+    account_id INTEGER NOT NULL,
+    user_id INTEGER NOT NULL,
+    subject TEXT NOT NULL,
+    username TEXT NOT NULL,
+    time_since_sent TEXT NOT NULL,
+    is_read BOOL NOT NULL,
+    body TEXT,
+    PRIMARY KEY (owner_id, message_id, sent)
+);
+
+CREATE TABLE message_meta (
+    request_hash INTEGER PRIMARY KEY,
+    owner_id INTEGER NOT NULL,
+    cached_at INTEGER,
+    absent BOOL NOT NULL DEFAULT FALSE
+);
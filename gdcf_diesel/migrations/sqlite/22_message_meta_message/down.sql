@@ -0,0 +1,8 @@
+DROP TABLE message_meta;
+
+CREATE TABLE message_meta (
+    request_hash INTEGER PRIMARY KEY,
+    owner_id INTEGER NOT NULL,
+    cached_at INTEGER,
+    absent BOOL NOT NULL DEFAULT FALSE
+);
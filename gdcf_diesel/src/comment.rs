@@ -0,0 +1,155 @@
+use crate::{key::DatabaseKey, meta::Entry, wrap::Wrapped, Cache, Error};
+use diesel::{backend::Backend, deserialize::FromSqlRow, ExpressionMethods, QueryDsl, Queryable, RunQueryDsl};
+use gdcf::{
+    api::request::LevelCommentsRequest,
+    cache::{CacheEntry, Lookup, Store},
+};
+use gdcf_model::{comment::LevelComment, user::Color};
+use log::{debug, warn};
+
+diesel_stuff! {
+    level_comment (comment_id, LevelComment<()>) {
+        (comment_id, comment_id, u64),
+        (user_id, user_id, u64),
+        (content, content, Option<String>),
+        (likes, likes, i32),
+        (is_flagged_spam, is_flagged_spam, bool),
+        (time_since_post, time_since_post, String),
+        (progress, progress, Option<u8>),
+        (is_elder_mod, is_elder_mod, bool),
+        (special_color, special_color, Option<Color>)
+    } with {
+        user: ()
+    }
+}
+
+// Metadata table associating the hashes of cached requests with the comment ids the request
+// returned, in the order the server provided them in
+table! {
+    level_comment_request_results (request_hash, position) {
+        request_hash -> Int8,
+        position -> Int4,
+        comment_id -> Int8,
+    }
+}
+
+// Metadata table storing information about when a whole comment list was cached
+meta_table!(level_comment_list_meta, request_hash);
+
+// Metadata table remembering which level a cached comment list belongs to, so that all cached
+// pages of a level's comments can be invalidated once a new comment is posted
+table! {
+    level_comment_list_level (request_hash) {
+        request_hash -> Int8,
+        level_id -> Int8,
+    }
+}
+
+allow_tables_to_appear_in_same_query!(level_comment_request_results, level_comment);
+
+joinable!(level_comment_request_results -> level_comment(comment_id));
+
+impl Lookup<LevelCommentsRequest> for Cache {
+    fn lookup(&self, key: &LevelCommentsRequest) -> Result<CacheEntry<Vec<LevelComment>, Entry>, Self::Err> {
+        use diesel::JoinOnDsl;
+
+        let connection = self.pool.get()?;
+
+        let entry = handle_missing!(level_comment_list_meta::table
+            .filter(level_comment_list_meta::request_hash.eq(key.database_key()))
+            .get_result(&connection));
+
+        let entry = self.entry(entry, self.expiry.level_comments);
+
+        if entry.absent {
+            return Ok(CacheEntry::MarkedAbsent(entry))
+        }
+
+        let comments: Vec<_> = handle_missing!(level_comment::table
+            .inner_join(level_comment_request_results::table.on(level_comment::comment_id.eq(level_comment_request_results::comment_id)))
+            .filter(level_comment_request_results::request_hash.eq(key.database_key()))
+            .order(level_comment_request_results::position.asc())
+            .select(level_comment::all_columns)
+            .load(&connection))
+        .into_iter()
+        .map(|row: Wrapped<_>| row.0)
+        .collect();
+
+        Ok(CacheEntry::Cached(comments, entry))
+    }
+}
+
+impl Store<LevelCommentsRequest> for Cache {
+    fn mark_absent(&mut self, key: &LevelCommentsRequest) -> Result<Entry, Self::Err> {
+        warn!("Marking results of LevelCommentsRequest with key {} as absent!", key);
+
+        let entry = Entry::absent(key.database_key());
+
+        update_entry!(self, entry, level_comment_list_meta::table, level_comment_list_meta::request_hash);
+
+        self.remember_comment_list_level(key)?;
+
+        Ok(entry)
+    }
+
+    fn store(&mut self, comments: &Vec<LevelComment>, key: &LevelCommentsRequest) -> Result<Entry, Self::Err> {
+        debug!("Storing result of LevelCommentsRequest with key {}", key);
+
+        let db_key = key.database_key();
+
+        let conn = self.pool.get()?;
+
+        diesel::delete(level_comment_request_results::table)
+            .filter(level_comment_request_results::request_hash.eq(db_key))
+            .execute(&conn)?;
+
+        for (position, comment) in comments.iter().enumerate() {
+            upsert!(self, comment, level_comment::table, level_comment::comment_id);
+
+            diesel::insert_into(level_comment_request_results::table)
+                .values((
+                    level_comment_request_results::request_hash.eq(db_key),
+                    level_comment_request_results::position.eq(position as i32),
+                    level_comment_request_results::comment_id.eq(comment.comment_id as i64),
+                ))
+                .execute(&conn)?;
+        }
+
+        let entry = Entry::new(db_key);
+
+        update_entry!(self, entry, level_comment_list_meta::table, level_comment_list_meta::request_hash);
+
+        self.remember_comment_list_level(key)?;
+
+        Ok(entry)
+    }
+}
+
+impl Cache {
+    fn remember_comment_list_level(&self, key: &LevelCommentsRequest) -> Result<(), Error> {
+        let conn = self.pool.get()?;
+
+        diesel::delete(level_comment_list_level::table.filter(level_comment_list_level::request_hash.eq(key.database_key())))
+            .execute(&conn)?;
+        diesel::insert_into(level_comment_list_level::table)
+            .values((
+                level_comment_list_level::request_hash.eq(key.database_key()),
+                level_comment_list_level::level_id.eq(key.level_id as i64),
+            ))
+            .execute(&conn)?;
+
+        Ok(())
+    }
+
+    /// Invalidates all cached pages of comments on the given level
+    pub(crate) fn invalidate_level_comments(&self, level_id: u64) -> Result<(), Error> {
+        let hashes: Vec<i64> = level_comment_list_level::table
+            .filter(level_comment_list_level::level_id.eq(level_id as i64))
+            .select(level_comment_list_level::request_hash)
+            .load(&self.pool.get()?)?;
+
+        invalidate_entry!(self, level_comment_list_meta, level_comment_list_meta::request_hash.eq_any(hashes));
+
+        Ok(())
+    }
+}
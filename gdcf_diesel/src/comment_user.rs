@@ -0,0 +1,129 @@
+use crate::{key::DatabaseKey, meta::Entry, wrap::Wrapped, Cache};
+use diesel::{backend::Backend, deserialize::FromSqlRow, sql_types::*, ExpressionMethods, QueryDsl, Queryable, RunQueryDsl};
+use gdcf::cache::{CacheEntry, CommentUserKey, Lookup, Store};
+use gdcf_model::{comment::CommentUser, user::Color, GameMode};
+use log::{debug, warn};
+
+// The user id isn't part of the CommentUser object, but of the comment it was provided along with,
+// so we cannot use `diesel_stuff!` here.
+table! {
+    comment_user (user_id) {
+        user_id -> Int8,
+        name -> Text,
+        icon_index -> Int2,
+        primary_color -> Int4,
+        secondary_color -> Int4,
+        icon_type -> Int2,
+        has_glow -> Bool,
+        account_id -> Nullable<Int8>,
+    }
+}
+
+type Row = (i64, String, i16, i32, i32, i16, bool, Option<i64>);
+type SqlType = (Int8, Text, Int2, Int4, Int4, Int2, Bool, Nullable<Int8>);
+
+impl<DB: Backend> Queryable<SqlType, DB> for Wrapped<CommentUser>
+where
+    Row: FromSqlRow<SqlType, DB>,
+{
+    type Row = Row;
+
+    fn build((_, name, icon_index, primary_color, secondary_color, icon_type, has_glow, account_id): Self::Row) -> Self {
+        Wrapped(CommentUser {
+            name,
+            icon_index: __for_queryable!(icon_index, u16),
+            primary_color: __for_queryable!(primary_color, Color),
+            secondary_color: __for_queryable!(secondary_color, Color),
+            icon_type: __for_queryable!(icon_type, GameMode),
+            has_glow,
+            account_id: __for_queryable!(account_id, Option<u64>),
+        })
+    }
+}
+
+type Values<'a> = (
+    diesel::dsl::Eq<comment_user::user_id, i64>,
+    diesel::dsl::Eq<comment_user::name, &'a str>,
+    diesel::dsl::Eq<comment_user::icon_index, i16>,
+    diesel::dsl::Eq<comment_user::primary_color, i32>,
+    diesel::dsl::Eq<comment_user::secondary_color, i32>,
+    diesel::dsl::Eq<comment_user::icon_type, i16>,
+    diesel::dsl::Eq<comment_user::has_glow, bool>,
+    diesel::dsl::Eq<comment_user::account_id, Option<i64>>,
+);
+
+fn values(key: u64, user: &CommentUser) -> Values<'_> {
+    use comment_user::columns::*;
+
+    (
+        user_id.eq(key as i64),
+        name.eq(&user.name[..]),
+        icon_index.eq(__for_values!(user.icon_index, u16)),
+        primary_color.eq(__for_values!(user.primary_color, Color)),
+        secondary_color.eq(__for_values!(user.secondary_color, Color)),
+        icon_type.eq(__for_values!(user.icon_type, GameMode)),
+        has_glow.eq(user.has_glow),
+        account_id.eq(__for_values!(user.account_id, Option<u64>)),
+    )
+}
+
+meta_table!(comment_user_meta, user_id);
+
+impl Lookup<CommentUserKey> for Cache {
+    fn lookup(&self, key: &CommentUserKey) -> Result<CacheEntry<CommentUser, Entry>, Self::Err> {
+        let connection = self.pool.get()?;
+
+        let entry = handle_missing!(comment_user_meta::table
+            .filter(comment_user_meta::user_id.eq(key.database_key()))
+            .get_result(&connection));
+
+        let entry = self.entry(entry, self.expiry.comment_user);
+
+        if entry.absent {
+            debug!("Object marked as absent!");
+
+            return Ok(CacheEntry::MarkedAbsent(entry))
+        }
+
+        let wrapped: Wrapped<CommentUser> = handle_missing!(comment_user::table
+            .filter(comment_user::user_id.eq(key.database_key()))
+            .get_result(&connection));
+
+        Ok(CacheEntry::Cached(wrapped.0, entry))
+    }
+}
+
+impl Store<CommentUserKey> for Cache {
+    fn mark_absent(&mut self, key: &CommentUserKey) -> Result<Entry, Self::Err> {
+        warn!("Marking CommentUser with key {} as absent!", key);
+
+        let entry = Entry::absent(key.database_key());
+
+        update_entry!(self, entry, comment_user_meta::table, comment_user_meta::user_id);
+
+        Ok(entry)
+    }
+
+    fn store(&mut self, user: &CommentUser, key: &CommentUserKey) -> Result<Entry, Self::Err> {
+        debug!("Storing {} under key {}", user, key);
+
+        let entry = Entry::new(key.database_key());
+
+        update_entry!(self, entry, comment_user_meta::table, comment_user_meta::user_id);
+
+        #[cfg(feature = "pg")]
+        diesel::insert_into(comment_user::table)
+            .values(values(key.0, user))
+            .on_conflict(comment_user::user_id)
+            .do_update()
+            .set(values(key.0, user))
+            .execute(&self.pool.get()?)?;
+
+        #[cfg(feature = "sqlite")]
+        diesel::replace_into(comment_user::table)
+            .values(values(key.0, user))
+            .execute(&self.pool.get()?)?;
+
+        Ok(entry)
+    }
+}
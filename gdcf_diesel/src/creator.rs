@@ -27,4 +27,4 @@ diesel_stuff! {
 meta_table!(creator_meta, user_id);
 
 store_simply!(CreatorKey, creator, creator_meta, user_id);
-lookup_simply!(CreatorKey, creator, creator_meta, user_id);
+lookup_simply!(CreatorKey, creator, creator_meta, user_id, creator);
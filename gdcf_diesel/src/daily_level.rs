@@ -0,0 +1,227 @@
+use crate::{
+    key::DatabaseKey,
+    meta::{DatabaseEntry, Entry},
+    Cache, Error,
+};
+use chrono::{Duration, Utc};
+use diesel::{ExpressionMethods, OptionalExtension, QueryDsl, RunQueryDsl};
+use gdcf::{
+    api::request::{DailyLevelRequest, LevelRequest},
+    cache::{CacheEntry, Lookup, Store},
+};
+use gdcf_model::level::{DailyLevel, Level};
+use log::{debug, warn};
+
+// The daily level and weekly demon are stored under the special level IDs used to download them
+// (`-1` and `-2` respectively). Once the level itself has been downloaded, its actual ID is
+// remembered here, until the server reports a new daily index.
+//
+// If the level is downloaded without a `DailyLevelRequest` having been made beforehand (or after
+// the daily level rotated), the index and time left are unknown, and only the level ID is stored.
+table! {
+    daily_level (request_key) {
+        request_key -> Int8,
+        daily_index -> Nullable<Int4>,
+        seconds_left -> Nullable<Int4>,
+        level_id -> Nullable<Int8>,
+    }
+}
+
+type Values = (
+    diesel::dsl::Eq<daily_level::request_key, i64>,
+    diesel::dsl::Eq<daily_level::daily_index, Option<i32>>,
+    diesel::dsl::Eq<daily_level::seconds_left, Option<i32>>,
+    diesel::dsl::Eq<daily_level::level_id, Option<i64>>,
+);
+
+fn values(key: i64, daily: Option<&DailyLevel>, resolved_level: Option<i64>) -> Values {
+    use daily_level::columns::*;
+
+    (
+        request_key.eq(key),
+        daily_index.eq(daily.map(|daily| __for_values!(daily.index, u32))),
+        seconds_left.eq(daily.map(|daily| __for_values!(daily.seconds_left, u32))),
+        level_id.eq(resolved_level),
+    )
+}
+
+/// Inserts the given values into the `daily_level` table, replacing the row for the same request
+/// key if one already exists
+macro_rules! upsert_daily_level {
+    ($conn: expr, $values: expr) => {{
+        #[cfg(feature = "pg")]
+        diesel::insert_into(daily_level::table)
+            .values($values)
+            .on_conflict(daily_level::request_key)
+            .do_update()
+            .set($values)
+            .execute($conn)?;
+
+        #[cfg(feature = "sqlite")]
+        diesel::replace_into(daily_level::table).values($values).execute($conn)?;
+    }};
+}
+
+// Metadata table storing information about when the daily level information was cached. Unlike all
+// other meta tables, the entries in this one don't expire after a configurable duration, but once
+// the daily level rotates.
+meta_table!(daily_level_meta, request_key);
+
+impl Cache {
+    /// Looks up the level currently stored as the daily level/weekly demon referred to by the given
+    /// [`LevelRequest`] for a [`SpecialLevel`](gdcf::api::request::SpecialLevel).
+    ///
+    /// The returned entry expires at the same time as the information about the daily level. If it
+    /// is unknown when the daily level rotates, it expires like the level itself would.
+    pub(crate) fn lookup_daily_level(&self, key: &LevelRequest) -> Result<CacheEntry<Level<Option<u64>, u64>, Entry>, Error> {
+        let connection = self.pool.get()?;
+
+        let entry: Option<DatabaseEntry> = daily_level_meta::table
+            .filter(daily_level_meta::request_key.eq(key.database_key()))
+            .get_result(&connection)
+            .optional()?;
+
+        if let Some(entry) = entry {
+            if entry.absent {
+                return Ok(CacheEntry::MarkedAbsent(self.entry(entry, self.expiry.absent)))
+            }
+        }
+
+        let (seconds_left, level_id): (Option<i32>, Option<i64>) = handle_missing!(daily_level::table
+            .filter(daily_level::request_key.eq(key.database_key()))
+            .select((daily_level::seconds_left, daily_level::level_id))
+            .get_result(&connection));
+
+        let level_id = match level_id {
+            Some(level_id) => level_id,
+            None => return Ok(CacheEntry::Missing),
+        };
+
+        match self.lookup(&LevelRequest::new(level_id as u64))? {
+            CacheEntry::Cached(level, level_entry) =>
+                match (entry, seconds_left) {
+                    (Some(entry), Some(seconds_left)) =>
+                        Ok(CacheEntry::Cached(level, self.entry(entry, Duration::seconds(seconds_left.into())))),
+                    _ => Ok(CacheEntry::Cached(level, level_entry)),
+                },
+            _ => Ok(CacheEntry::Missing),
+        }
+    }
+
+    /// Remembers the ID of the level that was downloaded via the given [`LevelRequest`] for a
+    /// [`SpecialLevel`](gdcf::api::request::SpecialLevel).
+    pub(crate) fn resolve_daily_level(&self, key: &LevelRequest, level_id: u64) -> Result<(), Error> {
+        let db_key = key.database_key();
+        let conn = self.pool.get()?;
+
+        let entry: Option<DatabaseEntry> = daily_level_meta::table
+            .filter(daily_level_meta::request_key.eq(db_key))
+            .get_result(&conn)
+            .optional()?;
+
+        let seconds_left: Option<i32> = daily_level::table
+            .filter(daily_level::request_key.eq(db_key))
+            .select(daily_level::seconds_left)
+            .get_result::<Option<i32>>(&conn)
+            .optional()?
+            .and_then(|seconds_left| seconds_left);
+
+        let rotated = match (entry, seconds_left) {
+            (Some(entry), Some(seconds_left)) => entry.absent || self.entry(entry, Duration::seconds(seconds_left.into())).expired,
+            _ => true,
+        };
+
+        if rotated {
+            // Whatever we knew about the daily level is outdated (or we never knew anything), so
+            // all we can remember is which level we just downloaded
+            debug!(
+                "Daily level information for key {} is outdated, only remembering level {}",
+                db_key, level_id
+            );
+
+            upsert_daily_level!(&conn, values(db_key, None, Some(level_id as i64)));
+        } else {
+            diesel::update(daily_level::table.filter(daily_level::request_key.eq(db_key)))
+                .set(daily_level::level_id.eq(level_id as i64))
+                .execute(&conn)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Lookup<DailyLevelRequest> for Cache {
+    fn lookup(&self, key: &DailyLevelRequest) -> Result<CacheEntry<DailyLevel, Entry>, Self::Err> {
+        let connection = self.pool.get()?;
+
+        let entry: DatabaseEntry = handle_missing!(daily_level_meta::table
+            .filter(daily_level_meta::request_key.eq(key.database_key()))
+            .get_result(&connection));
+
+        if entry.absent {
+            return Ok(CacheEntry::MarkedAbsent(self.entry(entry, self.expiry.absent)))
+        }
+
+        let (index, seconds_left) = match handle_missing!(daily_level::table
+            .filter(daily_level::request_key.eq(key.database_key()))
+            .select((daily_level::daily_index, daily_level::seconds_left))
+            .get_result::<(Option<i32>, Option<i32>)>(&connection))
+        {
+            (Some(index), Some(seconds_left)) => (index, seconds_left),
+            _ => return Ok(CacheEntry::Missing),
+        };
+
+        let entry = self.entry(entry, Duration::seconds(seconds_left.into()));
+
+        // The amount of seconds stored is relative to the time the information was cached
+        let elapsed = (Utc::now().naive_utc() - entry.cached_at).num_seconds();
+        let seconds_left = (i64::from(seconds_left) - elapsed).max(0);
+
+        Ok(CacheEntry::Cached(
+            DailyLevel {
+                level: (),
+                index: __for_queryable!(index, u32),
+                weekly: key.weekly,
+                seconds_left: __for_queryable!(seconds_left, u32),
+            },
+            entry,
+        ))
+    }
+}
+
+impl Store<DailyLevelRequest> for Cache {
+    fn mark_absent(&mut self, key: &DailyLevelRequest) -> Result<Entry, Self::Err> {
+        warn!("Marking result of DailyLevelRequest with key {} as absent!", key);
+
+        let entry = Entry::absent(key.database_key());
+
+        update_entry!(self, entry, daily_level_meta::table, daily_level_meta::request_key);
+
+        Ok(entry)
+    }
+
+    fn store(&mut self, daily: &DailyLevel, key: &DailyLevelRequest) -> Result<Entry, Self::Err> {
+        debug!("Storing {} under key {}", daily, key);
+
+        let db_key = key.database_key();
+
+        let conn = self.pool.get()?;
+
+        // If the daily level hasn't rotated since we last checked, we still know which level it is
+        let resolved_level = daily_level::table
+            .filter(daily_level::request_key.eq(db_key))
+            .filter(daily_level::daily_index.eq(Some(__for_values!(daily.index, u32))))
+            .select(daily_level::level_id)
+            .get_result::<Option<i64>>(&conn)
+            .optional()?
+            .and_then(|level_id| level_id);
+
+        upsert_daily_level!(&conn, values(db_key, Some(daily), resolved_level));
+
+        let entry = Entry::new(db_key);
+
+        update_entry!(self, entry, daily_level_meta::table, daily_level_meta::request_key);
+
+        Ok(entry)
+    }
+}
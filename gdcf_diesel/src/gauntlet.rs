@@ -0,0 +1,69 @@
+use crate::{key::DatabaseKey, meta::Entry, wrap::Wrapped, Cache};
+use diesel::{backend::Backend, deserialize::FromSqlRow, ExpressionMethods, QueryDsl, Queryable, RunQueryDsl};
+use gdcf::{
+    api::request::GauntletsRequest,
+    cache::{CacheEntry, Lookup, Store},
+};
+use gdcf_model::level::Gauntlet;
+use log::{debug, warn};
+
+diesel_stuff! {
+    gauntlet (gauntlet_id, Gauntlet) {
+        (gauntlet_id, gauntlet_id, u64),
+        (levels, levels, Vec<u64>)
+    }
+}
+
+// Metadata table storing information about when the list of gauntlets was cached. Since there is
+// only one such list, this table contains at most one row.
+meta_table!(gauntlet_list_meta, request_hash);
+
+impl Lookup<GauntletsRequest> for Cache {
+    fn lookup(&self, key: &GauntletsRequest) -> Result<CacheEntry<Vec<Gauntlet>, Entry>, Self::Err> {
+        let connection = self.pool.get()?;
+
+        let entry = handle_missing!(gauntlet_list_meta::table
+            .filter(gauntlet_list_meta::request_hash.eq(key.database_key()))
+            .get_result(&connection));
+
+        let entry = self.entry(entry, self.expiry.gauntlets);
+
+        if entry.absent {
+            return Ok(CacheEntry::MarkedAbsent(entry))
+        }
+
+        let gauntlets: Vec<_> = handle_missing!(gauntlet::table.order(gauntlet::gauntlet_id.asc()).load(&connection))
+            .into_iter()
+            .map(|row: Wrapped<_>| row.0)
+            .collect();
+
+        Ok(CacheEntry::Cached(gauntlets, entry))
+    }
+}
+
+impl Store<GauntletsRequest> for Cache {
+    fn mark_absent(&mut self, key: &GauntletsRequest) -> Result<Entry, Self::Err> {
+        warn!("Marking results of GauntletsRequest with key {} as absent!", key);
+
+        let entry = Entry::absent(key.database_key());
+
+        update_entry!(self, entry, gauntlet_list_meta::table, gauntlet_list_meta::request_hash);
+
+        Ok(entry)
+    }
+
+    fn store(&mut self, gauntlets: &Vec<Gauntlet>, key: &GauntletsRequest) -> Result<Entry, Self::Err> {
+        debug!("Storing result of GauntletsRequest with key {}", key);
+
+        // Gauntlets are never removed from the game, so there's no need to clear out the old list
+        for gauntlet in gauntlets {
+            upsert!(self, gauntlet, gauntlet::table, gauntlet::gauntlet_id);
+        }
+
+        let entry = Entry::new(key.database_key());
+
+        update_entry!(self, entry, gauntlet_list_meta::table, gauntlet_list_meta::request_hash);
+
+        Ok(entry)
+    }
+}
@@ -1,8 +1,12 @@
 use crate::level::SemiLevel;
 use derive_more::Display;
 use gdcf::{
-    api::request::{LevelRequest, LevelsRequest, UserRequest},
-    cache::{CreatorKey, Key, NewgroundsSongKey},
+    api::request::{
+        BlockedUsersRequest, DailyLevelRequest, FriendListRequest, FriendRequestsRequest, GauntletsRequest, LeaderboardRequest,
+        LevelCommentsRequest, LevelRequest, LevelScoresRequest, LevelsRequest, MapPacksRequest, MessageRequest, MessagesRequest,
+        UserRequest,
+    },
+    cache::{CommentUserKey, CreatorKey, Key, NewgroundsSongKey},
 };
 use gdcf_model::level::PartialLevel;
 use std::{
@@ -20,6 +24,12 @@ impl DatabaseKey for CreatorKey {
     }
 }
 
+impl DatabaseKey for CommentUserKey {
+    fn database_key(&self) -> i64 {
+        self.0 as i64
+    }
+}
+
 #[derive(Debug, Display)]
 pub(crate) struct SemiLevelKey(pub u64);
 
@@ -48,7 +58,16 @@ impl DatabaseKey for PartialLevelKey {
 
 impl DatabaseKey for LevelRequest {
     fn database_key(&self) -> i64 {
-        self.level_id as i64
+        match self.special {
+            Some(special) => special.into(),
+            None => self.level_id as i64,
+        }
+    }
+}
+
+impl DatabaseKey for DailyLevelRequest {
+    fn database_key(&self) -> i64 {
+        self.level_request().database_key()
     }
 }
 
@@ -77,6 +96,122 @@ impl DatabaseKey for LevelsRequest {
         self.request_type.hash(&mut state);
         self.page.hash(&mut state);
 
+        if let Some(ref authentication) = self.authentication {
+            authentication.hash(&mut state);
+        }
+
+        if !self.followed.is_empty() {
+            self.followed.hash(&mut state);
+        }
+
+        state.finish() as i64
+    }
+}
+
+impl DatabaseKey for LevelCommentsRequest {
+    fn database_key(&self) -> i64 {
+        let mut state = DefaultHasher::new();
+
+        self.level_id.hash(&mut state);
+        self.sort_mode.hash(&mut state);
+        self.limit.hash(&mut state);
+        self.page.hash(&mut state);
+        self.total.hash(&mut state);
+
+        state.finish() as i64
+    }
+}
+
+impl DatabaseKey for LeaderboardRequest {
+    fn database_key(&self) -> i64 {
+        let mut state = DefaultHasher::new();
+
+        self.hash(&mut state);
+
+        state.finish() as i64
+    }
+}
+
+impl DatabaseKey for LevelScoresRequest {
+    fn database_key(&self) -> i64 {
+        let mut state = DefaultHasher::new();
+
+        self.hash(&mut state);
+
+        state.finish() as i64
+    }
+}
+
+impl DatabaseKey for MapPacksRequest {
+    fn database_key(&self) -> i64 {
+        let mut state = DefaultHasher::new();
+
+        self.hash(&mut state);
+
+        state.finish() as i64
+    }
+}
+
+impl DatabaseKey for GauntletsRequest {
+    fn database_key(&self) -> i64 {
+        let mut state = DefaultHasher::new();
+
+        self.hash(&mut state);
+
+        state.finish() as i64
+    }
+}
+
+impl DatabaseKey for MessagesRequest {
+    fn database_key(&self) -> i64 {
+        let mut state = DefaultHasher::new();
+
+        self.hash(&mut state);
+
+        state.finish() as i64
+    }
+}
+
+impl DatabaseKey for MessageRequest {
+    fn database_key(&self) -> i64 {
+        let mut state = DefaultHasher::new();
+
+        self.hash(&mut state);
+
+        state.finish() as i64
+    }
+}
+
+// Friend lists and lists of blocked users share a table and would otherwise hash to the same value,
+// so we mix in which kind of list we're dealing with
+impl DatabaseKey for FriendListRequest {
+    fn database_key(&self) -> i64 {
+        let mut state = DefaultHasher::new();
+
+        "friends".hash(&mut state);
+        self.hash(&mut state);
+
+        state.finish() as i64
+    }
+}
+
+impl DatabaseKey for BlockedUsersRequest {
+    fn database_key(&self) -> i64 {
+        let mut state = DefaultHasher::new();
+
+        "blocked".hash(&mut state);
+        self.hash(&mut state);
+
+        state.finish() as i64
+    }
+}
+
+impl DatabaseKey for FriendRequestsRequest {
+    fn database_key(&self) -> i64 {
+        let mut state = DefaultHasher::new();
+
+        self.hash(&mut state);
+
         state.finish() as i64
     }
 }
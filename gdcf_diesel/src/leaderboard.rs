@@ -0,0 +1,259 @@
+use crate::{key::DatabaseKey, meta::Entry, wrap::Wrapped, Cache, Error};
+use chrono::NaiveDateTime;
+use diesel::{backend::Backend, deserialize::FromSqlRow, sql_types::*, ExpressionMethods, QueryDsl, Queryable, RunQueryDsl};
+use gdcf::{
+    api::request::LeaderboardRequest,
+    cache::{CacheEntry, Lookup, Store},
+};
+use gdcf_model::{
+    user::{Color, LeaderboardEntry},
+    GameMode,
+};
+use log::{debug, warn};
+
+// Unlike all other tables, this one is never updated. Instead, every time a leaderboard is
+// retrieved, a new snapshot of it is appended, identified by the hash of the request and the time
+// it was made at.
+table! {
+    leaderboard_entry (request_hash, cached_at, rank) {
+        request_hash -> Int8,
+        cached_at -> Timestamp,
+        rank -> Int4,
+        name -> Text,
+        user_id -> Int8,
+        stars -> Int4,
+        demons -> Int2,
+        creator_points -> Int2,
+        icon_index -> Int2,
+        primary_color -> Int4,
+        secondary_color -> Int4,
+        secret_coins -> Int2,
+        icon_type -> Int2,
+        has_glow -> Bool,
+        account_id -> Int8,
+        user_coins -> Int2,
+        diamonds -> Int2,
+    }
+}
+
+type Row = (
+    i64,
+    NaiveDateTime,
+    i32,
+    String,
+    i64,
+    i32,
+    i16,
+    i16,
+    i16,
+    i32,
+    i32,
+    i16,
+    i16,
+    bool,
+    i64,
+    i16,
+    i16,
+);
+type SqlType = (
+    Int8,
+    Timestamp,
+    Int4,
+    Text,
+    Int8,
+    Int4,
+    Int2,
+    Int2,
+    Int2,
+    Int4,
+    Int4,
+    Int2,
+    Int2,
+    Bool,
+    Int8,
+    Int2,
+    Int2,
+);
+
+impl<DB: Backend> Queryable<SqlType, DB> for Wrapped<LeaderboardEntry>
+where
+    Row: FromSqlRow<SqlType, DB>,
+{
+    type Row = Row;
+
+    fn build(row: Self::Row) -> Self {
+        Wrapped(LeaderboardEntry {
+            rank: __for_queryable!(row.2, u32),
+            name: row.3,
+            user_id: __for_queryable!(row.4, u64),
+            stars: __for_queryable!(row.5, u32),
+            demons: __for_queryable!(row.6, u16),
+            creator_points: __for_queryable!(row.7, u16),
+            icon_index: __for_queryable!(row.8, u16),
+            primary_color: __for_queryable!(row.9, Color),
+            secondary_color: __for_queryable!(row.10, Color),
+            secret_coins: __for_queryable!(row.11, u8),
+            icon_type: __for_queryable!(row.12, GameMode),
+            has_glow: row.13,
+            account_id: __for_queryable!(row.14, u64),
+            user_coins: __for_queryable!(row.15, u16),
+            diamonds: __for_queryable!(row.16, u16),
+        })
+    }
+}
+
+type Values<'a> = (
+    diesel::dsl::Eq<leaderboard_entry::request_hash, i64>,
+    diesel::dsl::Eq<leaderboard_entry::cached_at, NaiveDateTime>,
+    diesel::dsl::Eq<leaderboard_entry::rank, i32>,
+    diesel::dsl::Eq<leaderboard_entry::name, &'a str>,
+    diesel::dsl::Eq<leaderboard_entry::user_id, i64>,
+    diesel::dsl::Eq<leaderboard_entry::stars, i32>,
+    diesel::dsl::Eq<leaderboard_entry::demons, i16>,
+    diesel::dsl::Eq<leaderboard_entry::creator_points, i16>,
+    diesel::dsl::Eq<leaderboard_entry::icon_index, i16>,
+    diesel::dsl::Eq<leaderboard_entry::primary_color, i32>,
+    diesel::dsl::Eq<leaderboard_entry::secondary_color, i32>,
+    diesel::dsl::Eq<leaderboard_entry::secret_coins, i16>,
+    diesel::dsl::Eq<leaderboard_entry::icon_type, i16>,
+    diesel::dsl::Eq<leaderboard_entry::has_glow, bool>,
+    diesel::dsl::Eq<leaderboard_entry::account_id, i64>,
+    diesel::dsl::Eq<leaderboard_entry::user_coins, i16>,
+    diesel::dsl::Eq<leaderboard_entry::diamonds, i16>,
+);
+
+fn values(entry: Entry, leaderboard_entry: &LeaderboardEntry) -> Values<'_> {
+    use leaderboard_entry::columns::*;
+
+    (
+        request_hash.eq(entry.key),
+        cached_at.eq(entry.cached_at),
+        rank.eq(__for_values!(leaderboard_entry.rank, u32)),
+        name.eq(&leaderboard_entry.name[..]),
+        user_id.eq(__for_values!(leaderboard_entry.user_id, u64)),
+        stars.eq(__for_values!(leaderboard_entry.stars, u32)),
+        demons.eq(__for_values!(leaderboard_entry.demons, u16)),
+        creator_points.eq(__for_values!(leaderboard_entry.creator_points, u16)),
+        icon_index.eq(__for_values!(leaderboard_entry.icon_index, u16)),
+        primary_color.eq(__for_values!(leaderboard_entry.primary_color, Color)),
+        secondary_color.eq(__for_values!(leaderboard_entry.secondary_color, Color)),
+        secret_coins.eq(__for_values!(leaderboard_entry.secret_coins, u8)),
+        icon_type.eq(__for_values!(leaderboard_entry.icon_type, GameMode)),
+        has_glow.eq(leaderboard_entry.has_glow),
+        account_id.eq(__for_values!(leaderboard_entry.account_id, u64)),
+        user_coins.eq(__for_values!(leaderboard_entry.user_coins, u16)),
+        diamonds.eq(__for_values!(leaderboard_entry.diamonds, u16)),
+    )
+}
+
+// Metadata table storing information about when a leaderboard was last retrieved
+meta_table!(leaderboard_meta, request_hash);
+
+/// A leaderboard as it was at a specific point in time
+#[derive(Debug, Clone, PartialEq)]
+pub struct LeaderboardSnapshot {
+    /// The point in time at which this leaderboard was retrieved
+    pub taken_at: NaiveDateTime,
+
+    /// The entries of the leaderboard, ordered by rank
+    pub entries: Vec<LeaderboardEntry>,
+}
+
+impl Cache {
+    /// Gets all snapshots of the leaderboard retrieved by the given [`LeaderboardRequest`] that
+    /// have been stored in this cache, ordered from oldest to newest
+    pub fn leaderboard_snapshots(&self, request: &LeaderboardRequest) -> Result<Vec<LeaderboardSnapshot>, Error> {
+        let rows: Vec<(NaiveDateTime, Wrapped<LeaderboardEntry>)> = leaderboard_entry::table
+            .filter(leaderboard_entry::request_hash.eq(request.database_key()))
+            .order((leaderboard_entry::cached_at.asc(), leaderboard_entry::rank.asc()))
+            .select((leaderboard_entry::cached_at, leaderboard_entry::all_columns))
+            .load(&self.pool.get()?)?;
+
+        let mut snapshots: Vec<LeaderboardSnapshot> = Vec::new();
+
+        for (taken_at, Wrapped(entry)) in rows {
+            match snapshots.last_mut() {
+                Some(snapshot) if snapshot.taken_at == taken_at => snapshot.entries.push(entry),
+                _ =>
+                    snapshots.push(LeaderboardSnapshot {
+                        taken_at,
+                        entries: vec![entry],
+                    }),
+            }
+        }
+
+        Ok(snapshots)
+    }
+
+    /// Gets the rank the player with the given account ID held in each stored snapshot of the
+    /// leaderboard retrieved by the given [`LeaderboardRequest`], ordered from oldest to newest.
+    ///
+    /// Snapshots the player didn't appear in are skipped.
+    pub fn rank_history(&self, request: &LeaderboardRequest, account_id: u64) -> Result<Vec<(NaiveDateTime, u32)>, Error> {
+        let history: Vec<(NaiveDateTime, i32)> = leaderboard_entry::table
+            .filter(leaderboard_entry::request_hash.eq(request.database_key()))
+            .filter(leaderboard_entry::account_id.eq(account_id as i64))
+            .order(leaderboard_entry::cached_at.asc())
+            .select((leaderboard_entry::cached_at, leaderboard_entry::rank))
+            .load(&self.pool.get()?)?;
+
+        Ok(history.into_iter().map(|(taken_at, rank)| (taken_at, rank as u32)).collect())
+    }
+}
+
+impl Lookup<LeaderboardRequest> for Cache {
+    fn lookup(&self, key: &LeaderboardRequest) -> Result<CacheEntry<Vec<LeaderboardEntry>, Entry>, Self::Err> {
+        let connection = self.pool.get()?;
+
+        let entry = handle_missing!(leaderboard_meta::table
+            .filter(leaderboard_meta::request_hash.eq(key.database_key()))
+            .get_result(&connection));
+
+        let entry = self.entry(entry, self.expiry.leaderboard);
+
+        if entry.absent {
+            return Ok(CacheEntry::MarkedAbsent(entry))
+        }
+
+        let entries: Vec<_> = handle_missing!(leaderboard_entry::table
+            .filter(leaderboard_entry::request_hash.eq(entry.key))
+            .filter(leaderboard_entry::cached_at.eq(entry.cached_at))
+            .order(leaderboard_entry::rank.asc())
+            .load(&connection))
+        .into_iter()
+        .map(|row: Wrapped<_>| row.0)
+        .collect();
+
+        Ok(CacheEntry::Cached(entries, entry))
+    }
+}
+
+impl Store<LeaderboardRequest> for Cache {
+    fn mark_absent(&mut self, key: &LeaderboardRequest) -> Result<Entry, Self::Err> {
+        warn!("Marking results of LeaderboardRequest with key {} as absent!", key);
+
+        let entry = Entry::absent(key.database_key());
+
+        update_entry!(self, entry, leaderboard_meta::table, leaderboard_meta::request_hash);
+
+        Ok(entry)
+    }
+
+    fn store(&mut self, entries: &Vec<LeaderboardEntry>, key: &LeaderboardRequest) -> Result<Entry, Self::Err> {
+        debug!("Storing snapshot of leaderboard with key {}", key);
+
+        let entry = Entry::new(key.database_key());
+
+        let conn = self.pool.get()?;
+
+        for leaderboard_entry in entries {
+            diesel::insert_into(leaderboard_entry::table)
+                .values(values(entry, leaderboard_entry))
+                .execute(&conn)?;
+        }
+
+        update_entry!(self, entry, leaderboard_meta::table, leaderboard_meta::request_hash);
+
+        Ok(entry)
+    }
+}
@@ -1,8 +1,9 @@
 use crate::{
     key::{DatabaseKey, PartialLevelKey, SemiLevelKey},
     meta::Entry,
+    partial_level::partial_level_meta,
     wrap::Wrapped,
-    Cache,
+    Cache, Error,
 };
 use diesel::{backend::Backend, deserialize::FromSqlRow, ExpressionMethods, Queryable, RunQueryDsl};
 use gdcf::{
@@ -89,10 +90,14 @@ impl Display for SemiLevel {
 
 meta_table!(level_meta, level_id);
 
-lookup_simply!(SemiLevelKey, level, level_meta, level_id);
+lookup_simply!(SemiLevelKey, level, level_meta, level_id, level);
 
 impl Lookup<LevelRequest> for Cache {
     fn lookup(&self, key: &LevelRequest) -> Result<CacheEntry<Level<Option<u64>, u64>, Entry>, Self::Err> {
+        if key.special.is_some() {
+            return self.lookup_daily_level(key)
+        }
+
         match self.lookup(&SemiLevelKey(key.level_id))? {
             CacheEntry::Cached(semi_level, meta) => {
                 let semi_level: SemiLevel = semi_level;
@@ -122,6 +127,16 @@ impl Lookup<LevelRequest> for Cache {
 
 impl Store<LevelRequest> for Cache {
     fn store(&mut self, obj: &Level<Option<u64>, u64>, key: &LevelRequest) -> Result<Self::CacheEntryMeta, Self::Err> {
+        // The daily level (or weekly demon) is stored like any other level. We only remember which
+        // level it currently is
+        if key.special.is_some() {
+            let entry = self.store(obj, &LevelRequest::new(obj.base.level_id))?;
+
+            self.resolve_daily_level(key, obj.base.level_id)?;
+
+            return Ok(entry)
+        }
+
         self.store(&obj.base, &PartialLevelKey(obj.base.level_id))?;
 
         debug!("Storing {} under key {}", obj, key);
@@ -142,3 +157,14 @@ impl Store<LevelRequest> for Cache {
         Ok(entry)
     }
 }
+
+impl Cache {
+    /// Invalidates the cached data of the level with the given ID, both the full level and the
+    /// partial level
+    pub(crate) fn invalidate_level(&self, level_id: u64) -> Result<(), Error> {
+        invalidate_entry!(self, level_meta, level_meta::level_id.eq(level_id as i64));
+        invalidate_entry!(self, partial_level_meta, partial_level_meta::level_id.eq(level_id as i64));
+
+        Ok(())
+    }
+}
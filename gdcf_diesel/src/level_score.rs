@@ -0,0 +1,156 @@
+use crate::{key::DatabaseKey, meta::Entry, wrap::Wrapped, Cache};
+use diesel::{backend::Backend, deserialize::FromSqlRow, sql_types::*, ExpressionMethods, QueryDsl, Queryable, RunQueryDsl};
+use gdcf::{
+    api::request::LevelScoresRequest,
+    cache::{CacheEntry, Lookup, Store},
+};
+use gdcf_model::{level::LevelScore, user::Color, GameMode};
+use log::{debug, warn};
+
+// The scores are stored under the hash of the request that retrieved them, as the same score shows
+// up in different scopes with different ranks
+table! {
+    level_score (request_hash, rank) {
+        request_hash -> Int8,
+        rank -> Int4,
+        name -> Text,
+        user_id -> Int8,
+        percent -> Int2,
+        icon_index -> Int2,
+        primary_color -> Int4,
+        secondary_color -> Int4,
+        coins -> Int2,
+        icon_type -> Int2,
+        has_glow -> Bool,
+        account_id -> Int8,
+        time_since_set -> Text,
+    }
+}
+
+type Row = (i64, i32, String, i64, i16, i16, i32, i32, i16, i16, bool, i64, String);
+type SqlType = (Int8, Int4, Text, Int8, Int2, Int2, Int4, Int4, Int2, Int2, Bool, Int8, Text);
+
+impl<DB: Backend> Queryable<SqlType, DB> for Wrapped<LevelScore>
+where
+    Row: FromSqlRow<SqlType, DB>,
+{
+    type Row = Row;
+
+    fn build(row: Self::Row) -> Self {
+        Wrapped(LevelScore {
+            rank: __for_queryable!(row.1, u32),
+            name: row.2,
+            user_id: __for_queryable!(row.3, u64),
+            percent: __for_queryable!(row.4, u8),
+            icon_index: __for_queryable!(row.5, u16),
+            primary_color: __for_queryable!(row.6, Color),
+            secondary_color: __for_queryable!(row.7, Color),
+            coins: __for_queryable!(row.8, u8),
+            icon_type: __for_queryable!(row.9, GameMode),
+            has_glow: row.10,
+            account_id: __for_queryable!(row.11, u64),
+            time_since_set: row.12,
+        })
+    }
+}
+
+type Values<'a> = (
+    diesel::dsl::Eq<level_score::request_hash, i64>,
+    diesel::dsl::Eq<level_score::rank, i32>,
+    diesel::dsl::Eq<level_score::name, &'a str>,
+    diesel::dsl::Eq<level_score::user_id, i64>,
+    diesel::dsl::Eq<level_score::percent, i16>,
+    diesel::dsl::Eq<level_score::icon_index, i16>,
+    diesel::dsl::Eq<level_score::primary_color, i32>,
+    diesel::dsl::Eq<level_score::secondary_color, i32>,
+    diesel::dsl::Eq<level_score::coins, i16>,
+    diesel::dsl::Eq<level_score::icon_type, i16>,
+    diesel::dsl::Eq<level_score::has_glow, bool>,
+    diesel::dsl::Eq<level_score::account_id, i64>,
+    diesel::dsl::Eq<level_score::time_since_set, &'a str>,
+);
+
+fn values(key: i64, score: &LevelScore) -> Values<'_> {
+    use level_score::columns::*;
+
+    (
+        request_hash.eq(key),
+        rank.eq(__for_values!(score.rank, u32)),
+        name.eq(&score.name[..]),
+        user_id.eq(__for_values!(score.user_id, u64)),
+        percent.eq(__for_values!(score.percent, u8)),
+        icon_index.eq(__for_values!(score.icon_index, u16)),
+        primary_color.eq(__for_values!(score.primary_color, Color)),
+        secondary_color.eq(__for_values!(score.secondary_color, Color)),
+        coins.eq(__for_values!(score.coins, u8)),
+        icon_type.eq(__for_values!(score.icon_type, GameMode)),
+        has_glow.eq(score.has_glow),
+        account_id.eq(__for_values!(score.account_id, u64)),
+        time_since_set.eq(&score.time_since_set[..]),
+    )
+}
+
+// Metadata table storing information about when the leaderboard of a level was cached
+meta_table!(level_score_meta, request_hash);
+
+impl Lookup<LevelScoresRequest> for Cache {
+    fn lookup(&self, key: &LevelScoresRequest) -> Result<CacheEntry<Vec<LevelScore>, Entry>, Self::Err> {
+        let connection = self.pool.get()?;
+
+        let entry = handle_missing!(level_score_meta::table
+            .filter(level_score_meta::request_hash.eq(key.database_key()))
+            .get_result(&connection));
+
+        let entry = self.entry(entry, self.expiry.level_scores);
+
+        if entry.absent {
+            return Ok(CacheEntry::MarkedAbsent(entry))
+        }
+
+        let scores: Vec<_> = handle_missing!(level_score::table
+            .filter(level_score::request_hash.eq(key.database_key()))
+            .order(level_score::rank.asc())
+            .load(&connection))
+        .into_iter()
+        .map(|row: Wrapped<_>| row.0)
+        .collect();
+
+        Ok(CacheEntry::Cached(scores, entry))
+    }
+}
+
+impl Store<LevelScoresRequest> for Cache {
+    fn mark_absent(&mut self, key: &LevelScoresRequest) -> Result<Entry, Self::Err> {
+        warn!("Marking results of LevelScoresRequest with key {} as absent!", key);
+
+        let entry = Entry::absent(key.database_key());
+
+        update_entry!(self, entry, level_score_meta::table, level_score_meta::request_hash);
+
+        Ok(entry)
+    }
+
+    fn store(&mut self, scores: &Vec<LevelScore>, key: &LevelScoresRequest) -> Result<Entry, Self::Err> {
+        debug!("Storing result of LevelScoresRequest with key {}", key);
+
+        let db_key = key.database_key();
+
+        let conn = self.pool.get()?;
+
+        diesel::delete(level_score::table)
+            .filter(level_score::request_hash.eq(db_key))
+            .execute(&conn)?;
+
+        for score in scores {
+            diesel::insert_into(level_score::table)
+                .values(values(db_key, score))
+                .execute(&conn)?;
+        }
+
+        let entry = Entry::new(db_key);
+
+        update_entry!(self, entry, level_score_meta::table, level_score_meta::request_hash);
+
+        Ok(entry)
+    }
+}
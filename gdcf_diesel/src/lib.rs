@@ -5,13 +5,24 @@
 mod meta;
 #[macro_use]
 mod macros;
+mod comment;
+mod comment_user;
 mod creator;
+mod daily_level;
+mod gauntlet;
 mod key;
+mod leaderboard;
 mod level;
+mod level_score;
+mod map_pack;
+mod message;
 mod partial_level;
 mod profile;
 mod song;
+mod user_list;
+mod vacuum;
 mod wrap;
+mod write;
 
 // diesel devs refuse to make their macros work with the new rust 2018 import mechanics, so this
 // shit is necessary
@@ -33,7 +44,7 @@ use gdcf_model::level::PartialLevel;
 use log::{debug, warn};
 use r2d2::Pool;
 
-pub use crate::meta::Entry;
+pub use crate::{leaderboard::LeaderboardSnapshot, meta::Entry, vacuum::VacuumPolicy};
 
 // this means we cannot enable two features at once. Since diesel doesn't allow writing database
 // agnostic code, the alternative to this is wrapping everything in macros (like we used to do in
@@ -46,19 +57,211 @@ use crate::key::PartialLevelKey;
 use diesel::sqlite::SqliteConnection;
 use gdcf::api::request::LevelsRequest;
 
+/// Struct describing after how long the different kinds of cached objects are considered outdated
+///
+/// Each field corresponds to one of the metadata tables of the cache. An entry in one of these
+/// tables is considered expired once more time than specified here has passed since it was cached.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Expiry {
+    /// Expiry of the results of [`LevelsRequest`]s (the `level_list_meta` table)
+    pub level_list: Duration,
+
+    /// Expiry of full levels retrieved via `LevelRequest`s (the `level_meta` table)
+    pub level: Duration,
+
+    /// Expiry of partial levels (the `partial_level_meta` table)
+    pub partial_level: Duration,
+
+    /// Expiry of newgrounds songs (the `song_meta` table)
+    pub song: Duration,
+
+    /// Expiry of creators (the `creator_meta` table)
+    pub creator: Duration,
+
+    /// Expiry of user profiles (the `profile_meta` table)
+    pub profile: Duration,
+
+    /// Expiry of the results of `LevelCommentsRequest`s (the `level_comment_list_meta` table)
+    pub level_comments: Duration,
+
+    /// Expiry of the user data provided alongside level comments (the `comment_user_meta` table)
+    pub comment_user: Duration,
+
+    /// Expiry of the leaderboards of single levels (the `level_score_meta` table)
+    pub level_scores: Duration,
+
+    /// Expiry of the results of `MapPacksRequest`s (the `map_pack_list_meta` table)
+    pub map_packs: Duration,
+
+    /// Expiry of the list of gauntlets (the `gauntlet_list_meta` table)
+    pub gauntlets: Duration,
+
+    /// Expiry of the results of `MessagesRequest`s (the `message_list_meta` table). The contents
+    /// of messages never expire.
+    pub messages: Duration,
+
+    /// Expiry of friend lists and lists of blocked users (the `user_list_meta` table)
+    pub user_lists: Duration,
+
+    /// Expiry of the results of `FriendRequestsRequest`s (the `friend_request_meta` table)
+    pub friend_requests: Duration,
+
+    /// Expiry of the most recent snapshot of a leaderboard (the `leaderboard_meta` table). Older
+    /// snapshots are kept around regardless.
+    pub leaderboard: Duration,
+
+    /// Expiry of entries that have been marked as absent, regardless of the table they're in
+    pub absent: Duration,
+}
+
+impl Expiry {
+    /// Constructs a new [`Expiry`] that uses the given [`Duration`] for every kind of object
+    pub fn uniform(duration: Duration) -> Expiry {
+        Expiry {
+            level_list: duration,
+            level: duration,
+            partial_level: duration,
+            song: duration,
+            creator: duration,
+            profile: duration,
+            level_comments: duration,
+            comment_user: duration,
+            level_scores: duration,
+            map_packs: duration,
+            gauntlets: duration,
+            messages: duration,
+            user_lists: duration,
+            friend_requests: duration,
+            leaderboard: duration,
+            absent: duration,
+        }
+    }
+
+    /// Sets the expiry of [`LevelsRequest`] results
+    pub fn level_list(mut self, duration: Duration) -> Self {
+        self.level_list = duration;
+        self
+    }
+
+    /// Sets the expiry of full levels
+    pub fn level(mut self, duration: Duration) -> Self {
+        self.level = duration;
+        self
+    }
+
+    /// Sets the expiry of partial levels
+    pub fn partial_level(mut self, duration: Duration) -> Self {
+        self.partial_level = duration;
+        self
+    }
+
+    /// Sets the expiry of newgrounds songs
+    pub fn song(mut self, duration: Duration) -> Self {
+        self.song = duration;
+        self
+    }
+
+    /// Sets the expiry of creators
+    pub fn creator(mut self, duration: Duration) -> Self {
+        self.creator = duration;
+        self
+    }
+
+    /// Sets the expiry of user profiles
+    pub fn profile(mut self, duration: Duration) -> Self {
+        self.profile = duration;
+        self
+    }
+
+    /// Sets the expiry of `LevelCommentsRequest` results
+    pub fn level_comments(mut self, duration: Duration) -> Self {
+        self.level_comments = duration;
+        self
+    }
+
+    /// Sets the expiry of the user data provided alongside level comments
+    pub fn comment_user(mut self, duration: Duration) -> Self {
+        self.comment_user = duration;
+        self
+    }
+
+    /// Sets the expiry of the leaderboards of single levels
+    pub fn level_scores(mut self, duration: Duration) -> Self {
+        self.level_scores = duration;
+        self
+    }
+
+    /// Sets the expiry of `MapPacksRequest` results
+    pub fn map_packs(mut self, duration: Duration) -> Self {
+        self.map_packs = duration;
+        self
+    }
+
+    /// Sets the expiry of the list of gauntlets
+    pub fn gauntlets(mut self, duration: Duration) -> Self {
+        self.gauntlets = duration;
+        self
+    }
+
+    /// Sets the expiry of `MessagesRequest` results
+    pub fn messages(mut self, duration: Duration) -> Self {
+        self.messages = duration;
+        self
+    }
+
+    /// Sets the expiry of friend lists and lists of blocked users
+    pub fn user_lists(mut self, duration: Duration) -> Self {
+        self.user_lists = duration;
+        self
+    }
+
+    /// Sets the expiry of `FriendRequestsRequest` results
+    pub fn friend_requests(mut self, duration: Duration) -> Self {
+        self.friend_requests = duration;
+        self
+    }
+
+    /// Sets the expiry of leaderboard snapshots
+    pub fn leaderboard(mut self, duration: Duration) -> Self {
+        self.leaderboard = duration;
+        self
+    }
+
+    /// Sets the expiry of absent markers
+    pub fn absent(mut self, duration: Duration) -> Self {
+        self.absent = duration;
+        self
+    }
+}
+
 pub struct Cache {
     #[cfg(feature = "pg")]
     pool: Pool<ConnectionManager<PgConnection>>,
     #[cfg(feature = "sqlite")]
     pool: Pool<ConnectionManager<SqliteConnection>>,
-    expire_after: Duration,
+    expiry: Expiry,
 }
 
 impl Cache {
-    fn entry(&self, db_entry: DatabaseEntry) -> Entry {
+    /// Sets the [`Expiry`] configuration to use for this cache
+    ///
+    /// Allows builder-style configuration of the cache
+    pub fn with_expiry(mut self, expiry: Expiry) -> Self {
+        self.expiry = expiry;
+        self
+    }
+
+    /// Gets the [`Expiry`] configuration used by this cache
+    pub fn expiry(&self) -> Expiry {
+        self.expiry
+    }
+
+    fn entry(&self, db_entry: DatabaseEntry, expire_after: Duration) -> Entry {
+        let expire_after = if db_entry.absent { self.expiry.absent } else { expire_after };
+
         let now = Utc::now();
         let then = DateTime::<Utc>::from_utc(db_entry.cached_at, Utc);
-        let expired = now - then > self.expire_after;
+        let expired = now - then > expire_after;
 
         Entry {
             expired,
@@ -73,14 +276,14 @@ impl Clone for Cache {
     fn clone(&self) -> Self {
         Cache {
             pool: self.pool.clone(),
-            expire_after: self.expire_after,
+            expiry: self.expiry,
         }
     }
 }
 
 #[cfg(feature = "pg")]
 mod postgres {
-    use super::Cache;
+    use super::{Cache, Expiry};
     use chrono::Duration;
     use diesel::r2d2::ConnectionManager;
     use r2d2::Pool;
@@ -91,7 +294,7 @@ mod postgres {
         pub fn postgres(database_url: impl Into<String>) -> Result<Self, r2d2::Error> {
             Ok(Cache {
                 pool: Pool::new(ConnectionManager::new(database_url.into()))?,
-                expire_after: Duration::minutes(60),
+                expiry: Expiry::uniform(Duration::minutes(60)),
             })
         }
 
@@ -103,25 +306,42 @@ mod postgres {
 
 #[cfg(feature = "sqlite")]
 mod sqlite {
-    use super::Cache;
+    use super::{Cache, Expiry};
     use chrono::Duration;
     use diesel::r2d2::ConnectionManager;
     use r2d2::Pool;
+    use std::sync::atomic::{AtomicUsize, Ordering};
 
     embed_migrations!("migrations/sqlite");
 
     impl Cache {
+        /// Constructs a new cache backed by an in-memory database, which is dropped together with
+        /// the cache
         pub fn in_memory() -> Result<Self, r2d2::Error> {
+            static DATABASES: AtomicUsize = AtomicUsize::new(0);
+
+            // Every connection to ":memory:" opens a separate database, so all connections in the
+            // pool instead need to connect to the same, uniquely named, shared in-memory database
+            let url = format!(
+                "file:gdcf_{}_{}?mode=memory&cache=shared",
+                std::process::id(),
+                DATABASES.fetch_add(1, Ordering::Relaxed)
+            );
+
+            // The database only exists as long as there's a connection to it, so they're never closed
             Ok(Self {
-                pool: Pool::new(ConnectionManager::new(":memory:"))?,
-                expire_after: Duration::seconds(60),
+                pool: Pool::builder()
+                    .idle_timeout(None)
+                    .max_lifetime(None)
+                    .build(ConnectionManager::new(url))?,
+                expiry: Expiry::uniform(Duration::seconds(60)),
             })
         }
 
         pub fn sqlite(path: impl Into<String>) -> Result<Self, r2d2::Error> {
             Ok(Self {
                 pool: Pool::new(ConnectionManager::new(path.into()))?,
-                expire_after: Duration::seconds(60),
+                expiry: Expiry::uniform(Duration::seconds(60)),
             })
         }
 
@@ -173,7 +393,7 @@ impl Lookup<LevelsRequest> for Cache {
             .filter(level_list_meta::request_hash.eq(key.database_key()))
             .get_result(&connection));
 
-        let entry = self.entry(entry);
+        let entry = self.entry(entry, self.expiry.level_list);
 
         if entry.absent {
             return Ok(CacheEntry::MarkedAbsent(entry))
@@ -19,6 +19,19 @@ macro_rules! update_entry {
     }};
 }
 
+/// Invalidates the entries matching the given filter in the given meta table by backdating them to
+/// the unix epoch, causing them to be treated as expired regardless of the configured [`Expiry`].
+/// The cached data itself is kept.
+macro_rules! invalidate_entry {
+    ($self: expr, $table: ident, $filter: expr) => {{
+        use diesel::{ExpressionMethods, QueryDsl};
+
+        diesel::update($table::table.filter($filter))
+            .set($table::cached_at.eq(chrono::DateTime::<chrono::Utc>::from(std::time::UNIX_EPOCH).naive_utc()))
+            .execute(&$self.pool.get()?)?;
+    }};
+}
+
 #[cfg(feature = "sqlite")]
 macro_rules! upsert {
     ($self: expr, $object: expr, $table: expr, $_: expr) => {
@@ -40,6 +53,7 @@ macro_rules! __diesel_type {
     (String) => {Text};
     (Option<$t: ident>) => {Nullable<__diesel_type!($t)>};
     (Vec<u8>) => {Binary};
+    (Vec<u64>) => {Text};
     (LevelRating) => {Text};
     (LevelLength) => {Text};
     (Password) => {Nullable<Text>};
@@ -47,6 +61,7 @@ macro_rules! __diesel_type {
     (GameVersion) => {Int2};
     (MainSong) => {Int2};
     (ModLevel) => {Int2};
+    (GameMode) => {Int2};
     (Color) => {Int4};
 }
 
@@ -66,6 +81,7 @@ macro_rules! __ref_if_not_copy {
     (Option<String>) => {Option<&'a str>};
     (Option<$t: ident>) => {Option<__ref_if_not_copy!($t)>};
     (Vec<u8>) => {&'a [u8]};
+    (Vec<u64>) => {String};
     (LevelRating) => {String};
     (LevelLength) => {String};
     (Password) => {Option<&'a str>};
@@ -73,6 +89,7 @@ macro_rules! __ref_if_not_copy {
     (GameVersion) => {i16};
     (MainSong) => {i16};
     (ModLevel) => {i16};
+    (GameMode) => {i16};
     (Color) => {i32};
 }
 
@@ -91,6 +108,7 @@ macro_rules! __row_type {
     (String) => {String};
     (Option<$t: ident>) => {Option<__row_type!($t)>};
     (Vec<u8>) => {Vec<u8>};
+    (Vec<u64>) => {String};
     (LevelRating) => {String};
     (LevelLength) => {String};
     (Password) => {Option<String>};
@@ -98,6 +116,7 @@ macro_rules! __row_type {
     (GameVersion) => {i16};
     (MainSong) => {i16};
     (ModLevel) => {i16};
+    (GameMode) => {i16};
     (Color) => {i32};
 }
 
@@ -120,6 +139,9 @@ macro_rules! __for_queryable {
     ($value: expr, Option<$t: ident>) => {
         $value.map(|inner| __for_queryable!(inner, $t))
     };
+    ($value: expr, Vec<u64>) => {
+        $value.split(',').filter_map(|id| id.parse().ok()).collect()
+    };
     ($value: expr, LevelRating) => {
         LevelRating::from($value)
     };
@@ -146,6 +168,9 @@ macro_rules! __for_queryable {
     ($value: expr, ModLevel) => {{
         ModLevel::from($value as u8)
     }};
+    ($value: expr, GameMode) => {{
+        GameMode::from($value as u8)
+    }};
     ($value: expr, Color) => {{
         if $value < 0 {
             Color::Unknown(-$value as u8)
@@ -212,6 +237,9 @@ macro_rules! __for_values {
     ($value: expr, Vec<u8>) => {
         &$value[..]
     };
+    ($value: expr, Vec<u64>) => {
+        $value.iter().map(ToString::to_string).collect::<Vec<_>>().join(",")
+    };
     ($value: expr, LevelRating) => {
         $value.to_string()
     };
@@ -237,6 +265,10 @@ macro_rules! __for_values {
         let byte: u8 = $value.into();
         byte as i16
     }};
+    ($value: expr, GameMode) => {{
+        let byte: u8 = $value.into();
+        byte as i16
+    }};
     ($value: expr, Color) => {{
         match $value {
             Color::Unknown(idx) => -(idx as i32),
@@ -283,7 +315,7 @@ macro_rules! store_simply {
 }
 
 macro_rules! lookup_simply {
-    ($key_type: ty, $object_table: ident,  $meta_table: ident, $primary_column: ident) => {
+    ($key_type: ty, $object_table: ident,  $meta_table: ident, $primary_column: ident, $expiry: ident) => {
         fn __impl_lookup() {
             use crate::{key::DatabaseKey, wrap::Wrapped, Cache, Entry};
             use diesel::{QueryDsl, RunQueryDsl};
@@ -304,7 +336,7 @@ macro_rules! lookup_simply {
                     let entry = handle_missing!($meta_table::table
                         .filter($meta_table::$primary_column.eq(key.database_key()))
                         .get_result(&connection));
-                    let entry = self.entry(entry);
+                    let entry = self.entry(entry, self.expiry.$expiry);
 
                     trace!("Successfully retrieved meta entry");
 
@@ -326,7 +358,9 @@ macro_rules! lookup_simply {
 }
 
 macro_rules! diesel_stuff {
-    ($table_name: ident($primary_key: ident, $rust_ty: ty) {$(($column_name: ident, $field_name: ident, $($rust_type:tt)*)),*}) => {
+    // The optional `with` block specifies values for fields of the rust type that have no
+    // corresponding column (for instance `()` placeholders)
+    ($table_name: ident($primary_key: ident, $rust_ty: ty) {$(($column_name: ident, $field_name: ident, $($rust_type:tt)*)),*} $(with {$($fixed_field: ident: $fixed_value: expr),*})?) => {
         table! {
             $table_name($primary_key) {
                 $($column_name -> __diesel_type!($($rust_type)*),)*
@@ -377,6 +411,7 @@ macro_rules! diesel_stuff {
                     $(
                         $field_name: __for_queryable!($field_name, $($rust_type)*)
                     ),*
+                    $($(, $fixed_field: $fixed_value)*)?
                 }
             }
         }
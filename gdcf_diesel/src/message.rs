@@ -0,0 +1,315 @@
+use crate::{
+    key::DatabaseKey,
+    meta::{DatabaseEntry, Entry},
+    wrap::Wrapped,
+    Cache, Error,
+};
+use diesel::{backend::Backend, deserialize::FromSqlRow, sql_types::*, ExpressionMethods, QueryDsl, Queryable, RunQueryDsl};
+use gdcf::{
+    api::request::{MessageRequest, MessagesRequest},
+    cache::{CacheEntry, Lookup, Store},
+};
+use gdcf_model::message::{Message, PartialMessage};
+use log::{debug, warn};
+
+// Messages are stored under the account that retrieved them, their ID and whether they were sent by
+// that account, as the sender and the recipient of a message see it with a different "other party".
+// The body is only available once the message itself has been downloaded.
+table! {
+    message (owner_id, message_id, sent) {
+        owner_id -> Int8,
+        message_id -> Int8,
+        sent -> Bool,
+        account_id -> Int8,
+        user_id -> Int8,
+        subject -> Text,
+        username -> Text,
+        time_since_sent -> Text,
+        is_read -> Bool,
+        body -> Nullable<Text>,
+    }
+}
+
+type PartialRow = (i64, bool, i64, i64, String, String, String, bool);
+type PartialSqlType = (Int8, Bool, Int8, Int8, Text, Text, Text, Bool);
+
+impl<DB: Backend> Queryable<PartialSqlType, DB> for Wrapped<PartialMessage>
+where
+    PartialRow: FromSqlRow<PartialSqlType, DB>,
+{
+    type Row = PartialRow;
+
+    fn build(row: Self::Row) -> Self {
+        Wrapped(PartialMessage {
+            message_id: __for_queryable!(row.0, u64),
+            sent: row.1,
+            account_id: __for_queryable!(row.2, u64),
+            user_id: __for_queryable!(row.3, u64),
+            subject: row.4,
+            username: row.5,
+            time_since_sent: row.6,
+            read: row.7,
+        })
+    }
+}
+
+type PartialValues<'a> = (
+    diesel::dsl::Eq<message::owner_id, i64>,
+    diesel::dsl::Eq<message::message_id, i64>,
+    diesel::dsl::Eq<message::sent, bool>,
+    diesel::dsl::Eq<message::account_id, i64>,
+    diesel::dsl::Eq<message::user_id, i64>,
+    diesel::dsl::Eq<message::subject, &'a str>,
+    diesel::dsl::Eq<message::username, &'a str>,
+    diesel::dsl::Eq<message::time_since_sent, &'a str>,
+    diesel::dsl::Eq<message::is_read, bool>,
+);
+
+fn partial_values(owner: u64, partial: &PartialMessage) -> PartialValues<'_> {
+    use message::columns::*;
+
+    (
+        owner_id.eq(__for_values!(owner, u64)),
+        message_id.eq(__for_values!(partial.message_id, u64)),
+        sent.eq(partial.sent),
+        account_id.eq(__for_values!(partial.account_id, u64)),
+        user_id.eq(__for_values!(partial.user_id, u64)),
+        subject.eq(&partial.subject[..]),
+        username.eq(&partial.username[..]),
+        time_since_sent.eq(&partial.time_since_sent[..]),
+        is_read.eq(partial.read),
+    )
+}
+
+// Metadata table associating the hashes of cached requests with the messages the request returned
+table! {
+    message_request_results (message_id, request_hash) {
+        message_id -> Int8,
+        request_hash -> Int8,
+    }
+}
+
+// Metadata table storing information about when a page of messages was cached
+meta_table!(message_list_meta, request_hash);
+
+// Metadata table storing information about when the content of a message was cached, on behalf of
+// which account, and which message it is
+table! {
+    message_meta (request_hash) {
+        request_hash -> Int8,
+        owner_id -> Int8,
+        message_id -> Int8,
+        sent -> Bool,
+        cached_at -> Timestamp,
+        absent -> Bool,
+    }
+}
+
+allow_tables_to_appear_in_same_query!(message_request_results, message);
+
+impl Cache {
+    /// Updates the stored data of the given message as seen by the account with the given ID
+    /// (without touching its content), inserting it if it isn't cached yet
+    fn store_partial_message(&self, owner: u64, partial: &PartialMessage) -> Result<(), Error> {
+        let conn = self.pool.get()?;
+
+        let updated = diesel::update(
+            message::table
+                .filter(message::owner_id.eq(owner as i64))
+                .filter(message::message_id.eq(partial.message_id as i64))
+                .filter(message::sent.eq(partial.sent)),
+        )
+        .set(partial_values(owner, partial))
+        .execute(&conn)?;
+
+        if updated == 0 {
+            diesel::insert_into(message::table)
+                .values(partial_values(owner, partial))
+                .execute(&conn)?;
+        }
+
+        Ok(())
+    }
+
+    /// Replaces the meta entry of the message content retrieved by the given [`MessageRequest`]
+    fn update_message_entry(&self, entry: Entry, key: &MessageRequest) -> Result<(), Error> {
+        let conn = self.pool.get()?;
+
+        diesel::delete(message_meta::table.filter(message_meta::request_hash.eq(entry.key))).execute(&conn)?;
+        diesel::insert_into(message_meta::table)
+            .values((
+                message_meta::request_hash.eq(entry.key),
+                message_meta::owner_id.eq(key.authentication.account_id as i64),
+                message_meta::message_id.eq(key.message_id as i64),
+                message_meta::sent.eq(key.sent),
+                message_meta::cached_at.eq(entry.cached_at),
+                message_meta::absent.eq(entry.absent),
+            ))
+            .execute(&conn)?;
+
+        Ok(())
+    }
+}
+
+impl Lookup<MessagesRequest> for Cache {
+    fn lookup(&self, key: &MessagesRequest) -> Result<CacheEntry<Vec<PartialMessage>, Entry>, Self::Err> {
+        use diesel::JoinOnDsl;
+
+        let connection = self.pool.get()?;
+
+        let entry = handle_missing!(message_list_meta::table
+            .filter(message_list_meta::request_hash.eq(key.database_key()))
+            .get_result(&connection));
+
+        let entry = self.entry(entry, self.expiry.messages);
+
+        if entry.absent {
+            return Ok(CacheEntry::MarkedAbsent(entry))
+        }
+
+        let messages: Vec<_> = handle_missing!(message::table
+            .inner_join(message_request_results::table.on(message::message_id.eq(message_request_results::message_id)))
+            .filter(message_request_results::request_hash.eq(key.database_key()))
+            .filter(message::owner_id.eq(key.authentication.account_id as i64))
+            .filter(message::sent.eq(key.sent))
+            .order(message::message_id.desc())
+            .select((
+                message::message_id,
+                message::sent,
+                message::account_id,
+                message::user_id,
+                message::subject,
+                message::username,
+                message::time_since_sent,
+                message::is_read,
+            ))
+            .load(&connection))
+        .into_iter()
+        .map(|row: Wrapped<_>| row.0)
+        .collect();
+
+        Ok(CacheEntry::Cached(messages, entry))
+    }
+}
+
+impl Store<MessagesRequest> for Cache {
+    fn mark_absent(&mut self, key: &MessagesRequest) -> Result<Entry, Self::Err> {
+        warn!("Marking results of MessagesRequest with key {} as absent!", key);
+
+        let entry = Entry::absent(key.database_key());
+
+        update_entry!(self, entry, message_list_meta::table, message_list_meta::request_hash);
+
+        Ok(entry)
+    }
+
+    fn store(&mut self, messages: &Vec<PartialMessage>, key: &MessagesRequest) -> Result<Entry, Self::Err> {
+        debug!("Storing result of MessagesRequest with key {}", key);
+
+        let db_key = key.database_key();
+
+        let conn = self.pool.get()?;
+
+        diesel::delete(message_request_results::table)
+            .filter(message_request_results::request_hash.eq(db_key))
+            .execute(&conn)?;
+
+        for partial in messages {
+            self.store_partial_message(key.authentication.account_id, partial)?;
+
+            diesel::insert_into(message_request_results::table)
+                .values((
+                    message_request_results::message_id.eq(partial.message_id as i64),
+                    message_request_results::request_hash.eq(db_key),
+                ))
+                .execute(&conn)?;
+        }
+
+        let entry = Entry::new(db_key);
+
+        update_entry!(self, entry, message_list_meta::table, message_list_meta::request_hash);
+
+        Ok(entry)
+    }
+}
+
+impl Lookup<MessageRequest> for Cache {
+    fn lookup(&self, key: &MessageRequest) -> Result<CacheEntry<Message, Entry>, Self::Err> {
+        let connection = self.pool.get()?;
+
+        let entry: DatabaseEntry = handle_missing!(message_meta::table
+            .filter(message_meta::request_hash.eq(key.database_key()))
+            .select((message_meta::request_hash, message_meta::cached_at, message_meta::absent))
+            .get_result(&connection));
+
+        if entry.absent {
+            return Ok(CacheEntry::MarkedAbsent(self.entry(entry, self.expiry.absent)))
+        }
+
+        // The content of a message cannot change once it has been sent, so cached messages never
+        // expire
+        let entry = Entry {
+            expired: false,
+            key: entry.key,
+            cached_at: entry.cached_at,
+            absent: false,
+        };
+
+        let (Wrapped(base), body): (Wrapped<PartialMessage>, Option<String>) = handle_missing!(message::table
+            .filter(message::owner_id.eq(key.authentication.account_id as i64))
+            .filter(message::message_id.eq(key.message_id as i64))
+            .filter(message::sent.eq(key.sent))
+            .select((
+                (
+                    message::message_id,
+                    message::sent,
+                    message::account_id,
+                    message::user_id,
+                    message::subject,
+                    message::username,
+                    message::time_since_sent,
+                    message::is_read,
+                ),
+                message::body,
+            ))
+            .get_result(&connection));
+
+        match body {
+            Some(body) => Ok(CacheEntry::Cached(Message { base, body }, entry)),
+            None => Ok(CacheEntry::Missing),
+        }
+    }
+}
+
+impl Store<MessageRequest> for Cache {
+    fn mark_absent(&mut self, key: &MessageRequest) -> Result<Entry, Self::Err> {
+        warn!("Marking message with key {} as absent!", key);
+
+        let entry = Entry::absent(key.database_key());
+
+        self.update_message_entry(entry, key)?;
+
+        Ok(entry)
+    }
+
+    fn store(&mut self, message: &Message, key: &MessageRequest) -> Result<Entry, Self::Err> {
+        debug!("Storing {} under key {}", message, key);
+
+        self.store_partial_message(key.authentication.account_id, &message.base)?;
+
+        diesel::update(
+            message::table
+                .filter(message::owner_id.eq(key.authentication.account_id as i64))
+                .filter(message::message_id.eq(message.base.message_id as i64))
+                .filter(message::sent.eq(message.base.sent)),
+        )
+        .set(message::body.eq(&message.body[..]))
+        .execute(&self.pool.get()?)?;
+
+        let entry = Entry::new(key.database_key());
+
+        self.update_message_entry(entry, key)?;
+
+        Ok(entry)
+    }
+}
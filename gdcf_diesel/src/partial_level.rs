@@ -39,7 +39,7 @@ diesel_stuff! {
 meta_table!(partial_level_meta, level_id);
 
 store_simply!(PartialLevelKey, partial_level, partial_level_meta, level_id);
-lookup_simply!(PartialLevelKey, partial_level, partial_level_meta, level_id);
+lookup_simply!(PartialLevelKey, partial_level, partial_level_meta, level_id, partial_level);
 
 // Metadata table associating the hashes of cached requests with the level ids the requested
 // returned
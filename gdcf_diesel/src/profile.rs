@@ -55,4 +55,4 @@ diesel_stuff! {
 meta_table!(profile_meta, account_id);
 
 store_simply!(UserRequest, profile, profile_meta, account_id);
-lookup_simply!(UserRequest, profile, profile_meta, account_id);
+lookup_simply!(UserRequest, profile, profile_meta, account_id, profile);
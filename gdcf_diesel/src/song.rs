@@ -1,4 +1,4 @@
-use crate::wrap::Wrapped;
+use crate::{meta::Entry, wrap::Wrapped, Cache};
 use diesel::{
     associations::Identifiable,
     backend::Backend,
@@ -6,7 +6,10 @@ use diesel::{
     sql_types::*,
     ExpressionMethods,
 };
-use gdcf::cache::NewgroundsSongKey;
+use gdcf::{
+    api::request::SongInfoRequest,
+    cache::{CacheEntry, Lookup, NewgroundsSongKey, Store},
+};
 use gdcf_model::song::NewgroundsSong;
 
 impl<'a> Identifiable for &'a Wrapped<NewgroundsSong> {
@@ -34,4 +37,20 @@ diesel_stuff! {
 meta_table!(song_meta, song_id);
 
 store_simply!(NewgroundsSongKey, newgrounds_song, song_meta, song_id);
-lookup_simply!(NewgroundsSongKey, newgrounds_song, song_meta, song_id);
+lookup_simply!(NewgroundsSongKey, newgrounds_song, song_meta, song_id, song);
+
+impl Lookup<SongInfoRequest> for Cache {
+    fn lookup(&self, key: &SongInfoRequest) -> Result<CacheEntry<NewgroundsSong, Entry>, Self::Err> {
+        self.lookup(&NewgroundsSongKey(key.song_id))
+    }
+}
+
+impl Store<SongInfoRequest> for Cache {
+    fn store(&mut self, song: &NewgroundsSong, key: &SongInfoRequest) -> Result<Entry, Self::Err> {
+        self.store(song, &NewgroundsSongKey(key.song_id))
+    }
+
+    fn mark_absent(&mut self, key: &SongInfoRequest) -> Result<Entry, Self::Err> {
+        Store::<NewgroundsSongKey>::mark_absent(self, &NewgroundsSongKey(key.song_id))
+    }
+}
@@ -0,0 +1,342 @@
+use crate::{key::DatabaseKey, meta::Entry, wrap::Wrapped, Cache, Error};
+use diesel::{backend::Backend, deserialize::FromSqlRow, sql_types::*, ExpressionMethods, QueryDsl, Queryable, RunQueryDsl};
+use gdcf::{
+    api::request::{BlockedUsersRequest, FriendListRequest, FriendRequestsRequest},
+    cache::{CacheEntry, Lookup, Store},
+};
+use gdcf_model::{
+    user::{Color, FriendRequest, ListedUser},
+    GameMode,
+};
+use log::{debug, warn};
+
+// Friend lists and lists of blocked users are stored under the hash of the request that retrieved
+// them, in the order the server provided them in
+table! {
+    listed_user (request_hash, position) {
+        request_hash -> Int8,
+        position -> Int4,
+        name -> Text,
+        user_id -> Int8,
+        icon_index -> Int2,
+        primary_color -> Int4,
+        secondary_color -> Int4,
+        icon_type -> Int2,
+        has_glow -> Bool,
+        account_id -> Int8,
+    }
+}
+
+type Row = (i64, i32, String, i64, i16, i32, i32, i16, bool, i64);
+type SqlType = (Int8, Int4, Text, Int8, Int2, Int4, Int4, Int2, Bool, Int8);
+
+impl<DB: Backend> Queryable<SqlType, DB> for Wrapped<ListedUser>
+where
+    Row: FromSqlRow<SqlType, DB>,
+{
+    type Row = Row;
+
+    fn build(row: Self::Row) -> Self {
+        Wrapped(ListedUser {
+            name: row.2,
+            user_id: __for_queryable!(row.3, u64),
+            icon_index: __for_queryable!(row.4, u16),
+            primary_color: __for_queryable!(row.5, Color),
+            secondary_color: __for_queryable!(row.6, Color),
+            icon_type: __for_queryable!(row.7, GameMode),
+            has_glow: row.8,
+            account_id: __for_queryable!(row.9, u64),
+        })
+    }
+}
+
+type Values<'a> = (
+    diesel::dsl::Eq<listed_user::request_hash, i64>,
+    diesel::dsl::Eq<listed_user::position, i32>,
+    diesel::dsl::Eq<listed_user::name, &'a str>,
+    diesel::dsl::Eq<listed_user::user_id, i64>,
+    diesel::dsl::Eq<listed_user::icon_index, i16>,
+    diesel::dsl::Eq<listed_user::primary_color, i32>,
+    diesel::dsl::Eq<listed_user::secondary_color, i32>,
+    diesel::dsl::Eq<listed_user::icon_type, i16>,
+    diesel::dsl::Eq<listed_user::has_glow, bool>,
+    diesel::dsl::Eq<listed_user::account_id, i64>,
+);
+
+fn values(key: i64, idx: i32, user: &ListedUser) -> Values<'_> {
+    use listed_user::columns::*;
+
+    (
+        request_hash.eq(key),
+        position.eq(idx),
+        name.eq(&user.name[..]),
+        user_id.eq(__for_values!(user.user_id, u64)),
+        icon_index.eq(__for_values!(user.icon_index, u16)),
+        primary_color.eq(__for_values!(user.primary_color, Color)),
+        secondary_color.eq(__for_values!(user.secondary_color, Color)),
+        icon_type.eq(__for_values!(user.icon_type, GameMode)),
+        has_glow.eq(user.has_glow),
+        account_id.eq(__for_values!(user.account_id, u64)),
+    )
+}
+
+// Metadata table storing information about when a friend list or list of blocked users was cached
+meta_table!(user_list_meta, request_hash);
+
+// Friend requests are stored under the hash of the request that retrieved them, as whether we're
+// looking at the sender or the recipient depends on it
+table! {
+    friend_request (request_hash, request_id) {
+        request_hash -> Int8,
+        request_id -> Int8,
+        name -> Text,
+        user_id -> Int8,
+        icon_index -> Int2,
+        primary_color -> Int4,
+        secondary_color -> Int4,
+        icon_type -> Int2,
+        has_glow -> Bool,
+        account_id -> Int8,
+        message -> Nullable<Text>,
+        time_since_sent -> Text,
+        unread -> Bool,
+    }
+}
+
+type FriendRequestRow = (i64, i64, String, i64, i16, i32, i32, i16, bool, i64, Option<String>, String, bool);
+type FriendRequestSqlType = (
+    Int8,
+    Int8,
+    Text,
+    Int8,
+    Int2,
+    Int4,
+    Int4,
+    Int2,
+    Bool,
+    Int8,
+    Nullable<Text>,
+    Text,
+    Bool,
+);
+
+impl<DB: Backend> Queryable<FriendRequestSqlType, DB> for Wrapped<FriendRequest>
+where
+    FriendRequestRow: FromSqlRow<FriendRequestSqlType, DB>,
+{
+    type Row = FriendRequestRow;
+
+    fn build(row: Self::Row) -> Self {
+        Wrapped(FriendRequest {
+            user: ListedUser {
+                name: row.2,
+                user_id: __for_queryable!(row.3, u64),
+                icon_index: __for_queryable!(row.4, u16),
+                primary_color: __for_queryable!(row.5, Color),
+                secondary_color: __for_queryable!(row.6, Color),
+                icon_type: __for_queryable!(row.7, GameMode),
+                has_glow: row.8,
+                account_id: __for_queryable!(row.9, u64),
+            },
+            request_id: __for_queryable!(row.1, u64),
+            message: row.10,
+            time_since_sent: row.11,
+            unread: row.12,
+        })
+    }
+}
+
+type FriendRequestValues<'a> = (
+    diesel::dsl::Eq<friend_request::request_hash, i64>,
+    diesel::dsl::Eq<friend_request::request_id, i64>,
+    diesel::dsl::Eq<friend_request::name, &'a str>,
+    diesel::dsl::Eq<friend_request::user_id, i64>,
+    diesel::dsl::Eq<friend_request::icon_index, i16>,
+    diesel::dsl::Eq<friend_request::primary_color, i32>,
+    diesel::dsl::Eq<friend_request::secondary_color, i32>,
+    diesel::dsl::Eq<friend_request::icon_type, i16>,
+    diesel::dsl::Eq<friend_request::has_glow, bool>,
+    diesel::dsl::Eq<friend_request::account_id, i64>,
+    diesel::dsl::Eq<friend_request::message, Option<&'a str>>,
+    diesel::dsl::Eq<friend_request::time_since_sent, &'a str>,
+    diesel::dsl::Eq<friend_request::unread, bool>,
+);
+
+fn friend_request_values(key: i64, request: &FriendRequest) -> FriendRequestValues<'_> {
+    use friend_request::columns::*;
+
+    (
+        request_hash.eq(key),
+        request_id.eq(__for_values!(request.request_id, u64)),
+        name.eq(&request.user.name[..]),
+        user_id.eq(__for_values!(request.user.user_id, u64)),
+        icon_index.eq(__for_values!(request.user.icon_index, u16)),
+        primary_color.eq(__for_values!(request.user.primary_color, Color)),
+        secondary_color.eq(__for_values!(request.user.secondary_color, Color)),
+        icon_type.eq(__for_values!(request.user.icon_type, GameMode)),
+        has_glow.eq(request.user.has_glow),
+        account_id.eq(__for_values!(request.user.account_id, u64)),
+        message.eq(request.message.as_ref().map(AsRef::as_ref)),
+        time_since_sent.eq(&request.time_since_sent[..]),
+        unread.eq(request.unread),
+    )
+}
+
+// Metadata table storing information about when a page of friend requests was cached
+meta_table!(friend_request_meta, request_hash);
+
+impl Cache {
+    fn lookup_user_list(&self, db_key: i64) -> Result<CacheEntry<Vec<ListedUser>, Entry>, Error> {
+        let connection = self.pool.get()?;
+
+        let entry = handle_missing!(user_list_meta::table
+            .filter(user_list_meta::request_hash.eq(db_key))
+            .get_result(&connection));
+
+        let entry = self.entry(entry, self.expiry.user_lists);
+
+        if entry.absent {
+            return Ok(CacheEntry::MarkedAbsent(entry))
+        }
+
+        let users: Vec<_> = handle_missing!(listed_user::table
+            .filter(listed_user::request_hash.eq(db_key))
+            .order(listed_user::position.asc())
+            .load(&connection))
+        .into_iter()
+        .map(|row: Wrapped<_>| row.0)
+        .collect();
+
+        Ok(CacheEntry::Cached(users, entry))
+    }
+
+    fn mark_user_list_absent(&self, db_key: i64) -> Result<Entry, Error> {
+        let entry = Entry::absent(db_key);
+
+        update_entry!(self, entry, user_list_meta::table, user_list_meta::request_hash);
+
+        Ok(entry)
+    }
+
+    fn store_user_list(&self, users: &[ListedUser], db_key: i64) -> Result<Entry, Error> {
+        let conn = self.pool.get()?;
+
+        diesel::delete(listed_user::table)
+            .filter(listed_user::request_hash.eq(db_key))
+            .execute(&conn)?;
+
+        for (idx, user) in users.iter().enumerate() {
+            diesel::insert_into(listed_user::table)
+                .values(values(db_key, idx as i32, user))
+                .execute(&conn)?;
+        }
+
+        let entry = Entry::new(db_key);
+
+        update_entry!(self, entry, user_list_meta::table, user_list_meta::request_hash);
+
+        Ok(entry)
+    }
+}
+
+impl Lookup<FriendListRequest> for Cache {
+    fn lookup(&self, key: &FriendListRequest) -> Result<CacheEntry<Vec<ListedUser>, Entry>, Self::Err> {
+        self.lookup_user_list(key.database_key())
+    }
+}
+
+impl Store<FriendListRequest> for Cache {
+    fn mark_absent(&mut self, key: &FriendListRequest) -> Result<Entry, Self::Err> {
+        warn!("Marking results of FriendListRequest with key {} as absent!", key);
+
+        self.mark_user_list_absent(key.database_key())
+    }
+
+    fn store(&mut self, users: &Vec<ListedUser>, key: &FriendListRequest) -> Result<Entry, Self::Err> {
+        debug!("Storing result of FriendListRequest with key {}", key);
+
+        self.store_user_list(users, key.database_key())
+    }
+}
+
+impl Lookup<BlockedUsersRequest> for Cache {
+    fn lookup(&self, key: &BlockedUsersRequest) -> Result<CacheEntry<Vec<ListedUser>, Entry>, Self::Err> {
+        self.lookup_user_list(key.database_key())
+    }
+}
+
+impl Store<BlockedUsersRequest> for Cache {
+    fn mark_absent(&mut self, key: &BlockedUsersRequest) -> Result<Entry, Self::Err> {
+        warn!("Marking results of BlockedUsersRequest with key {} as absent!", key);
+
+        self.mark_user_list_absent(key.database_key())
+    }
+
+    fn store(&mut self, users: &Vec<ListedUser>, key: &BlockedUsersRequest) -> Result<Entry, Self::Err> {
+        debug!("Storing result of BlockedUsersRequest with key {}", key);
+
+        self.store_user_list(users, key.database_key())
+    }
+}
+
+impl Lookup<FriendRequestsRequest> for Cache {
+    fn lookup(&self, key: &FriendRequestsRequest) -> Result<CacheEntry<Vec<FriendRequest>, Entry>, Self::Err> {
+        let connection = self.pool.get()?;
+
+        let entry = handle_missing!(friend_request_meta::table
+            .filter(friend_request_meta::request_hash.eq(key.database_key()))
+            .get_result(&connection));
+
+        let entry = self.entry(entry, self.expiry.friend_requests);
+
+        if entry.absent {
+            return Ok(CacheEntry::MarkedAbsent(entry))
+        }
+
+        let requests: Vec<_> = handle_missing!(friend_request::table
+            .filter(friend_request::request_hash.eq(key.database_key()))
+            .order(friend_request::request_id.desc())
+            .load(&connection))
+        .into_iter()
+        .map(|row: Wrapped<_>| row.0)
+        .collect();
+
+        Ok(CacheEntry::Cached(requests, entry))
+    }
+}
+
+impl Store<FriendRequestsRequest> for Cache {
+    fn mark_absent(&mut self, key: &FriendRequestsRequest) -> Result<Entry, Self::Err> {
+        warn!("Marking results of FriendRequestsRequest with key {} as absent!", key);
+
+        let entry = Entry::absent(key.database_key());
+
+        update_entry!(self, entry, friend_request_meta::table, friend_request_meta::request_hash);
+
+        Ok(entry)
+    }
+
+    fn store(&mut self, requests: &Vec<FriendRequest>, key: &FriendRequestsRequest) -> Result<Entry, Self::Err> {
+        debug!("Storing result of FriendRequestsRequest with key {}", key);
+
+        let db_key = key.database_key();
+
+        let conn = self.pool.get()?;
+
+        diesel::delete(friend_request::table)
+            .filter(friend_request::request_hash.eq(db_key))
+            .execute(&conn)?;
+
+        for request in requests {
+            diesel::insert_into(friend_request::table)
+                .values(friend_request_values(db_key, request))
+                .execute(&conn)?;
+        }
+
+        let entry = Entry::new(db_key);
+
+        update_entry!(self, entry, friend_request_meta::table, friend_request_meta::request_hash);
+
+        Ok(entry)
+    }
+}
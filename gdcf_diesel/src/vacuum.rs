@@ -0,0 +1,469 @@
+use crate::{
+    comment::{level_comment, level_comment_list_level, level_comment_list_meta, level_comment_request_results},
+    comment_user::{comment_user, comment_user_meta},
+    creator::{creator, creator_meta},
+    daily_level::{daily_level, daily_level_meta},
+    gauntlet::{gauntlet, gauntlet_list_meta},
+    leaderboard::{leaderboard_entry, leaderboard_meta},
+    level::{level, level_meta},
+    level_score::{level_score, level_score_meta},
+    map_pack::{map_pack, map_pack_list_meta, map_pack_request_results},
+    message::{message_list_meta, message_meta, message_request_results},
+    partial_level::{level_list_meta, level_request_results, partial_level, partial_level_meta},
+    profile::{profile, profile_meta},
+    song::{newgrounds_song, song_meta},
+    user_list::{friend_request, friend_request_meta, listed_user, user_list_meta},
+    Cache, Error,
+};
+use chrono::{Duration, NaiveDateTime, Utc};
+use diesel::{BoolExpressionMethods, Connection, ExpressionMethods, QueryDsl, RunQueryDsl};
+use log::{debug, info};
+
+/// Struct describing which entries [`Cache::vacuum`] removes from the cache
+///
+/// Every criterion applies to the entries of each metadata table separately. The cached data
+/// belonging to removed entries is deleted alongside them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct VacuumPolicy {
+    /// Entries cached longer ago than this are removed, regardless of whether they're expired.
+    /// Leaderboard snapshots older than this are removed as well. If `None`, the age of entries
+    /// isn't taken into account.
+    pub max_age: Option<Duration>,
+
+    /// Whether entries that have been marked as absent should be removed, regardless of their age
+    pub prune_absent: bool,
+
+    /// The maximum amount of entries to keep in each metadata table. If a table contains more
+    /// entries, the ones that were cached the longest time ago are removed. If `None`, the amount
+    /// of entries isn't limited.
+    pub max_entries: Option<u32>,
+}
+
+impl VacuumPolicy {
+    /// Sets the age after which entries are removed
+    pub fn max_age(mut self, max_age: Duration) -> Self {
+        self.max_age = Some(max_age);
+        self
+    }
+
+    /// Sets whether entries marked as absent should be removed
+    pub fn prune_absent(mut self, prune_absent: bool) -> Self {
+        self.prune_absent = prune_absent;
+        self
+    }
+
+    /// Sets the maximum amount of entries to keep in each metadata table
+    pub fn max_entries(mut self, max_entries: u32) -> Self {
+        self.max_entries = Some(max_entries);
+        self
+    }
+}
+
+/// Removes the entries matching the given [`VacuumPolicy`] from the given meta tables, evaluating
+/// to the amount of removed rows
+macro_rules! prune_entries {
+    ($connection: expr, $policy: expr, $($meta: ident($primary: ident)),*) => {{
+        let mut removed = 0;
+
+        $(
+            if let Some(max_age) = $policy.max_age {
+                removed += diesel::delete($meta::table.filter($meta::cached_at.lt(cutoff(max_age)))).execute($connection)?;
+            }
+
+            if $policy.prune_absent {
+                removed += diesel::delete($meta::table.filter($meta::absent.eq(true))).execute($connection)?;
+            }
+
+            if let Some(max_entries) = $policy.max_entries {
+                let newest = $meta::table
+                    .select($meta::$primary)
+                    .order($meta::cached_at.desc())
+                    .limit(i64::from(max_entries));
+
+                removed += diesel::delete($meta::table.filter($meta::$primary.ne_all(newest))).execute($connection)?;
+            }
+
+            debug!("Pruned meta table {}, {} rows removed so far", stringify!($meta), removed);
+        )*
+
+        removed
+    }};
+}
+
+/// Removes all rows from the given tables that are no longer referenced from the table they're
+/// associated with, evaluating to the amount of removed rows
+macro_rules! remove_orphans {
+    ($connection: expr, $($table: ident($column: ident) => $referencing: ident($referencing_column: ident)),*) => {{
+        let mut removed = 0;
+
+        $(
+            let orphans = diesel::delete($table::table.filter($table::$column.ne_all($referencing::table.select($referencing::$referencing_column))))
+                .execute($connection)?;
+
+            debug!("Removed {} orphaned rows from {}", orphans, stringify!($table));
+
+            removed += orphans;
+        )*
+
+        removed
+    }};
+}
+
+/// SQL condition matching the rows of the `message` table whose content has no entry in
+/// `message_meta`
+const WITHOUT_CONTENT_ENTRY: &str = "NOT EXISTS (SELECT 1 FROM message_meta WHERE message_meta.owner_id = message.owner_id AND \
+                                     message_meta.message_id = message.message_id AND message_meta.sent = message.sent)";
+
+fn cutoff(max_age: Duration) -> NaiveDateTime {
+    Utc::now().naive_utc() - max_age
+}
+
+impl Cache {
+    /// Removes the entries matching the given [`VacuumPolicy`] from this cache, together with all
+    /// cached data that's no longer reachable afterwards (for example partial levels that are no
+    /// longer part of any cached [`LevelsRequest`](gdcf::api::request::LevelsRequest) result).
+    ///
+    /// When using sqlite, the database file is compacted afterwards, which might take a while for
+    /// large databases.
+    ///
+    /// Returns the amount of rows that were removed
+    pub fn vacuum(&self, policy: VacuumPolicy) -> Result<usize, Error> {
+        let connection = self.pool.get()?;
+
+        let removed = connection.transaction::<_, Error, _>(|| {
+            let mut removed = prune_entries!(
+                &connection,
+                policy,
+                level_list_meta(request_hash),
+                partial_level_meta(level_id),
+                level_meta(level_id),
+                daily_level_meta(request_key),
+                song_meta(song_id),
+                creator_meta(user_id),
+                profile_meta(account_id),
+                level_comment_list_meta(request_hash),
+                comment_user_meta(user_id),
+                level_score_meta(request_hash),
+                map_pack_list_meta(request_hash),
+                gauntlet_list_meta(request_hash),
+                message_list_meta(request_hash),
+                message_meta(request_hash),
+                user_list_meta(request_hash),
+                friend_request_meta(request_hash),
+                leaderboard_meta(request_hash)
+            );
+
+            // The order matters here: the result sets of requests need to be cleaned up before the
+            // objects they reference
+            removed += remove_orphans!(
+                &connection,
+                level_request_results(request_hash) => level_list_meta(request_hash),
+                level(level_id) => level_meta(level_id),
+                daily_level(request_key) => daily_level_meta(request_key),
+                newgrounds_song(song_id) => song_meta(song_id),
+                creator(user_id) => creator_meta(user_id),
+                profile(account_id) => profile_meta(account_id),
+                comment_user(user_id) => comment_user_meta(user_id),
+                level_comment_request_results(request_hash) => level_comment_list_meta(request_hash),
+                level_comment_list_level(request_hash) => level_comment_list_meta(request_hash),
+                level_comment(comment_id) => level_comment_request_results(comment_id),
+                level_score(request_hash) => level_score_meta(request_hash),
+                map_pack_request_results(request_hash) => map_pack_list_meta(request_hash),
+                map_pack(pack_id) => map_pack_request_results(pack_id),
+                message_request_results(request_hash) => message_list_meta(request_hash),
+                listed_user(request_hash) => user_list_meta(request_hash),
+                friend_request(request_hash) => friend_request_meta(request_hash)
+            );
+
+            // Partial levels are still needed as long as they're part of a cached level list, even if
+            // their own entry has been removed
+            removed += diesel::delete(
+                partial_level::table.filter(
+                    partial_level::level_id
+                        .ne_all(partial_level_meta::table.select(partial_level_meta::level_id))
+                        .and(partial_level::level_id.ne_all(level_request_results::table.select(level_request_results::level_id))),
+                ),
+            )
+            .execute(&connection)?;
+
+            // Messages are still needed as long as they're part of a cached list of messages, even if
+            // the meta entry of their content has been removed. In that case only the content is
+            // removed. Diesel cannot express the correlated subqueries needed here.
+            removed += diesel::sql_query(format!(
+                "DELETE FROM message WHERE message_id NOT IN (SELECT message_id FROM message_request_results) AND {}",
+                WITHOUT_CONTENT_ENTRY
+            ))
+            .execute(&connection)?;
+
+            let cleared = diesel::sql_query(format!(
+                "UPDATE message SET body = NULL WHERE body IS NOT NULL AND {}",
+                WITHOUT_CONTENT_ENTRY
+            ))
+            .execute(&connection)?;
+
+            debug!("Removed the content of {} messages", cleared);
+
+            // There's only a single list of gauntlets
+            let gauntlet_lists: i64 = gauntlet_list_meta::table.count().get_result(&connection)?;
+
+            if gauntlet_lists == 0 {
+                removed += diesel::delete(gauntlet::table).execute(&connection)?;
+            }
+
+            // Unlike other data, old leaderboard snapshots are kept around after their meta entry has
+            // been replaced, so they can only be removed based on their age
+            if let Some(max_age) = policy.max_age {
+                removed += diesel::delete(leaderboard_entry::table.filter(leaderboard_entry::cached_at.lt(cutoff(max_age))))
+                    .execute(&connection)?;
+            }
+
+            Ok(removed)
+        })?;
+
+        info!("Vacuuming removed {} rows from the cache", removed);
+
+        // Deleting rows doesn't shrink the database file, sqlite requires explicit compaction
+        #[cfg(feature = "sqlite")]
+        diesel::sql_query("VACUUM").execute(&connection)?;
+
+        Ok(removed)
+    }
+}
+
+#[cfg(all(test, feature = "sqlite"))]
+mod tests {
+    use super::*;
+    use crate::{message::message, meta::Entry};
+    use gdcf::{
+        api::request::{Authentication, MessageRequest, MessagesRequest},
+        cache::{CacheEntry, Lookup, Store},
+    };
+    use gdcf_model::message::{Message, PartialMessage};
+
+    /// Inserts the same five entries into every given meta table: one cached two days ago, one
+    /// marked absent, and three fresh ones, cached in the order of their keys
+    macro_rules! seed {
+        ($connection: expr, $($meta: ident),*) => {
+            $(
+                for entry in entries() {
+                    diesel::insert_into($meta::table).values(entry).execute($connection).unwrap();
+                }
+            )*
+        };
+    }
+
+    /// Evaluates to the keys remaining in each of the given meta tables
+    macro_rules! remaining {
+        ($connection: expr, $($meta: ident($primary: ident)),*) => {
+            vec![$(
+                (stringify!($meta), $meta::table.select($meta::$primary).order($meta::$primary).load::<i64>($connection).unwrap())
+            ),*]
+        };
+    }
+
+    fn entries() -> Vec<Entry> {
+        let now = Utc::now().naive_utc();
+
+        vec![
+            entry(1, now - Duration::days(2), false),
+            entry(2, now - Duration::minutes(40), true),
+            entry(3, now - Duration::minutes(30), false),
+            entry(4, now - Duration::minutes(20), false),
+            entry(5, now - Duration::minutes(10), false),
+        ]
+    }
+
+    fn entry(key: i64, cached_at: NaiveDateTime, absent: bool) -> Entry {
+        Entry {
+            cached_at,
+            expired: false,
+            key,
+            absent,
+        }
+    }
+
+    fn cache() -> Cache {
+        let cache = Cache::in_memory().unwrap();
+
+        cache.initialize().unwrap();
+        cache
+    }
+
+    /// Seeds every meta table of a fresh cache, vacuums it with the given policy and returns the
+    /// keys remaining in each table
+    fn vacuum_seeded(policy: VacuumPolicy) -> Vec<(&'static str, Vec<i64>)> {
+        let cache = cache();
+        let connection = cache.pool.get().unwrap();
+
+        seed!(
+            &connection,
+            level_list_meta,
+            partial_level_meta,
+            level_meta,
+            daily_level_meta,
+            song_meta,
+            creator_meta,
+            profile_meta,
+            level_comment_list_meta,
+            comment_user_meta,
+            level_score_meta,
+            map_pack_list_meta,
+            gauntlet_list_meta,
+            message_list_meta,
+            user_list_meta,
+            friend_request_meta,
+            leaderboard_meta
+        );
+
+        for entry in entries() {
+            diesel::insert_into(message_meta::table)
+                .values((
+                    message_meta::request_hash.eq(entry.key),
+                    message_meta::owner_id.eq(1),
+                    message_meta::message_id.eq(entry.key),
+                    message_meta::sent.eq(false),
+                    message_meta::cached_at.eq(entry.cached_at),
+                    message_meta::absent.eq(entry.absent),
+                ))
+                .execute(&connection)
+                .unwrap();
+        }
+
+        cache.vacuum(policy).unwrap();
+
+        remaining!(
+            &connection,
+            level_list_meta(request_hash),
+            partial_level_meta(level_id),
+            level_meta(level_id),
+            daily_level_meta(request_key),
+            song_meta(song_id),
+            creator_meta(user_id),
+            profile_meta(account_id),
+            level_comment_list_meta(request_hash),
+            comment_user_meta(user_id),
+            level_score_meta(request_hash),
+            map_pack_list_meta(request_hash),
+            gauntlet_list_meta(request_hash),
+            message_list_meta(request_hash),
+            message_meta(request_hash),
+            user_list_meta(request_hash),
+            friend_request_meta(request_hash),
+            leaderboard_meta(request_hash)
+        )
+    }
+
+    fn assert_remaining(remaining: Vec<(&str, Vec<i64>)>, expected: &[i64]) {
+        for (table, keys) in remaining {
+            assert_eq!(keys, expected, "unexpected entries remaining in {}", table);
+        }
+    }
+
+    #[test]
+    fn empty_policy_removes_nothing() {
+        assert_remaining(vacuum_seeded(VacuumPolicy::default()), &[1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn max_age() {
+        assert_remaining(vacuum_seeded(VacuumPolicy::default().max_age(Duration::days(1))), &[2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn prune_absent() {
+        assert_remaining(vacuum_seeded(VacuumPolicy::default().prune_absent(true)), &[1, 3, 4, 5]);
+    }
+
+    #[test]
+    fn max_entries() {
+        assert_remaining(vacuum_seeded(VacuumPolicy::default().max_entries(2)), &[4, 5]);
+    }
+
+    #[test]
+    fn combined_policy() {
+        let policy = VacuumPolicy::default().max_age(Duration::days(1)).prune_absent(true).max_entries(3);
+
+        assert_remaining(vacuum_seeded(policy), &[3, 4, 5]);
+    }
+
+    fn authentication() -> Authentication {
+        Authentication::new(1, "gjp".to_string())
+    }
+
+    fn received_message(message_id: u64) -> Message {
+        Message {
+            base: PartialMessage {
+                message_id,
+                sent: false,
+                account_id: 2,
+                user_id: 3,
+                subject: format!("Message {}", message_id),
+                username: "sender".to_string(),
+                time_since_sent: "1 hour".to_string(),
+                read: false,
+            },
+            body: "Hello".to_string(),
+        }
+    }
+
+    /// Backdates the meta entry of the content of the message with the given ID by two days
+    fn backdate_content(cache: &Cache, message_id: u64) {
+        diesel::update(message_meta::table.filter(message_meta::message_id.eq(message_id as i64)))
+            .set(message_meta::cached_at.eq(cutoff(Duration::days(2))))
+            .execute(&cache.pool.get().unwrap())
+            .unwrap();
+    }
+
+    fn message_ids(cache: &Cache) -> Vec<i64> {
+        message::table
+            .select(message::message_id)
+            .order(message::message_id)
+            .load(&cache.pool.get().unwrap())
+            .unwrap()
+    }
+
+    #[test]
+    fn prunes_message_contents() {
+        let mut cache = cache();
+        let policy = VacuumPolicy::default().max_age(Duration::days(1));
+
+        // Messages 1 and 2 are part of the inbox, the content of messages 2 and 3 has been cached
+        cache
+            .store(
+                &vec![received_message(1).base, received_message(2).base],
+                &MessagesRequest::inbox(authentication()),
+            )
+            .unwrap();
+        cache
+            .store(&received_message(2), &MessageRequest::new(authentication(), 2))
+            .unwrap();
+        cache
+            .store(&received_message(3), &MessageRequest::new(authentication(), 3))
+            .unwrap();
+
+        backdate_content(&cache, 3);
+        cache.vacuum(policy).unwrap();
+
+        // Message 3 is no longer referenced from anywhere
+        assert_eq!(message_ids(&cache), vec![1, 2]);
+        assert!(matches!(
+            cache.lookup(&MessageRequest::new(authentication(), 2)).unwrap(),
+            CacheEntry::Cached(..)
+        ));
+
+        backdate_content(&cache, 2);
+        cache.vacuum(policy).unwrap();
+
+        // Message 2 is still part of the inbox, only its content is gone
+        assert_eq!(message_ids(&cache), vec![1, 2]);
+        assert_eq!(
+            cache.lookup(&MessageRequest::new(authentication(), 2)).unwrap(),
+            CacheEntry::Missing
+        );
+
+        match cache.lookup(&MessagesRequest::inbox(authentication())).unwrap() {
+            CacheEntry::Cached(messages, _) => assert_eq!(messages.len(), 2),
+            other => panic!("unexpected {:?}", other),
+        }
+    }
+}
@@ -0,0 +1,17 @@
+use crate::Cache;
+use gdcf::cache::InvalidateObjects;
+
+impl InvalidateObjects for Cache {
+    fn invalidate_level(&mut self, level_id: u64) -> Result<(), Self::Err> {
+        Cache::invalidate_level(self, level_id)
+    }
+
+    fn invalidate_level_comments(&mut self, level_id: u64) -> Result<(), Self::Err> {
+        Cache::invalidate_level_comments(self, level_id)
+    }
+
+    // Profile comments aren't cached
+    fn invalidate_profile_comments(&mut self, _: u64) -> Result<(), Self::Err> {
+        Ok(())
+    }
+}
@@ -0,0 +1,103 @@
+use gdcf::{
+    api::request::{
+        BlockedUsersRequest, DailyLevelRequest, FriendListRequest, FriendRequestsRequest, GauntletsRequest, LeaderboardRequest,
+        LevelCommentsRequest, LevelRequest, LevelScoresRequest, LevelsRequest, MapPacksRequest, MessageRequest, MessagesRequest,
+        ProfileCommentsRequest, SongInfoRequest, UserRequest, UserSearchRequest,
+    },
+    cache::{CommentUserKey, CreatorKey, Key, NewgroundsSongKey},
+};
+use gdcf_model::{
+    comment::{CommentUser, LevelComment, ProfileComment},
+    level::{DailyLevel, Gauntlet, Level, LevelScore, MapPack, PartialLevel},
+    message::{Message, PartialMessage},
+    song::NewgroundsSong,
+    user::{Creator, FriendRequest, LeaderboardEntry, ListedUser, SearchedUser, User},
+};
+use gdcf_parse::{error::ValueError, Parse};
+
+/// Trait for keys whose cached data is stored in the format the Geometry Dash servers use in their
+/// responses
+///
+/// For requests, this is the unchanged response body of the servers.
+pub(crate) trait RobtopFormat: Key {
+    /// Parses the object cached under a key of this type from the given data. In case of failure,
+    /// returns a description of what went wrong
+    fn parse(data: &str) -> Result<Self::Result, String>;
+}
+
+/// Trait for keys of objects the servers only provide alongside the results of other requests
+///
+/// There is no response body of their own to store for these, so they are stored in the format
+/// they have inside of the responses they were part of.
+pub(crate) trait Fragment: RobtopFormat {
+    fn unparse(object: &Self::Result) -> String;
+}
+
+/// Gets the section of the given response body containing the requested objects. The other
+/// sections contain secondary objects and pagination info.
+fn main_section(body: &str) -> &str {
+    body.split('#').next().unwrap_or(body)
+}
+
+/// Parses the `|`-separated list of objects in the given section of a response body
+fn list<'a, T>(section: &'a str, parse: impl Fn(&'a str) -> Result<T, ValueError<'a>>) -> Result<Vec<T>, ValueError<'a>> {
+    section.split('|').filter(|fragment| !fragment.is_empty()).map(parse).collect()
+}
+
+macro_rules! robtop_format {
+    ($($key_type: ty => |$data: ident| $parse: expr),*) => {
+        $(
+            impl RobtopFormat for $key_type {
+                fn parse($data: &str) -> Result<Self::Result, String> {
+                    $parse.map_err(|err| err.to_string())
+                }
+            }
+        )*
+    };
+}
+
+robtop_format!(
+    LevelRequest => |body| Level::parse_str(main_section(body), ':'),
+    LevelsRequest => |body| list(main_section(body), |fragment| PartialLevel::parse_str(fragment, ':')),
+    LevelScoresRequest => |body| list(body, |fragment| LevelScore::parse_str(fragment, ':')),
+    DailyLevelRequest => |body| DailyLevel::parse_unindexed_str(body, '|'),
+    // Every comment is followed by the user that posted it, which was cached separately
+    LevelCommentsRequest => |body| list(main_section(body), |fragment| {
+        LevelComment::parse_str(fragment.split(':').next().unwrap_or(fragment), '~')
+    }),
+    ProfileCommentsRequest => |body| list(main_section(body), |fragment| ProfileComment::parse_str(fragment, '~')),
+    MapPacksRequest => |body| list(main_section(body), |fragment| MapPack::parse_str(fragment, ':')),
+    GauntletsRequest => |body| list(main_section(body), |fragment| Gauntlet::parse_str(fragment, ':')),
+    LeaderboardRequest => |body| list(body, |fragment| LeaderboardEntry::parse_str(fragment, ':')),
+    UserRequest => |body| User::parse_str(body, ':'),
+    UserSearchRequest => |body| SearchedUser::parse_str(main_section(body), ':'),
+    FriendListRequest => |body| list(body, |fragment| ListedUser::parse_str(fragment, ':')),
+    BlockedUsersRequest => |body| list(body, |fragment| ListedUser::parse_str(fragment, ':')),
+    FriendRequestsRequest => |body| list(main_section(body), |fragment| FriendRequest::parse_str(fragment, ':')),
+    MessagesRequest => |body| list(main_section(body), |fragment| PartialMessage::parse_str(fragment, ':')),
+    MessageRequest => |body| Message::parse_str(body, ':'),
+    // The response to a `SongInfoRequest` has the same format as the songs provided alongside
+    // levels, which is why both can share their entries
+    SongInfoRequest => |body| NewgroundsSong::parse_str2(body, "~|~"),
+    NewgroundsSongKey => |data| NewgroundsSong::parse_str2(data, "~|~"),
+    CreatorKey => |data| Creator::parse_unindexed_str(data, ':'),
+    CommentUserKey => |data| CommentUser::parse_str(data, '~')
+);
+
+impl Fragment for NewgroundsSongKey {
+    fn unparse(song: &NewgroundsSong) -> String {
+        song.clone().unparse_str2("~|~")
+    }
+}
+
+impl Fragment for CreatorKey {
+    fn unparse(creator: &Creator) -> String {
+        creator.clone().unparse_unindexed_str(':')
+    }
+}
+
+impl Fragment for CommentUserKey {
+    fn unparse(user: &CommentUser) -> String {
+        user.clone().unparse_str('~')
+    }
+}
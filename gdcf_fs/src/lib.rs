@@ -0,0 +1,529 @@
+//! [`Cache`](gdcf::cache::Cache) implementation for use with `gdcf`, storing objects in plain files
+//!
+//! Every entry consists of two files in the cache directory: a `.txt` file containing the response
+//! body the Geometry Dash servers sent for the request, unchanged, and a `.json` file containing
+//! metadata about the entry (the request it belongs to, when it was cached, etc.). Entries that
+//! have been marked as absent have no `.txt` file. Objects the servers only provide alongside the
+//! results of other requests (creators, songs, etc.) are stored in the format they have inside of
+//! those responses.
+//!
+//! The cached data is parsed using `gdcf_parse` on every lookup. This means that improvements to
+//! the parsers (e.g. once the meaning of more `index_*` fields is known) automatically apply to
+//! all data ever cached.
+//!
+//! Since entries can only be created from response bodies, results of requests can only be cached
+//! via [`Store::store_raw`](gdcf::cache::Store::store_raw), which `gdcf` does for all requests it
+//! makes. Storing them via [`Store::store`](gdcf::cache::Store::store) fails with
+//! [`Error::MissingBody`]. When used as the first tier of a
+//! [`TieredCache`](gdcf::cache::TieredCache), entries can thus only be promoted from second tiers
+//! that keep the response bodies as well (see
+//! [`Lookup::lookup_raw`](gdcf::cache::Lookup::lookup_raw)).
+
+#![deny(unused_must_use)]
+#![deny(unused_imports)]
+
+mod format;
+mod write;
+
+use crate::format::{Fragment, RobtopFormat};
+use chrono::{Duration, NaiveDateTime, Utc};
+use failure::Fail;
+use gdcf::{
+    api::request::{
+        BlockedUsersRequest, DailyLevelRequest, FriendListRequest, FriendRequestsRequest, GauntletsRequest, LeaderboardRequest,
+        LevelCommentsRequest, LevelRequest, LevelScoresRequest, LevelsRequest, MapPacksRequest, MessageRequest, MessagesRequest,
+        ProfileCommentsRequest, SongInfoRequest, UserRequest, UserSearchRequest,
+    },
+    cache::{
+        refresh_seconds_left, special_level_expiry, CacheEntry, CacheEntryMeta, CommentUserKey, CreatorKey, Key, KeyPath, Lookup,
+        NewgroundsSongKey, Store,
+    },
+    error::CacheError,
+};
+use gdcf_model::level::{DailyLevel, Level};
+use log::{debug, warn};
+use serde_derive::{Deserialize, Serialize};
+use std::{
+    any::TypeId,
+    collections::HashMap,
+    fmt::{Display, Formatter},
+    fs,
+    io::ErrorKind,
+    path::{Path, PathBuf},
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+#[derive(Debug, Clone)]
+pub struct Cache {
+    /// The directory all entries are stored in
+    root: PathBuf,
+
+    /// The expiry used for all kinds of objects that don't have one set in `expiry_overrides`
+    expiry: Duration,
+
+    /// Expiry of entries that have been marked as absent, regardless of their kind
+    absent_expiry: Duration,
+
+    /// Expiry of specific kinds of objects, indexed by the [`TypeId`] of their key type
+    expiry_overrides: HashMap<TypeId, Duration>,
+}
+
+impl Cache {
+    /// Constructs a new [`Cache`] storing its entries in the given directory. The directory is
+    /// created if it doesn't exist yet.
+    pub fn new(root: impl Into<PathBuf>) -> Result<Cache, Error> {
+        let root = root.into();
+
+        fs::create_dir_all(&root)?;
+
+        Ok(Cache {
+            root,
+            expiry: Duration::minutes(60),
+            absent_expiry: Duration::minutes(60),
+            expiry_overrides: HashMap::new(),
+        })
+    }
+
+    /// Sets the [`Duration`] after which cached objects are considered outdated. This applies to
+    /// every kind of object whose expiry hasn't been set via [`Cache::with_expiry_for`]
+    ///
+    /// Allows builder-style configuration of the cache
+    pub fn with_expiry(mut self, expiry: Duration) -> Self {
+        self.expiry = expiry;
+        self
+    }
+
+    /// Sets the [`Duration`] after which the results of lookups using keys of type `K` are
+    /// considered outdated
+    pub fn with_expiry_for<K: Key + 'static>(mut self, expiry: Duration) -> Self {
+        self.expiry_overrides.insert(TypeId::of::<K>(), expiry);
+        self
+    }
+
+    /// Sets the [`Duration`] after which entries marked as absent are considered outdated
+    pub fn with_absent_expiry(mut self, expiry: Duration) -> Self {
+        self.absent_expiry = expiry;
+        self
+    }
+
+    /// Gets the directory this cache stores its entries in
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    fn expiry_of<K: 'static>(&self) -> Duration {
+        self.expiry_overrides.get(&TypeId::of::<K>()).cloned().unwrap_or(self.expiry)
+    }
+
+    /// Gets the location of the given key in the cache directory, without extension, as it is
+    /// shared by the data and the metadata file of an entry
+    fn path(&self, key: &impl KeyPath) -> PathBuf {
+        self.root.join(key.key_path().iter().collect::<PathBuf>())
+    }
+
+    fn data_path(&self, key: &impl KeyPath) -> PathBuf {
+        self.path(key).with_extension("txt")
+    }
+
+    fn metadata_path(&self, key: &impl KeyPath) -> PathBuf {
+        self.path(key).with_extension("json")
+    }
+
+    fn lookup_entry<K>(&self, key: &K, expire_after: Duration) -> Result<CacheEntry<K::Result, Entry>, Error>
+    where
+        K: KeyPath + RobtopFormat,
+    {
+        let metadata = match read_metadata(&self.metadata_path(key))? {
+            Some(metadata) => metadata,
+            None => return Ok(CacheEntry::Missing),
+        };
+
+        let expire_after = if metadata.absent { self.absent_expiry } else { expire_after };
+
+        let entry = Entry {
+            cached_at: metadata.cached_at,
+            expired: metadata.invalidated || Utc::now().naive_utc() - metadata.cached_at > expire_after,
+            absent: metadata.absent,
+        };
+
+        if metadata.absent {
+            return Ok(CacheEntry::MarkedAbsent(entry))
+        }
+
+        let data_path = self.data_path(key);
+        let data = match fs::read_to_string(&data_path) {
+            Ok(data) => data,
+            Err(ref err) if err.kind() == ErrorKind::NotFound => {
+                warn!("Data file {} of non-absent cache entry is missing", data_path.display());
+
+                return Ok(CacheEntry::Missing)
+            },
+            Err(err) => return Err(err.into()),
+        };
+
+        match K::parse(&data) {
+            Ok(object) => Ok(CacheEntry::Cached(object, entry)),
+            Err(err) => Err(Error::Parse(data_path.display().to_string(), err)),
+        }
+    }
+
+    /// Reads the data file of the entry with the given key, which for requests is the response body
+    /// the entry was cached from
+    fn lookup_body(&self, key: &impl KeyPath) -> Result<Option<String>, Error> {
+        match fs::read_to_string(self.data_path(key)) {
+            Ok(data) => Ok(Some(data)),
+            Err(ref err) if err.kind() == ErrorKind::NotFound => Ok(None),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    fn store_entry<K>(&self, key: &K, data: Option<&str>) -> Result<Entry, Error>
+    where
+        K: KeyPath + Display,
+    {
+        let metadata = Metadata {
+            request: key.to_string(),
+            cached_at: Utc::now().naive_utc(),
+            absent: data.is_none(),
+            invalidated: false,
+        };
+
+        // The metadata file is written last, so that an entry is only visible once it's complete
+        match data {
+            Some(data) => write_atomically(&self.data_path(key), data)?,
+            None =>
+                match fs::remove_file(self.data_path(key)) {
+                    Err(ref err) if err.kind() == ErrorKind::NotFound => (),
+                    result => result?,
+                },
+        }
+
+        write_metadata(&self.metadata_path(key), &metadata)?;
+
+        Ok(Entry {
+            cached_at: metadata.cached_at,
+            expired: false,
+            absent: metadata.absent,
+        })
+    }
+
+    /// Marks the entry with the given key as expired, if it exists
+    pub(crate) fn invalidate_entry(&self, key: &impl KeyPath) -> Result<(), Error> {
+        invalidate(&self.metadata_path(key))
+    }
+
+    /// Marks all entries in the given directory, which is relative to the cache directory, as
+    /// expired
+    pub(crate) fn invalidate_directory(&self, directory: impl AsRef<Path>) -> Result<(), Error> {
+        let entries = match fs::read_dir(self.root.join(directory)) {
+            Ok(entries) => entries,
+            Err(ref err) if err.kind() == ErrorKind::NotFound => return Ok(()),
+            Err(err) => return Err(err.into()),
+        };
+
+        for entry in entries {
+            let path = entry?.path();
+
+            if path.extension().is_some_and(|extension| extension == "json") {
+                invalidate(&path)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// The contents of the metadata file of an entry
+#[derive(Debug, Serialize, Deserialize)]
+struct Metadata {
+    /// Human readable description of the request the entry belongs to
+    request: String,
+
+    cached_at: NaiveDateTime,
+    absent: bool,
+    invalidated: bool,
+}
+
+fn read_metadata(path: &Path) -> Result<Option<Metadata>, Error> {
+    match fs::read_to_string(path) {
+        Ok(metadata) => Ok(Some(serde_json::from_str(&metadata)?)),
+        Err(ref err) if err.kind() == ErrorKind::NotFound => Ok(None),
+        Err(err) => Err(err.into()),
+    }
+}
+
+fn write_metadata(path: &Path, metadata: &Metadata) -> Result<(), Error> {
+    write_atomically(path, &serde_json::to_string_pretty(metadata)?)
+}
+
+fn invalidate(metadata_path: &Path) -> Result<(), Error> {
+    if let Some(mut metadata) = read_metadata(metadata_path)? {
+        metadata.invalidated = true;
+
+        write_metadata(metadata_path, &metadata)?;
+    }
+
+    Ok(())
+}
+
+/// Writes the given contents to the file at the given path, creating all parent directories. The
+/// contents are written to a temporary file first, so that readers never see partially written
+/// files
+fn write_atomically(path: &Path, contents: &str) -> Result<(), Error> {
+    static TEMPORARY_FILES: AtomicUsize = AtomicUsize::new(0);
+
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+
+    let temporary = path.with_extension(format!(
+        "{}.{}.tmp",
+        std::process::id(),
+        TEMPORARY_FILES.fetch_add(1, Ordering::Relaxed)
+    ));
+
+    fs::write(&temporary, contents)?;
+    fs::rename(&temporary, path)?;
+
+    Ok(())
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Entry {
+    cached_at: NaiveDateTime,
+    expired: bool,
+    absent: bool,
+}
+
+impl Entry {
+    pub fn cached_at(&self) -> NaiveDateTime {
+        self.cached_at
+    }
+}
+
+impl Display for Entry {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "File entry, cached at {} (expired: {}), absent: {}",
+            self.cached_at, self.expired, self.absent
+        )
+    }
+}
+
+impl CacheEntryMeta for Entry {
+    fn is_expired(&self) -> bool {
+        self.expired
+    }
+
+    fn is_absent(&self) -> bool {
+        self.absent
+    }
+}
+
+#[derive(Debug, Fail)]
+pub enum Error {
+    #[fail(display = "I/O error: {}", _0)]
+    Io(#[cause] std::io::Error),
+
+    #[fail(display = "Malformed metadata file: {}", _0)]
+    Metadata(#[cause] serde_json::Error),
+
+    #[fail(display = "The data in {} could not be parsed: {}", _0, _1)]
+    Parse(String, String),
+
+    #[fail(
+        display = "The result of {} can only be cached along with the response body it was parsed from",
+        _0
+    )]
+    MissingBody(String),
+}
+
+impl From<std::io::Error> for Error {
+    fn from(err: std::io::Error) -> Self {
+        Error::Io(err)
+    }
+}
+
+impl From<serde_json::Error> for Error {
+    fn from(err: serde_json::Error) -> Self {
+        Error::Metadata(err)
+    }
+}
+
+impl CacheError for Error {}
+
+impl gdcf::cache::Cache for Cache {
+    type CacheEntryMeta = Entry;
+    type Err = Error;
+}
+
+// Requests are cached as the response bodies the servers sent, which the parsed objects cannot be
+// turned back into
+
+macro_rules! cache_responses {
+    ($($key_type: ty),*) => {
+        $(
+            impl Lookup<$key_type> for Cache {
+                fn lookup(&self, key: &$key_type) -> Result<CacheEntry<<$key_type as Key>::Result, Entry>, Self::Err> {
+                    self.lookup_entry(key, self.expiry_of::<$key_type>())
+                }
+
+                fn lookup_raw(&self, key: &$key_type) -> Result<Option<String>, Self::Err> {
+                    self.lookup_body(key)
+                }
+            }
+
+            impl Store<$key_type> for Cache {
+                fn store(&mut self, _: &<$key_type as Key>::Result, key: &$key_type) -> Result<Entry, Self::Err> {
+                    Err(Error::MissingBody(key.to_string()))
+                }
+
+                fn mark_absent(&mut self, key: &$key_type) -> Result<Entry, Self::Err> {
+                    warn!("Marking {} with key {} as absent!", stringify!($key_type), key);
+
+                    self.store_entry(key, None)
+                }
+
+                fn store_raw(&mut self, _: &<$key_type as Key>::Result, body: &str, key: &$key_type) -> Result<Entry, Self::Err> {
+                    debug!("Storing result of {} with key {}", stringify!($key_type), key);
+
+                    self.store_entry(key, Some(body))
+                }
+            }
+        )*
+    };
+}
+
+cache_responses!(
+    LevelsRequest,
+    LevelScoresRequest,
+    LevelCommentsRequest,
+    ProfileCommentsRequest,
+    MapPacksRequest,
+    GauntletsRequest,
+    MessagesRequest,
+    MessageRequest,
+    LeaderboardRequest,
+    UserRequest,
+    UserSearchRequest,
+    FriendListRequest,
+    BlockedUsersRequest,
+    FriendRequestsRequest,
+    SongInfoRequest
+);
+
+macro_rules! cache_fragments {
+    ($($key_type: ty),*) => {
+        $(
+            impl Lookup<$key_type> for Cache {
+                fn lookup(&self, key: &$key_type) -> Result<CacheEntry<<$key_type as Key>::Result, Entry>, Self::Err> {
+                    self.lookup_entry(key, self.expiry_of::<$key_type>())
+                }
+            }
+
+            impl Store<$key_type> for Cache {
+                fn store(&mut self, object: &<$key_type as Key>::Result, key: &$key_type) -> Result<Entry, Self::Err> {
+                    debug!("Storing {} with key {}", stringify!($key_type), key);
+
+                    self.store_entry(key, Some(&<$key_type>::unparse(object)))
+                }
+
+                fn mark_absent(&mut self, key: &$key_type) -> Result<Entry, Self::Err> {
+                    warn!("Marking {} with key {} as absent!", stringify!($key_type), key);
+
+                    self.store_entry(key, None)
+                }
+            }
+        )*
+    };
+}
+
+cache_fragments!(NewgroundsSongKey, CreatorKey, CommentUserKey);
+
+impl Lookup<DailyLevelRequest> for Cache {
+    fn lookup(&self, key: &DailyLevelRequest) -> Result<CacheEntry<DailyLevel, Entry>, Self::Err> {
+        let (mut daily, entry) = match self.lookup_entry(key, self.expiry_of::<DailyLevelRequest>())? {
+            CacheEntry::Cached(daily, entry) => (daily, entry),
+            other => return Ok(other),
+        };
+
+        let rotated = refresh_seconds_left(&mut daily, entry.cached_at);
+
+        Ok(CacheEntry::Cached(daily, Entry { expired: rotated, ..entry }))
+    }
+
+    fn lookup_raw(&self, key: &DailyLevelRequest) -> Result<Option<String>, Self::Err> {
+        self.lookup_body(key)
+    }
+}
+
+impl Store<DailyLevelRequest> for Cache {
+    fn store(&mut self, _: &DailyLevel, key: &DailyLevelRequest) -> Result<Entry, Self::Err> {
+        Err(Error::MissingBody(key.to_string()))
+    }
+
+    fn mark_absent(&mut self, key: &DailyLevelRequest) -> Result<Entry, Self::Err> {
+        warn!("Marking result of DailyLevelRequest with key {} as absent!", key);
+
+        self.store_entry(key, None)
+    }
+
+    fn store_raw(&mut self, daily: &DailyLevel, body: &str, key: &DailyLevelRequest) -> Result<Entry, Self::Err> {
+        debug!("Storing {} under key {}", daily, key);
+
+        self.store_entry(key, Some(body))
+    }
+}
+
+impl Lookup<LevelRequest> for Cache {
+    fn lookup(&self, key: &LevelRequest) -> Result<CacheEntry<Level<Option<u64>, u64>, Entry>, Self::Err> {
+        let (level, entry) = match self.lookup_entry(key, self.expiry_of::<LevelRequest>())? {
+            CacheEntry::Cached(level, entry) => (level, entry),
+            other => return Ok(other),
+        };
+
+        let expires_at = match key.daily_level_request() {
+            Some(daily_request) =>
+                match self.lookup_entry(&daily_request, self.expiry_of::<DailyLevelRequest>())? {
+                    CacheEntry::Cached(daily, daily_entry) => special_level_expiry(&daily, daily_entry.cached_at, entry.cached_at),
+                    _ => None,
+                },
+            None => None,
+        };
+
+        match expires_at {
+            Some(expires_at) =>
+                Ok(CacheEntry::Cached(
+                    level,
+                    Entry {
+                        expired: Utc::now().naive_utc() >= expires_at,
+                        ..entry
+                    },
+                )),
+            None => Ok(CacheEntry::Cached(level, entry)),
+        }
+    }
+
+    fn lookup_raw(&self, key: &LevelRequest) -> Result<Option<String>, Self::Err> {
+        self.lookup_body(key)
+    }
+}
+
+impl Store<LevelRequest> for Cache {
+    fn store(&mut self, _: &Level<Option<u64>, u64>, key: &LevelRequest) -> Result<Entry, Self::Err> {
+        Err(Error::MissingBody(key.to_string()))
+    }
+
+    fn mark_absent(&mut self, key: &LevelRequest) -> Result<Entry, Self::Err> {
+        warn!("Marking level with key {} as absent!", key);
+
+        self.store_entry(key, None)
+    }
+
+    fn store_raw(&mut self, level: &Level<Option<u64>, u64>, body: &str, key: &LevelRequest) -> Result<Entry, Self::Err> {
+        debug!("Storing {} under key {}", level, key);
+
+        self.store_entry(key, Some(body))
+    }
+}
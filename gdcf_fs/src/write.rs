@@ -0,0 +1,20 @@
+use crate::Cache;
+use gdcf::{
+    api::request::LevelRequest,
+    cache::{path, InvalidateObjects},
+};
+use std::path::PathBuf;
+
+impl InvalidateObjects for Cache {
+    fn invalidate_level(&mut self, level_id: u64) -> Result<(), Self::Err> {
+        self.invalidate_entry(&LevelRequest::new(level_id))
+    }
+
+    fn invalidate_level_comments(&mut self, level_id: u64) -> Result<(), Self::Err> {
+        self.invalidate_directory(path::level_comments(level_id).iter().collect::<PathBuf>())
+    }
+
+    fn invalidate_profile_comments(&mut self, account_id: u64) -> Result<(), Self::Err> {
+        self.invalidate_directory(path::profile_comments(account_id).iter().collect::<PathBuf>())
+    }
+}
@@ -0,0 +1,60 @@
+//! Tests using the file system cache as the first tier of a `TieredCache`, which can only store
+//! the raw response bodies of requests
+
+use gdcf::{
+    api::request::SongInfoRequest,
+    cache::{CacheEntry, Lookup, Store, TieredCache},
+};
+use gdcf_fs::Cache;
+use gdcf_model::song::NewgroundsSong;
+use gdcf_parse::Parse;
+use std::path::PathBuf;
+
+const SONG_BODY: &str = "1~|~803223~|~2~|~Xenogenesis~|~3~|~50531~|~4~|~TheFatRat~|~5~|~5.99~|~6~|~~|~10~|~http%3A%2F%2Faudio.ngfiles.com%2F803000%2F803223_Xenogenesis.mp3~|~7~|~~|~8~|~1";
+
+/// Gets a fresh cache directory for the test with the given name
+fn directory(test: &str) -> PathBuf {
+    let directory = std::env::temp_dir().join(format!("gdcf_fs_{}_{}", test, std::process::id()));
+
+    let _ = std::fs::remove_dir_all(&directory);
+
+    directory
+}
+
+#[test]
+fn promotes_raw_response_bodies() {
+    let root = directory("promotes_raw_response_bodies");
+    let first = Cache::new(root.join("first")).unwrap();
+    let mut second = Cache::new(root.join("second")).unwrap();
+    let request = SongInfoRequest::new(803223);
+    let song = NewgroundsSong::parse_str2(SONG_BODY, "~|~").unwrap();
+
+    second.store_raw(&song, SONG_BODY, &request).unwrap();
+
+    let tiered = TieredCache::new(first.clone(), second);
+
+    assert!(matches!(tiered.lookup(&request).unwrap(), CacheEntry::Cached(..)));
+    assert!(matches!(first.lookup(&request).unwrap(), CacheEntry::Cached(ref cached, _) if *cached == song));
+    assert_eq!(first.lookup_raw(&request).unwrap().as_deref(), Some(SONG_BODY));
+
+    std::fs::remove_dir_all(root).unwrap();
+}
+
+#[test]
+fn lookup_succeeds_if_promotion_is_impossible() {
+    let root = directory("lookup_succeeds_if_promotion_is_impossible");
+    let first = Cache::new(&root).unwrap();
+    let mut second = gdcf_memory::Cache::default();
+    let request = SongInfoRequest::new(803223);
+    let song = NewgroundsSong::parse_str2(SONG_BODY, "~|~").unwrap();
+
+    // The memory cache doesn't keep the response body, so the entry cannot be promoted
+    second.store(&song, &request).unwrap();
+
+    let tiered = TieredCache::new(first.clone(), second);
+
+    assert!(matches!(tiered.lookup(&request).unwrap(), CacheEntry::Cached(ref cached, _) if *cached == song));
+    assert_eq!(first.lookup(&request).unwrap(), CacheEntry::Missing);
+
+    std::fs::remove_dir_all(root).unwrap();
+}
@@ -0,0 +1,388 @@
+//! In-memory [`Cache`](gdcf::cache::Cache) implementation for use with `gdcf`
+//!
+//! Cached objects are kept in a map of fixed capacity. Once it runs full, the least recently used
+//! entries are evicted. Nothing is persisted, so this cache is best suited for tests, short-lived
+//! tools, or as a fast layer in front of a persistent cache.
+
+#![deny(unused_must_use)]
+#![deny(unused_imports)]
+
+mod lru;
+mod write;
+
+use crate::lru::{Lru, Slot, SlotKey};
+use chrono::{Duration, NaiveDateTime, Utc};
+use failure::Fail;
+use gdcf::{
+    api::request::{
+        BlockedUsersRequest, DailyLevelRequest, FriendListRequest, FriendRequestsRequest, GauntletsRequest, LeaderboardRequest,
+        LevelCommentsRequest, LevelRequest, LevelScoresRequest, LevelsRequest, MapPacksRequest, MessageRequest, MessagesRequest,
+        ProfileCommentsRequest, SongInfoRequest, UserRequest, UserSearchRequest,
+    },
+    cache::{
+        refresh_seconds_left, special_level_expiry, CacheEntry, CacheEntryMeta, CommentUserKey, CreatorKey, Key, Lookup, NewgroundsSongKey,
+        Store,
+    },
+    error::CacheError,
+};
+use gdcf_model::{
+    level::{DailyLevel, Level},
+    song::NewgroundsSong,
+};
+use log::{debug, warn};
+use std::{
+    any::TypeId,
+    collections::{hash_map::DefaultHasher, HashMap},
+    fmt::{Display, Formatter},
+    hash::{Hash, Hasher},
+    sync::{Arc, Mutex, MutexGuard},
+};
+
+/// The default amount of entries a [`Cache`] holds
+pub const DEFAULT_CAPACITY: usize = 4096;
+
+#[derive(Clone)]
+pub struct Cache {
+    lru: Arc<Mutex<Lru>>,
+
+    /// The expiry used for all kinds of objects that don't have one set in `expiry_overrides`
+    expiry: Duration,
+
+    /// Expiry of entries that have been marked as absent, regardless of their kind
+    absent_expiry: Duration,
+
+    /// Expiry of specific kinds of objects, indexed by the [`TypeId`] of their key type
+    expiry_overrides: HashMap<TypeId, Duration>,
+}
+
+impl Default for Cache {
+    fn default() -> Self {
+        Cache::new(DEFAULT_CAPACITY)
+    }
+}
+
+impl Cache {
+    /// Constructs a new, empty [`Cache`] holding at most `capacity` entries
+    pub fn new(capacity: usize) -> Cache {
+        Cache {
+            lru: Arc::new(Mutex::new(Lru::new(capacity))),
+            expiry: Duration::minutes(60),
+            absent_expiry: Duration::minutes(60),
+            expiry_overrides: HashMap::new(),
+        }
+    }
+
+    /// Sets the [`Duration`] after which cached objects are considered outdated. This applies to
+    /// every kind of object whose expiry hasn't been set via [`Cache::with_expiry_for`]
+    ///
+    /// Allows builder-style configuration of the cache
+    pub fn with_expiry(mut self, expiry: Duration) -> Self {
+        self.expiry = expiry;
+        self
+    }
+
+    /// Sets the [`Duration`] after which the results of lookups using keys of type `K` are
+    /// considered outdated
+    pub fn with_expiry_for<K: Key + 'static>(mut self, expiry: Duration) -> Self {
+        self.expiry_overrides.insert(TypeId::of::<K>(), expiry);
+        self
+    }
+
+    /// Sets the [`Duration`] after which entries marked as absent are considered outdated
+    pub fn with_absent_expiry(mut self, expiry: Duration) -> Self {
+        self.absent_expiry = expiry;
+        self
+    }
+
+    /// Gets the maximal amount of entries this cache holds
+    pub fn capacity(&self) -> Result<usize, Error> {
+        Ok(self.lru()?.capacity())
+    }
+
+    /// Gets the amount of entries currently in this cache
+    pub fn len(&self) -> Result<usize, Error> {
+        Ok(self.lru()?.len())
+    }
+
+    /// Checks whether this cache contains no entries
+    pub fn is_empty(&self) -> Result<bool, Error> {
+        Ok(self.lru()?.len() == 0)
+    }
+
+    /// Removes all entries from this cache
+    pub fn clear(&self) -> Result<(), Error> {
+        self.lru()?.clear();
+
+        Ok(())
+    }
+
+    fn lru(&self) -> Result<MutexGuard<'_, Lru>, Error> {
+        self.lru.lock().map_err(|_| Error::Poisoned)
+    }
+
+    fn expiry_of<K: 'static>(&self) -> Duration {
+        self.expiry_overrides.get(&TypeId::of::<K>()).cloned().unwrap_or(self.expiry)
+    }
+
+    fn entry(&self, slot: &Slot, expire_after: Duration) -> Entry {
+        let absent = slot.object.is_none();
+        let expire_after = if absent { self.absent_expiry } else { expire_after };
+
+        Entry {
+            cached_at: slot.cached_at,
+            expired: slot.invalidated || Utc::now().naive_utc() - slot.cached_at > expire_after,
+            absent,
+        }
+    }
+
+    fn lookup_entry<K>(&self, key: &K, expire_after: Duration) -> Result<CacheEntry<K::Result, Entry>, Error>
+    where
+        K: Key + Hash + Eq + 'static,
+        K::Result: Clone + 'static,
+    {
+        let mut lru = self.lru()?;
+
+        // Slots are only addressed by the hash of their key, so we need to make sure we didn't hit
+        // a different key with the same hash
+        let slot = match lru.get(&slot_key(key)) {
+            Some(slot) if slot.key.downcast_ref::<K>() == Some(key) => slot,
+            _ => return Ok(CacheEntry::Missing),
+        };
+
+        let entry = self.entry(slot, expire_after);
+
+        Ok(match slot.object {
+            None => CacheEntry::MarkedAbsent(entry),
+            Some(ref object) =>
+                match object.downcast_ref::<K::Result>() {
+                    Some(object) => CacheEntry::Cached(object.clone(), entry),
+                    None => CacheEntry::Missing,
+                },
+        })
+    }
+
+    fn store_entry<K>(&self, key: &K, object: Option<&K::Result>) -> Result<Entry, Error>
+    where
+        K: Key + Hash + Clone + Send + Sync + 'static,
+        K::Result: Clone + Send + Sync + 'static,
+    {
+        let cached_at = Utc::now().naive_utc();
+        let object = object.map(|object| Box::new(object.clone()) as _);
+
+        let entry = Entry {
+            cached_at,
+            expired: false,
+            absent: object.is_none(),
+        };
+
+        self.lru()?
+            .insert(slot_key(key), Slot::new(Box::new(key.clone()), object, cached_at));
+
+        Ok(entry)
+    }
+
+    /// Marks all entries whose key is of type `K` and matches the given predicate as expired. The
+    /// predicate is also given the cached object, or [`None`] if the entry is marked as absent
+    pub(crate) fn invalidate_where<K>(&self, predicate: impl Fn(&K, Option<&K::Result>) -> bool) -> Result<(), Error>
+    where
+        K: Key + 'static,
+        K::Result: 'static,
+    {
+        for slot in self.lru()?.slots_mut() {
+            if let Some(key) = slot.key.downcast_ref::<K>() {
+                let object = slot.object.as_ref().and_then(|object| object.downcast_ref::<K::Result>());
+
+                slot.invalidated |= predicate(key, object);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn slot_key<K: Hash + 'static>(key: &K) -> SlotKey {
+    let mut state = DefaultHasher::new();
+
+    key.hash(&mut state);
+
+    (TypeId::of::<K>(), state.finish())
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Entry {
+    cached_at: NaiveDateTime,
+    expired: bool,
+    absent: bool,
+}
+
+impl Entry {
+    pub fn cached_at(&self) -> NaiveDateTime {
+        self.cached_at
+    }
+}
+
+impl Display for Entry {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "In-memory entry, cached at {} (expired: {}), absent: {}",
+            self.cached_at, self.expired, self.absent
+        )
+    }
+}
+
+impl CacheEntryMeta for Entry {
+    fn is_expired(&self) -> bool {
+        self.expired
+    }
+
+    fn is_absent(&self) -> bool {
+        self.absent
+    }
+}
+
+#[derive(Debug, Fail)]
+pub enum Error {
+    #[fail(display = "The cache was poisoned by a thread panicking while accessing it")]
+    Poisoned,
+}
+
+impl CacheError for Error {}
+
+impl gdcf::cache::Cache for Cache {
+    type CacheEntryMeta = Entry;
+    type Err = Error;
+}
+
+macro_rules! cache_simply {
+    ($($key_type: ty),*) => {
+        $(
+            impl Lookup<$key_type> for Cache {
+                fn lookup(&self, key: &$key_type) -> Result<CacheEntry<<$key_type as Key>::Result, Entry>, Self::Err> {
+                    self.lookup_entry(key, self.expiry_of::<$key_type>())
+                }
+            }
+
+            impl Store<$key_type> for Cache {
+                fn store(&mut self, object: &<$key_type as Key>::Result, key: &$key_type) -> Result<Entry, Self::Err> {
+                    debug!("Storing result of {} with key {}", stringify!($key_type), key);
+
+                    self.store_entry(key, Some(object))
+                }
+
+                fn mark_absent(&mut self, key: &$key_type) -> Result<Entry, Self::Err> {
+                    warn!("Marking {} with key {} as absent!", stringify!($key_type), key);
+
+                    self.store_entry(key, None)
+                }
+            }
+        )*
+    };
+}
+
+cache_simply!(
+    LevelsRequest,
+    LevelScoresRequest,
+    LevelCommentsRequest,
+    ProfileCommentsRequest,
+    MapPacksRequest,
+    GauntletsRequest,
+    MessagesRequest,
+    MessageRequest,
+    LeaderboardRequest,
+    UserRequest,
+    UserSearchRequest,
+    FriendListRequest,
+    BlockedUsersRequest,
+    FriendRequestsRequest,
+    NewgroundsSongKey,
+    CreatorKey,
+    CommentUserKey
+);
+
+impl Lookup<SongInfoRequest> for Cache {
+    fn lookup(&self, key: &SongInfoRequest) -> Result<CacheEntry<NewgroundsSong, Entry>, Self::Err> {
+        self.lookup(&NewgroundsSongKey(key.song_id))
+    }
+}
+
+impl Store<SongInfoRequest> for Cache {
+    fn store(&mut self, song: &NewgroundsSong, key: &SongInfoRequest) -> Result<Entry, Self::Err> {
+        self.store(song, &NewgroundsSongKey(key.song_id))
+    }
+
+    fn mark_absent(&mut self, key: &SongInfoRequest) -> Result<Entry, Self::Err> {
+        Store::<NewgroundsSongKey>::mark_absent(self, &NewgroundsSongKey(key.song_id))
+    }
+}
+
+impl Lookup<DailyLevelRequest> for Cache {
+    fn lookup(&self, key: &DailyLevelRequest) -> Result<CacheEntry<DailyLevel, Entry>, Self::Err> {
+        let (mut daily, entry) = match self.lookup_entry(key, self.expiry_of::<DailyLevelRequest>())? {
+            CacheEntry::Cached(daily, entry) => (daily, entry),
+            other => return Ok(other),
+        };
+
+        let rotated = refresh_seconds_left(&mut daily, entry.cached_at);
+
+        Ok(CacheEntry::Cached(daily, Entry { expired: rotated, ..entry }))
+    }
+}
+
+impl Store<DailyLevelRequest> for Cache {
+    fn store(&mut self, daily: &DailyLevel, key: &DailyLevelRequest) -> Result<Entry, Self::Err> {
+        debug!("Storing {} under key {}", daily, key);
+
+        self.store_entry(key, Some(daily))
+    }
+
+    fn mark_absent(&mut self, key: &DailyLevelRequest) -> Result<Entry, Self::Err> {
+        warn!("Marking result of DailyLevelRequest with key {} as absent!", key);
+
+        self.store_entry(key, None)
+    }
+}
+
+impl Lookup<LevelRequest> for Cache {
+    fn lookup(&self, key: &LevelRequest) -> Result<CacheEntry<Level<Option<u64>, u64>, Entry>, Self::Err> {
+        let (level, entry) = match self.lookup_entry(key, self.expiry_of::<LevelRequest>())? {
+            CacheEntry::Cached(level, entry) => (level, entry),
+            other => return Ok(other),
+        };
+
+        let expires_at = match key.daily_level_request() {
+            Some(daily_request) =>
+                match self.lookup_entry(&daily_request, self.expiry_of::<DailyLevelRequest>())? {
+                    CacheEntry::Cached(daily, daily_entry) => special_level_expiry(&daily, daily_entry.cached_at, entry.cached_at),
+                    _ => None,
+                },
+            None => None,
+        };
+
+        match expires_at {
+            Some(expires_at) =>
+                Ok(CacheEntry::Cached(
+                    level,
+                    Entry {
+                        expired: Utc::now().naive_utc() >= expires_at,
+                        ..entry
+                    },
+                )),
+            None => Ok(CacheEntry::Cached(level, entry)),
+        }
+    }
+}
+
+impl Store<LevelRequest> for Cache {
+    fn store(&mut self, level: &Level<Option<u64>, u64>, key: &LevelRequest) -> Result<Entry, Self::Err> {
+        debug!("Storing {} under key {}", level, key);
+
+        self.store_entry(key, Some(level))
+    }
+
+    fn mark_absent(&mut self, key: &LevelRequest) -> Result<Entry, Self::Err> {
+        warn!("Marking level with key {} as absent!", key);
+
+        self.store_entry(key, None)
+    }
+}
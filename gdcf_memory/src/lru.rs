@@ -0,0 +1,265 @@
+use chrono::NaiveDateTime;
+use std::{
+    any::{Any, TypeId},
+    collections::{BTreeMap, HashMap},
+};
+
+/// Identifies a slot in the cache by the type of the key and the key's hash
+pub(crate) type SlotKey = (TypeId, u64);
+
+pub(crate) struct Slot {
+    /// The key this slot was stored under, kept around so that entries can be invalidated based on
+    /// the request they belong to, and so that keys with colliding hashes can be told apart
+    pub(crate) key: Box<dyn Any + Send + Sync>,
+
+    /// The cached object, or [`None`] if the key was marked as absent
+    pub(crate) object: Option<Box<dyn Any + Send + Sync>>,
+
+    pub(crate) cached_at: NaiveDateTime,
+    pub(crate) invalidated: bool,
+
+    last_used: u64,
+}
+
+impl Slot {
+    pub(crate) fn new(key: Box<dyn Any + Send + Sync>, object: Option<Box<dyn Any + Send + Sync>>, cached_at: NaiveDateTime) -> Slot {
+        Slot {
+            key,
+            object,
+            cached_at,
+            invalidated: false,
+            last_used: 0,
+        }
+    }
+}
+
+/// A map holding at most `capacity` slots, evicting the least recently used one if it runs full
+pub(crate) struct Lru {
+    capacity: usize,
+    slots: HashMap<SlotKey, Slot>,
+
+    /// Maps the time of their last use to the slots. Since every use gets a new, unique time, the
+    /// first element is always the least recently used slot
+    recency: BTreeMap<u64, SlotKey>,
+    clock: u64,
+}
+
+impl Lru {
+    pub(crate) fn new(capacity: usize) -> Lru {
+        Lru {
+            capacity,
+            slots: HashMap::new(),
+            recency: BTreeMap::new(),
+            clock: 0,
+        }
+    }
+
+    pub(crate) fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.slots.len()
+    }
+
+    pub(crate) fn clear(&mut self) {
+        self.slots.clear();
+        self.recency.clear();
+    }
+
+    /// Gets the slot with the given key, marking it as the most recently used one
+    pub(crate) fn get(&mut self, key: &SlotKey) -> Option<&Slot> {
+        let now = self.tick();
+        let slot = self.slots.get_mut(key)?;
+
+        self.recency.remove(&slot.last_used);
+        self.recency.insert(now, *key);
+
+        slot.last_used = now;
+
+        Some(slot)
+    }
+
+    /// Inserts the given slot, replacing the one previously stored under the given key, and evicts
+    /// the least recently used slots if the capacity is exceeded
+    pub(crate) fn insert(&mut self, key: SlotKey, mut slot: Slot) {
+        if self.capacity == 0 {
+            return
+        }
+
+        slot.last_used = self.tick();
+
+        self.recency.insert(slot.last_used, key);
+
+        if let Some(old) = self.slots.insert(key, slot) {
+            self.recency.remove(&old.last_used);
+        }
+
+        while self.slots.len() > self.capacity {
+            let (&oldest, &evicted) = match self.recency.iter().next() {
+                Some(oldest) => oldest,
+                None => break,
+            };
+
+            self.recency.remove(&oldest);
+            self.slots.remove(&evicted);
+        }
+    }
+
+    /// Iterates over all slots, without affecting their recency
+    pub(crate) fn slots_mut(&mut self) -> impl Iterator<Item = &mut Slot> {
+        self.slots.values_mut()
+    }
+
+    fn tick(&mut self) -> u64 {
+        self.clock += 1;
+        self.clock
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Lru, Slot, SlotKey};
+    use crate::Cache;
+    use chrono::{Duration, Utc};
+    use gdcf::cache::{CacheEntry, CacheEntryMeta, CreatorKey, InvalidateObjects, Lookup, Store};
+    use gdcf_model::user::Creator;
+    use std::any::TypeId;
+
+    fn key(id: u64) -> SlotKey {
+        (TypeId::of::<u64>(), id)
+    }
+
+    fn slot(id: u64) -> Slot {
+        Slot::new(Box::new(id), Some(Box::new(id)), Utc::now().naive_utc())
+    }
+
+    fn lru_with(capacity: usize, ids: &[u64]) -> Lru {
+        let mut lru = Lru::new(capacity);
+
+        for &id in ids {
+            lru.insert(key(id), slot(id));
+        }
+
+        lru
+    }
+
+    fn contained(lru: &Lru, ids: &[u64]) -> Vec<u64> {
+        ids.iter().cloned().filter(|&id| lru.slots.contains_key(&key(id))).collect()
+    }
+
+    #[test]
+    fn evicts_least_recently_inserted() {
+        let mut lru = lru_with(2, &[1, 2, 3]);
+
+        assert_eq!(lru.len(), 2);
+        assert_eq!(contained(&lru, &[1, 2, 3]), vec![2, 3]);
+    }
+
+    #[test]
+    fn get_marks_slot_as_recently_used() {
+        let mut lru = lru_with(2, &[1, 2]);
+
+        assert!(lru.get(&key(1)).is_some());
+
+        lru.insert(key(3), slot(3));
+
+        assert_eq!(contained(&lru, &[1, 2, 3]), vec![1, 3]);
+    }
+
+    #[test]
+    fn replacing_slot_does_not_evict() {
+        let mut lru = lru_with(2, &[1, 2]);
+
+        lru.insert(key(1), slot(1));
+
+        assert_eq!(lru.len(), 2);
+        assert_eq!(lru.recency.len(), 2);
+
+        // Replacing counts as a use
+        lru.insert(key(3), slot(3));
+
+        assert_eq!(contained(&lru, &[1, 2, 3]), vec![1, 3]);
+    }
+
+    #[test]
+    fn zero_capacity_stores_nothing() {
+        let mut lru = lru_with(0, &[1]);
+
+        assert_eq!(lru.len(), 0);
+        assert!(lru.get(&key(1)).is_none());
+    }
+
+    #[test]
+    fn clear_removes_everything() {
+        let mut lru = lru_with(3, &[1, 2, 3]);
+
+        lru.clear();
+
+        assert_eq!(lru.len(), 0);
+        assert!(lru.recency.is_empty());
+
+        // The capacity is still available afterwards
+        for id in 4..7 {
+            lru.insert(key(id), slot(id));
+        }
+
+        assert_eq!(contained(&lru, &[4, 5, 6]), vec![4, 5, 6]);
+    }
+
+    fn creator(user_id: u64) -> Creator {
+        Creator {
+            user_id,
+            name: "Creator".to_string(),
+            account_id: None,
+        }
+    }
+
+    fn is_expired(cache: &Cache, user_id: u64) -> bool {
+        match cache.lookup(&CreatorKey(user_id)).unwrap() {
+            CacheEntry::Cached(_, entry) | CacheEntry::MarkedAbsent(entry) => entry.is_expired(),
+            CacheEntry::Missing => panic!("entry {} missing", user_id),
+        }
+    }
+
+    #[test]
+    fn entries_expire() {
+        let mut fresh = Cache::new(4);
+        let mut stale = Cache::new(4).with_expiry(Duration::seconds(-1));
+
+        fresh.store(&creator(1), &CreatorKey(1)).unwrap();
+        stale.store(&creator(1), &CreatorKey(1)).unwrap();
+
+        assert!(!is_expired(&fresh, 1));
+        assert!(is_expired(&stale, 1));
+    }
+
+    #[test]
+    fn expiry_overrides() {
+        let mut cache = Cache::new(4)
+            .with_expiry(Duration::seconds(-1))
+            .with_expiry_for::<CreatorKey>(Duration::minutes(5))
+            .with_absent_expiry(Duration::seconds(-1));
+
+        cache.store(&creator(1), &CreatorKey(1)).unwrap();
+        Store::<CreatorKey>::mark_absent(&mut cache, &CreatorKey(2)).unwrap();
+
+        assert!(!is_expired(&cache, 1));
+        assert!(is_expired(&cache, 2));
+    }
+
+    #[test]
+    fn invalidated_entries_are_expired() {
+        let mut cache = Cache::new(4);
+
+        cache.store(&creator(1), &CreatorKey(1)).unwrap();
+        cache.invalidate_level(1).unwrap();
+
+        // Invalidating a level doesn't affect creators
+        assert!(!is_expired(&cache, 1));
+
+        cache.invalidate_where(|key: &CreatorKey, _| key.0 == 1).unwrap();
+
+        assert!(is_expired(&cache, 1));
+    }
+}
@@ -0,0 +1,26 @@
+use crate::Cache;
+use gdcf::{
+    api::request::{LevelCommentsRequest, LevelRequest, ProfileCommentsRequest},
+    cache::InvalidateObjects,
+};
+
+impl InvalidateObjects for Cache {
+    fn invalidate_level(&mut self, level_id: u64) -> Result<(), Self::Err> {
+        self.invalidate_where(|request: &LevelRequest, level| {
+            match request.special {
+                None => request.level_id == level_id,
+                // The daily and weekly level are requested without knowing their ID, so we need to
+                // check which level was downloaded
+                Some(_) => level.is_some_and(|level| level.base.level_id == level_id),
+            }
+        })
+    }
+
+    fn invalidate_level_comments(&mut self, level_id: u64) -> Result<(), Self::Err> {
+        self.invalidate_where(|request: &LevelCommentsRequest, _| request.level_id == level_id)
+    }
+
+    fn invalidate_profile_comments(&mut self, account_id: u64) -> Result<(), Self::Err> {
+        self.invalidate_where(|request: &ProfileCommentsRequest, _| request.account_id == account_id)
+    }
+}
@@ -0,0 +1,68 @@
+use gdcf::{
+    api::request::{Authentication, BlockedUsersRequest, FriendListRequest, FriendRequestsRequest, MessageRequest, MessagesRequest},
+    cache::{CacheEntry, KeyPath, Lookup, Store},
+};
+use gdcf_memory::Cache;
+use std::{
+    collections::hash_map::DefaultHasher,
+    hash::{Hash, Hasher},
+};
+
+fn owner() -> Authentication {
+    Authentication::new(8451, "correct".to_string())
+}
+
+fn impostor() -> Authentication {
+    Authentication::new(8451, "wrong".to_string())
+}
+
+fn hash_of(key: &impl Hash) -> u64 {
+    let mut state = DefaultHasher::new();
+
+    key.hash(&mut state);
+    state.finish()
+}
+
+/// Asserts that the keys differ in every way a cache might use to identify its entries
+fn assert_distinct<K: Hash + Eq + KeyPath>(owner: K, impostor: K) {
+    assert!(owner != impostor);
+    assert_ne!(hash_of(&owner), hash_of(&impostor));
+    assert_ne!(owner.key_path(), impostor.key_path());
+}
+
+#[test]
+fn keys_depend_on_password() {
+    assert_distinct(MessagesRequest::inbox(owner()), MessagesRequest::inbox(impostor()));
+    assert_distinct(MessageRequest::new(owner(), 1), MessageRequest::new(impostor(), 1));
+    assert_distinct(FriendListRequest::new(owner()), FriendListRequest::new(impostor()));
+    assert_distinct(BlockedUsersRequest::new(owner()), BlockedUsersRequest::new(impostor()));
+    assert_distinct(
+        FriendRequestsRequest::received(owner()),
+        FriendRequestsRequest::received(impostor()),
+    );
+}
+
+#[test]
+fn entries_are_not_shared_between_passwords() {
+    let mut cache = Cache::default();
+
+    cache.store(&Vec::new(), &FriendListRequest::new(owner())).unwrap();
+
+    assert_eq!(cache.lookup(&FriendListRequest::new(impostor())).unwrap(), CacheEntry::Missing);
+
+    // A failed request made with the wrong password must not affect the owner's entry either
+    Store::<FriendListRequest>::mark_absent(&mut cache, &FriendListRequest::new(impostor())).unwrap();
+
+    assert!(matches!(
+        cache.lookup(&FriendListRequest::new(owner())).unwrap(),
+        CacheEntry::Cached(..)
+    ));
+}
+
+#[test]
+fn debug_output_omits_password() {
+    let debug = format!("{:?}", MessagesRequest::inbox(owner()));
+
+    assert!(debug.contains("8451"));
+    assert!(!debug.contains("correct"));
+}
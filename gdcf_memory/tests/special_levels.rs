@@ -0,0 +1,68 @@
+use gdcf::{
+    api::request::LevelRequest,
+    cache::{CacheEntry, CacheEntryMeta, InvalidateObjects, Lookup, Store},
+};
+use gdcf_memory::Cache;
+use gdcf_model::{
+    level::{Featured, Level, LevelLength, LevelRating, PartialLevel, Password},
+    GameVersion,
+};
+
+fn level(level_id: u64) -> Level<Option<u64>, u64> {
+    Level {
+        base: PartialLevel {
+            level_id,
+            name: "Level".to_string(),
+            description: None,
+            version: 1,
+            creator: 1,
+            difficulty: LevelRating::NotAvailable,
+            downloads: 0,
+            main_song: None,
+            gd_version: GameVersion::Version { major: 2, minor: 1 },
+            likes: 0,
+            length: LevelLength::Tiny,
+            stars: 0,
+            featured: Featured::NotFeatured,
+            copy_of: None,
+            custom_song: None,
+            coin_amount: 0,
+            coins_verified: false,
+            stars_requested: None,
+            is_epic: false,
+            object_amount: None,
+            index_31: None,
+            index_40: None,
+            index_43: String::new(),
+            index_46: None,
+            index_47: None,
+        },
+        level_data: Vec::new(),
+        password: Password::NoCopy,
+        time_since_upload: "1 day".to_string(),
+        time_since_update: "1 day".to_string(),
+        index_36: String::new(),
+    }
+}
+
+fn is_expired(cache: &Cache, request: &LevelRequest) -> bool {
+    match cache.lookup(request).unwrap() {
+        CacheEntry::Cached(_, entry) => entry.is_expired(),
+        other => panic!("unexpected {:?}", other),
+    }
+}
+
+#[test]
+fn invalidating_level_invalidates_special_level() {
+    let mut cache = Cache::default();
+
+    cache.store(&level(10), &LevelRequest::daily()).unwrap();
+    cache.store(&level(20), &LevelRequest::weekly()).unwrap();
+    cache.store(&level(10), &LevelRequest::new(10)).unwrap();
+
+    cache.invalidate_level(10).unwrap();
+
+    assert!(is_expired(&cache, &LevelRequest::new(10)));
+    assert!(is_expired(&cache, &LevelRequest::daily()));
+    assert!(!is_expired(&cache, &LevelRequest::weekly()));
+}
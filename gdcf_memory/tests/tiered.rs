@@ -0,0 +1,218 @@
+//! Tests of `TieredCache`, using in-memory caches for both tiers
+
+use failure::Fail;
+use gdcf::{
+    cache::{CacheEntry, CacheEntryMeta, CreatorKey, Lookup, Store, TieredCache, TieredEntryMeta, WriteMode},
+    error::CacheError,
+};
+use gdcf_memory::{Cache, Entry};
+use gdcf_model::user::Creator;
+use std::sync::{
+    atomic::{AtomicU64, Ordering},
+    Arc, Mutex,
+};
+
+fn creator(user_id: u64, name: &str) -> Creator {
+    Creator {
+        user_id,
+        name: name.to_string(),
+        account_id: None,
+    }
+}
+
+fn cached_name<C: Lookup<CreatorKey>>(cache: &C, user_id: u64) -> Option<String> {
+    match cache.lookup(&CreatorKey(user_id)).ok()? {
+        CacheEntry::Cached(creator, _) => Some(creator.name),
+        _ => None,
+    }
+}
+
+#[test]
+fn write_through_writes_both_tiers() {
+    let (first, second) = (Cache::default(), Cache::default());
+    let mut tiered = TieredCache::new(first.clone(), second.clone());
+
+    tiered.store(&creator(1, "Alice"), &CreatorKey(1)).unwrap();
+
+    assert_eq!(tiered.pending_writes(), 0);
+    assert_eq!(cached_name(&first, 1).as_deref(), Some("Alice"));
+    assert_eq!(cached_name(&second, 1).as_deref(), Some("Alice"));
+}
+
+#[test]
+fn write_back_queues_writes_until_flushed() {
+    let (first, second) = (Cache::default(), Cache::default());
+    let mut tiered = TieredCache::new(first.clone(), second.clone()).with_write_mode(WriteMode::WriteBack { batch_size: 10 });
+
+    tiered.store(&creator(1, "Alice"), &CreatorKey(1)).unwrap();
+    Store::<CreatorKey>::mark_absent(&mut tiered, &CreatorKey(2)).unwrap();
+
+    assert_eq!(tiered.pending_writes(), 2);
+    assert_eq!(cached_name(&first, 1).as_deref(), Some("Alice"));
+    assert_eq!(second.lookup(&CreatorKey(1)).unwrap(), CacheEntry::Missing);
+
+    tiered.flush().unwrap();
+
+    assert_eq!(tiered.pending_writes(), 0);
+    assert_eq!(cached_name(&second, 1).as_deref(), Some("Alice"));
+    assert!(matches!(second.lookup(&CreatorKey(2)).unwrap(), CacheEntry::MarkedAbsent(_)));
+}
+
+#[test]
+fn write_back_flushes_full_batches() {
+    let second = Cache::default();
+    let mut tiered = TieredCache::new(Cache::default(), second.clone()).with_write_mode(WriteMode::WriteBack { batch_size: 3 });
+
+    tiered.store(&creator(1, "Alice"), &CreatorKey(1)).unwrap();
+    tiered.store(&creator(2, "Bob"), &CreatorKey(2)).unwrap();
+
+    assert_eq!(tiered.pending_writes(), 2);
+    assert!(second.is_empty().unwrap());
+
+    tiered.store(&creator(3, "Carol"), &CreatorKey(3)).unwrap();
+
+    assert_eq!(tiered.pending_writes(), 0);
+    assert_eq!(second.len().unwrap(), 3);
+}
+
+#[test]
+fn pending_writes_are_shared_between_clones() {
+    let mut tiered = TieredCache::new(Cache::default(), Cache::default()).with_write_mode(WriteMode::WriteBack { batch_size: 10 });
+    let mut clone = tiered.clone();
+
+    tiered.store(&creator(1, "Alice"), &CreatorKey(1)).unwrap();
+
+    assert_eq!(clone.pending_writes(), 1);
+
+    clone.flush().unwrap();
+
+    assert_eq!(tiered.pending_writes(), 0);
+    assert_eq!(cached_name(tiered.second(), 1).as_deref(), Some("Alice"));
+}
+
+#[test]
+fn lookup_promotes_entries_of_second_tier() {
+    let (first, mut second) = (Cache::default(), Cache::default());
+    let tiered = TieredCache::new(first.clone(), second.clone());
+
+    second.store(&creator(1, "Alice"), &CreatorKey(1)).unwrap();
+    Store::<CreatorKey>::mark_absent(&mut second, &CreatorKey(2)).unwrap();
+
+    assert!(matches!(
+        tiered.lookup(&CreatorKey(1)).unwrap(),
+        CacheEntry::Cached(_, TieredEntryMeta::Second(_))
+    ));
+    assert!(matches!(
+        tiered.lookup(&CreatorKey(2)).unwrap(),
+        CacheEntry::MarkedAbsent(TieredEntryMeta::Second(_))
+    ));
+
+    assert_eq!(cached_name(&first, 1).as_deref(), Some("Alice"));
+    assert!(matches!(first.lookup(&CreatorKey(2)).unwrap(), CacheEntry::MarkedAbsent(_)));
+    assert!(matches!(
+        tiered.lookup(&CreatorKey(1)).unwrap(),
+        CacheEntry::Cached(_, TieredEntryMeta::First(_))
+    ));
+}
+
+#[test]
+fn expired_entries_of_second_tier_are_not_promoted() {
+    let first = Cache::default();
+    let mut second = Cache::default().with_expiry(chrono::Duration::seconds(-1));
+    let tiered = TieredCache::new(first.clone(), second.clone());
+
+    second.store(&creator(1, "Alice"), &CreatorKey(1)).unwrap();
+
+    match tiered.lookup(&CreatorKey(1)).unwrap() {
+        CacheEntry::Cached(_, meta) => assert!(meta.is_expired()),
+        other => panic!("unexpected {:?}", other),
+    }
+
+    assert_eq!(first.lookup(&CreatorKey(1)).unwrap(), CacheEntry::Missing);
+}
+
+#[test]
+fn write_back_lookup_after_eviction_is_not_stale() {
+    let (first, mut second) = (Cache::new(1), Cache::default());
+    let mut tiered = TieredCache::new(first.clone(), second.clone()).with_write_mode(WriteMode::WriteBack { batch_size: 10 });
+
+    second.store(&creator(1, "Old name"), &CreatorKey(1)).unwrap();
+    tiered.store(&creator(1, "New name"), &CreatorKey(1)).unwrap();
+
+    // Evicts the new entry from the first tier, while its write to the second tier is still queued
+    tiered.store(&creator(2, "Bob"), &CreatorKey(2)).unwrap();
+
+    assert_eq!(first.lookup(&CreatorKey(1)).unwrap(), CacheEntry::Missing);
+    assert_eq!(cached_name(&tiered, 1).as_deref(), Some("New name"));
+    assert_eq!(cached_name(&second, 1).as_deref(), Some("New name"));
+    assert_eq!(tiered.pending_writes(), 0);
+}
+
+/// An in-memory cache recording the user IDs of all creators stored in it, which fails to store
+/// the creator with the user ID set in `fail_on`
+#[derive(Clone)]
+struct Flaky {
+    inner: Cache,
+    stored: Arc<Mutex<Vec<u64>>>,
+    fail_on: Arc<AtomicU64>,
+}
+
+#[derive(Debug, Fail)]
+#[fail(display = "Refusing to store creator {}", _0)]
+struct FlakyError(u64);
+
+impl CacheError for FlakyError {}
+
+impl gdcf::cache::Cache for Flaky {
+    type CacheEntryMeta = Entry;
+    type Err = FlakyError;
+}
+
+impl Lookup<CreatorKey> for Flaky {
+    fn lookup(&self, key: &CreatorKey) -> Result<CacheEntry<Creator, Entry>, FlakyError> {
+        Ok(self.inner.lookup(key).unwrap())
+    }
+}
+
+impl Store<CreatorKey> for Flaky {
+    fn store(&mut self, creator: &Creator, key: &CreatorKey) -> Result<Entry, FlakyError> {
+        if self.fail_on.load(Ordering::SeqCst) == key.0 {
+            return Err(FlakyError(key.0))
+        }
+
+        self.stored.lock().unwrap().push(key.0);
+
+        Ok(self.inner.store(creator, key).unwrap())
+    }
+
+    fn mark_absent(&mut self, key: &CreatorKey) -> Result<Entry, FlakyError> {
+        Ok(Store::<CreatorKey>::mark_absent(&mut self.inner, key).unwrap())
+    }
+}
+
+#[test]
+fn failed_flush_requeues_remaining_writes_in_order() {
+    let second = Flaky {
+        inner: Cache::default(),
+        stored: Arc::default(),
+        fail_on: Arc::new(AtomicU64::new(2)),
+    };
+    let mut tiered = TieredCache::new(Cache::default(), second.clone()).with_write_mode(WriteMode::WriteBack { batch_size: 10 });
+
+    for (user_id, name) in &[(1, "Alice"), (2, "Bob"), (3, "Carol"), (4, "Dave")] {
+        tiered.store(&creator(*user_id, name), &CreatorKey(*user_id)).unwrap();
+    }
+
+    assert!(tiered.flush().is_err());
+
+    // The failed write is discarded, the ones after it stay queued
+    assert_eq!(*second.stored.lock().unwrap(), vec![1]);
+    assert_eq!(tiered.pending_writes(), 2);
+
+    tiered.store(&creator(5, "Eve"), &CreatorKey(5)).unwrap();
+    second.fail_on.store(0, Ordering::SeqCst);
+    tiered.flush().unwrap();
+
+    assert_eq!(*second.stored.lock().unwrap(), vec![1, 3, 4, 5]);
+    assert_eq!(tiered.pending_writes(), 0);
+}
@@ -1,9 +1,11 @@
-use crate::{
-    user::{Color, ModLevel},
-    GameMode,
-};
+use std::fmt::{Display, Error, Formatter};
 
-#[derive(Debug)]
+use crate::{user::Color, GameMode};
+#[cfg(feature = "serde_support")]
+use serde_derive::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde_support", derive(Serialize, Deserialize))]
 pub struct ProfileComment {
     /// The actual content of the [`ProfileComment`] made.
     ///
@@ -32,7 +34,8 @@ pub struct ProfileComment {
     pub time_since_post: String,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde_support", derive(Serialize, Deserialize))]
 pub struct LevelComment<User = ()> {
     /// Information about the user that made this [`LevelComment`]. Is generally a [`CommentUser`]
     /// object
@@ -48,7 +51,7 @@ pub struct LevelComment<User = ()> {
     ///
     /// ## GD Internals
     /// This value is provided at index `3`
-    pub user_id: String,
+    pub user_id: u64,
 
     /// The amount of likes this [`LevelComment`] has received
     ///
@@ -61,7 +64,7 @@ pub struct LevelComment<User = ()> {
     ///
     /// ## GD Internals
     /// This value is provided at index `6`
-    pub comment_id: String,
+    pub comment_id: u64,
 
     /// Whether this [`LevelComment`] has been flagged as spam (because of having received too many
     /// dislikes or for other reasons)
@@ -99,7 +102,8 @@ pub struct LevelComment<User = ()> {
     pub special_color: Option<Color>,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde_support", derive(Serialize, Deserialize))]
 pub struct CommentUser {
     /// This [`CommentUser`]'s name
     ///
@@ -148,3 +152,15 @@ pub struct CommentUser {
     /// This value is provided at index `16`
     pub account_id: Option<u64>,
 }
+
+impl<User> Display for LevelComment<User> {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), Error> {
+        write!(f, "LevelComment({}, by {})", self.comment_id, self.user_id)
+    }
+}
+
+impl Display for CommentUser {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), Error> {
+        write!(f, "CommentUser({})", self.name)
+    }
+}
@@ -1,8 +1,9 @@
 //! Module containing all models related to Geometry Dash levels
 
 pub mod data;
+pub mod local;
 
-use crate::{song::MainSong, GameVersion};
+use crate::{song::MainSong, user::Color, GameMode, GameVersion};
 use std::fmt::{Display, Error, Formatter};
 
 #[cfg(feature = "serde_support")]
@@ -440,7 +441,7 @@ impl<Song, User> PartialLevel<Song, User> {
 /// `17`, `20`, `21`, `22`, `23`, `24`, `26`, `31`, `32`, `33`, `34`, `40`,
 /// `41`, `44`
 #[derive(Debug, Eq, PartialEq, Clone)]
-#[cfg_attr(feature = "serde_support", derive(Serialize))]
+#[cfg_attr(feature = "serde_support", derive(Serialize, Deserialize))]
 pub struct Level<Song, User> {
     /// The [`PartialLevel`] this [`Level`] instance supplements
     pub base: PartialLevel<Song, User>,
@@ -454,8 +455,14 @@ pub struct Level<Song, User> {
     /// ## GD Internals:
     /// This value is provided at index `4`, and is urlsafe base64 encoded and
     /// `DEFLATE` compressed
-    #[cfg_attr(feature = "serialize_level_data", serde(serialize_with = "base64_encode"))]
-    #[cfg_attr(all(feature = "serde_support", not(feature = "serialize_level_data")), serde(skip_serializing))]
+    #[cfg_attr(
+        feature = "serialize_level_data",
+        serde(serialize_with = "base64_encode", deserialize_with = "base64_decode")
+    )]
+    #[cfg_attr(
+        all(feature = "serde_support", not(feature = "serialize_level_data")),
+        serde(skip_serializing, default)
+    )]
     pub level_data: Vec<u8>,
 
     /// The level's password
@@ -485,6 +492,258 @@ pub struct Level<Song, User> {
     pub index_36: String,
 }
 
+/// Struct representing a single entry of a level's leaderboard, as returned by the
+/// `getGJLevelScores211` endpoint
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde_support", derive(Serialize, Deserialize))]
+pub struct LevelScore {
+    /// This [`LevelScore`]'s placement on the level's leaderboard
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `6`
+    pub rank: u32,
+
+    /// The name of the player who achieved this [`LevelScore`]
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `1`
+    pub name: String,
+
+    /// The player's unique user ID
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `2`
+    pub user_id: u64,
+
+    /// The player's best percentage on the level
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `3`
+    pub percent: u8,
+
+    /// The index of the icon being displayed.
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `9`
+    pub icon_index: u16,
+
+    /// The player's primary color
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `10`
+    pub primary_color: Color,
+
+    /// The player's secondary color
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `11`
+    pub secondary_color: Color,
+
+    /// The amount of the level's coins the player has collected
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `13`
+    pub coins: u8,
+
+    /// The type of icon being displayed
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `14`
+    pub icon_type: GameMode,
+
+    /// Values indicating whether the player has glow activated or not.
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `15`
+    pub has_glow: bool,
+
+    /// The player's unique account ID
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `16`
+    pub account_id: u64,
+
+    /// The time passed since this [`LevelScore`] was set
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `42`
+    pub time_since_set: String,
+}
+
+impl Display for LevelScore {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), Error> {
+        write!(f, "LevelScore(#{}, {}, {}%)", self.rank, self.name, self.percent)
+    }
+}
+
+/// Struct representing a map pack
+///
+/// ## GD Internals:
+/// Map packs are provided by the Geometry Dash servers in a `getGJMapPacks21` response. The
+/// levels contained in a map pack can be retrieved via a `getGJLevels` request with type `10`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde_support", derive(Serialize, Deserialize))]
+pub struct MapPack<Level = u64> {
+    /// The [`MapPack`]'s unique ID
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `1`
+    pub pack_id: u64,
+
+    /// The [`MapPack`]'s name
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `2`
+    pub name: String,
+
+    /// The levels contained in this [`MapPack`]. Is generally the level ID, or a [`PartialLevel`]
+    /// object
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `3`, as a comma separated list of level IDs
+    pub levels: Vec<Level>,
+
+    /// The amount of stars awarded for completing this [`MapPack`]
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `4`
+    pub stars: u8,
+
+    /// The amount of coins awarded for completing this [`MapPack`]
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `5`
+    pub coins: u8,
+
+    /// The difficulty displayed for this [`MapPack`]
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `6`. Unlike the ratings of levels, map pack difficulties
+    /// are simply numbered from `0` (auto) to `10` (extreme demon), although the demon difficulties
+    /// aren't in order (`6` is hard demon, `7` easy demon)
+    pub difficulty: LevelRating,
+
+    /// The color of this [`MapPack`]'s name
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `7`, as a comma separated RGB triple
+    pub text_color: Color,
+
+    /// The color of this [`MapPack`]'s progress bar
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `8`, as a comma separated RGB triple
+    pub bar_color: Color,
+}
+
+/// Struct representing a gauntlet
+///
+/// ## GD Internals:
+/// Gauntlets are provided by the Geometry Dash servers in a `getGJGauntlets21` response. Apart
+/// from their ID and levels, the servers provide no information about them, the game simply
+/// hardcodes their names.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde_support", derive(Serialize, Deserialize))]
+pub struct Gauntlet {
+    /// The [`Gauntlet`]'s unique ID
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `1`
+    pub gauntlet_id: u64,
+
+    /// The IDs of the levels contained in this [`Gauntlet`]
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `3`, as a comma separated list of level IDs
+    pub levels: Vec<u64>,
+}
+
+impl Gauntlet {
+    /// Gets the name the game displays for this [`Gauntlet`], or [`None`] if GDCF doesn't know
+    /// this gauntlet yet
+    pub fn name(&self) -> Option<&'static str> {
+        Some(match self.gauntlet_id {
+            1 => "Fire",
+            2 => "Ice",
+            3 => "Poison",
+            4 => "Shadow",
+            5 => "Lava",
+            6 => "Bonus",
+            7 => "Chaos",
+            8 => "Demon",
+            9 => "Time",
+            10 => "Crystal",
+            11 => "Magic",
+            12 => "Spike",
+            13 => "Monster",
+            14 => "Doom",
+            15 => "Death",
+            _ => return None,
+        })
+    }
+}
+
+/// Struct representing the current daily or weekly level
+///
+/// ## GD Internals:
+/// This information is provided by the Geometry Dash servers in a `getGJDailyLevel` response.
+/// The response doesn't contain the level itself, it has to be downloaded via a `downloadGJLevel`
+/// request with the special level ID `-1` (for the daily level) or `-2` (for the weekly demon).
+///
+/// ### Indexing:
+/// These objects aren't indexed in the response. The indexes used here are based on the order in
+/// which the fields appear in the response
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde_support", derive(Serialize, Deserialize))]
+pub struct DailyLevel<Level = ()> {
+    /// The level that is currently the daily/weekly level. Is generally a [`Level`] object, or
+    /// `()` if the level hasn't been retrieved yet
+    pub level: Level,
+
+    /// The number of this [`DailyLevel`], as displayed in-game
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `1`. For weekly demons, the servers add `100000` to it
+    pub index: u32,
+
+    /// Whether this is the weekly demon instead of the daily level
+    ///
+    /// ## GD Internals:
+    /// This value is derived from the value at index `1`, see [`DailyLevel::index`]
+    pub weekly: bool,
+
+    /// The amount of seconds left until the next daily/weekly level replaces this one
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `2`
+    pub seconds_left: u32,
+}
+
+impl<Level> Display for MapPack<Level> {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), Error> {
+        write!(f, "MapPack({}, {})", self.pack_id, self.name)
+    }
+}
+
+impl<Level> Display for DailyLevel<Level> {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), Error> {
+        if self.weekly {
+            write!(f, "DailyLevel(weekly #{})", self.index)
+        } else {
+            write!(f, "DailyLevel(daily #{})", self.index)
+        }
+    }
+}
+
+impl Display for Gauntlet {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), Error> {
+        match self.name() {
+            Some(name) => write!(f, "Gauntlet({}, {})", self.gauntlet_id, name),
+            None => write!(f, "Gauntlet({})", self.gauntlet_id),
+        }
+    }
+}
+
 impl<Song, User> Display for PartialLevel<Song, User> {
     fn fmt(&self, f: &mut Formatter) -> Result<(), Error> {
         write!(f, "PartialLevel({}, {})", self.level_id, self.name)
@@ -508,7 +767,7 @@ where
 }
 
 #[cfg(feature = "serialize_level_data")]
-use base64::{encode_config, URL_SAFE};
+use base64::{decode_config, encode_config, URL_SAFE};
 #[cfg(feature = "serialize_level_data")]
 use serde::Serializer;
 
@@ -520,6 +779,16 @@ where
     serializer.collect_str(&encode_config(level_data, URL_SAFE))
 }
 
+#[cfg(feature = "serialize_level_data")]
+fn base64_decode<'de, D>(deserializer: D) -> Result<Vec<u8>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    use serde::{de::Error as _, Deserialize as _};
+
+    decode_config(&String::deserialize(deserializer)?, URL_SAFE).map_err(D::Error::custom)
+}
+
 impl From<i32> for Featured {
     fn from(value: i32) -> Self {
         match value {
@@ -1,20 +1,33 @@
 pub mod ids;
 pub mod portal;
 pub mod text;
+pub mod timeline;
 pub mod trigger;
 
-use crate::level::{
-    data::{
-        portal::{PortalData, Speed},
-        text::TextData,
-        trigger::ColorTriggerData,
+use crate::{
+    level::{
+        data::{
+            portal::{PortalData, Speed},
+            text::TextData,
+            timeline::Timeline,
+            trigger::{
+                AlphaTriggerData, AnimateTriggerData, CollisionTriggerData, ColorTriggerData, CountTriggerData, FollowPlayerYTriggerData,
+                FollowTriggerData, InstantCountTriggerData, MoveTriggerData, OnDeathTriggerData, PickupTriggerData, PulseTriggerData,
+                RotateTriggerData, ShakeTriggerData, SpawnTriggerData, StopTriggerData, ToggleTriggerData, TouchTriggerData, TriggerFlags,
+            },
+        },
+        Level,
     },
-    Level,
+    GameMode,
+};
+use flate2::{read::GzDecoder, write::GzEncoder, Compression};
+use std::{
+    collections::HashMap,
+    io::{Read, Write},
+    time::Duration,
 };
-use flate2::read::GzDecoder;
-use std::{io::Read, time::Duration};
 
-#[derive(Debug, PartialEq, Clone, Default, Copy)]
+#[derive(Debug, PartialEq, Clone, Default)]
 pub struct LevelMetadata {
     pub starting_speed: Speed,
     pub song_offset: f64,
@@ -23,7 +36,24 @@ pub struct LevelMetadata {
     pub dual_start: bool,
     pub two_player_controls: bool,
     pub start_gravity_inverted: bool,
+
+    /// The game mode the player starts the level in
+    ///
+    /// ## GD Internals:
+    /// This value is provided at key `kA2`
+    pub starting_game_mode: GameMode,
+
+    /// Whether the player starts the level in mini mode
+    ///
+    /// ## GD Internals:
+    /// This value is provided at key `kA3`
+    pub start_mini: bool,
     // ... other fields in the metadata section ...
+    /// All properties of the level's metadata section GDCF doesn't model (yet), like its color
+    /// channels, as `(key, value)` pairs in their raw level string representation.
+    ///
+    /// See [`LevelObject::unknown_properties`] for why these are kept.
+    pub unknown_properties: HashMap<String, String>,
 }
 
 #[derive(Debug, PartialEq, Clone)]
@@ -34,8 +64,78 @@ pub struct LevelObject {
     pub flipped_x: bool,
     pub flipped_y: bool,
     pub rotation: f32,
+
+    /// The IDs of the groups this object is in
+    ///
+    /// ## GD Internals:
+    /// This value is provided at key `57`, as a `.`-separated list
+    pub groups: Vec<u16>,
+
+    /// The ID of the color channel used for the object's main color, or `0` if the object uses
+    /// its default channel
+    ///
+    /// ## GD Internals:
+    /// This value is provided at key `21`
+    pub main_color: u16,
+
+    /// The ID of the color channel used for the object's secondary (detail) color, or `0` if the
+    /// object uses its default channel
+    ///
+    /// ## GD Internals:
+    /// This value is provided at key `22`
+    pub secondary_color: u16,
+
+    /// The z-layer the object is drawn on, or `0` if the object uses its default layer
+    ///
+    /// ## GD Internals:
+    /// This value is provided at key `24`
+    pub z_layer: i8,
+
+    /// The object's z-order inside its z-layer
+    ///
+    /// ## GD Internals:
+    /// This value is provided at key `25`
+    pub z_order: i16,
+
+    /// The object's scale
+    ///
+    /// ## GD Internals:
+    /// This value is provided at key `32`. If it is missing, the object has a scale of `1`
+    pub scale: f32,
+
+    /// The editor layer the object is on
+    ///
+    /// ## GD Internals:
+    /// This value is provided at key `20`
+    pub editor_layer_1: u16,
+
+    /// The second editor layer the object is on
+    ///
+    /// ## GD Internals:
+    /// This value is provided at key `61`
+    pub editor_layer_2: u16,
+
+    /// Whether the object is invisible during gameplay
+    ///
+    /// ## GD Internals:
+    /// This value is provided at key `135`
+    pub hidden: bool,
+
+    /// Whether the object has collision disabled
+    ///
+    /// ## GD Internals:
+    /// This value is provided at key `121`
+    pub no_touch: bool,
+
     // ... other fields they all have ...
     pub metadata: ObjectData,
+
+    /// All properties of this object GDCF doesn't model (yet), as `(key, value)` pairs in their
+    /// raw level string representation.
+    ///
+    /// These are kept so that unparsing an object doesn't lose any of its data, meaning a level
+    /// can be parsed, modified and serialized again without getting corrupted.
+    pub unknown_properties: HashMap<String, String>,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -44,6 +144,84 @@ pub enum ObjectData {
     Portal(PortalData),
     Text(TextData),
     ColorTrigger(ColorTriggerData),
+    MoveTrigger(MoveTriggerData),
+    RotateTrigger(RotateTriggerData),
+    AlphaTrigger(AlphaTriggerData),
+    ToggleTrigger(ToggleTriggerData),
+    PulseTrigger(PulseTriggerData),
+    SpawnTrigger(SpawnTriggerData),
+    StopTrigger(StopTriggerData),
+    FollowTrigger(FollowTriggerData),
+    FollowPlayerYTrigger(FollowPlayerYTriggerData),
+    ShakeTrigger(ShakeTriggerData),
+    AnimateTrigger(AnimateTriggerData),
+    TouchTrigger(TouchTriggerData),
+    CountTrigger(CountTriggerData),
+    InstantCountTrigger(InstantCountTriggerData),
+    PickupTrigger(PickupTriggerData),
+    CollisionTrigger(CollisionTriggerData),
+    OnDeathTrigger(OnDeathTriggerData),
+}
+
+impl ObjectData {
+    /// Whether this object is a trigger
+    pub fn is_trigger(&self) -> bool {
+        self.trigger_flags().is_some()
+    }
+
+    /// The [`TriggerFlags`] of this object, or [`None`] if it isn't a trigger
+    pub fn trigger_flags(&self) -> Option<TriggerFlags> {
+        Some(match self {
+            ObjectData::None | ObjectData::Portal(_) | ObjectData::Text(_) => return None,
+            ObjectData::ColorTrigger(data) => data.flags,
+            ObjectData::MoveTrigger(data) => data.flags,
+            ObjectData::RotateTrigger(data) => data.flags,
+            ObjectData::AlphaTrigger(data) => data.flags,
+            ObjectData::ToggleTrigger(data) => data.flags,
+            ObjectData::PulseTrigger(data) => data.flags,
+            ObjectData::SpawnTrigger(data) => data.flags,
+            ObjectData::StopTrigger(data) => data.flags,
+            ObjectData::FollowTrigger(data) => data.flags,
+            ObjectData::FollowPlayerYTrigger(data) => data.flags,
+            ObjectData::ShakeTrigger(data) => data.flags,
+            ObjectData::AnimateTrigger(data) => data.flags,
+            ObjectData::TouchTrigger(data) => data.flags,
+            ObjectData::CountTrigger(data) => data.flags,
+            ObjectData::InstantCountTrigger(data) => data.flags,
+            ObjectData::PickupTrigger(data) => data.flags,
+            ObjectData::CollisionTrigger(data) => data.flags,
+            ObjectData::OnDeathTrigger(data) => data.flags,
+        })
+    }
+
+    /// The ID of the group this object acts on, or [`None`] if it isn't a trigger targeting a
+    /// group.
+    ///
+    /// Pulse triggers only have a target group if they target a group instead of a color channel.
+    /// A target group of `0` means that the trigger doesn't have a target set.
+    ///
+    /// Together with [`LevelObject::groups`], this allows following chains of triggers, e.g. spawn
+    /// triggers activating groups containing further spawn triggers.
+    pub fn target_group(&self) -> Option<u16> {
+        Some(match self {
+            ObjectData::MoveTrigger(data) => data.target_group,
+            ObjectData::RotateTrigger(data) => data.target_group,
+            ObjectData::AlphaTrigger(data) => data.target_group,
+            ObjectData::ToggleTrigger(data) => data.target_group,
+            ObjectData::PulseTrigger(data) if data.target_is_group => data.target,
+            ObjectData::SpawnTrigger(data) => data.target_group,
+            ObjectData::StopTrigger(data) => data.target_group,
+            ObjectData::FollowTrigger(data) => data.target_group,
+            ObjectData::FollowPlayerYTrigger(data) => data.target_group,
+            ObjectData::AnimateTrigger(data) => data.target_group,
+            ObjectData::TouchTrigger(data) => data.target_group,
+            ObjectData::CountTrigger(data) => data.target_group,
+            ObjectData::InstantCountTrigger(data) => data.target_group,
+            ObjectData::CollisionTrigger(data) => data.target_group,
+            ObjectData::OnDeathTrigger(data) => data.target_group,
+            _ => return None,
+        })
+    }
 }
 
 #[derive(Debug, PartialEq, Eq, Copy, Clone)]
@@ -54,19 +232,54 @@ pub struct Stats {
 
 impl<S, U> Level<S, U> {
     pub fn decompress_data(&self) -> std::io::Result<String> {
-        let mut s = String::new();
-        let mut d = GzDecoder::new(&self.level_data[..]);
-
-        d.read_to_string(&mut s)?;
+        decompress(&self.level_data)
+    }
 
-        Ok(s)
+    /// Compresses the given level string and stores it as this [`Level`]'s data. This is the
+    /// inverse of [`Level::decompress_data`].
+    ///
+    /// Just like for data retrieved from the servers, the base64 encoding only happens once the
+    /// level is unparsed.
+    pub fn compress_data(&mut self, level_string: &str) {
+        self.level_data = compress(level_string)
     }
 }
 
+/// Decompresses raw level data, as found in [`Level::level_data`], into the level string
+pub fn decompress(level_data: &[u8]) -> std::io::Result<String> {
+    let mut s = String::new();
+    let mut d = GzDecoder::new(level_data);
+
+    d.read_to_string(&mut s)?;
+
+    Ok(s)
+}
+
+/// Compresses the given string the same way the game compresses level data and save files. This
+/// is the inverse of [`decompress`]
+pub fn compress(data: &str) -> Vec<u8> {
+    let mut e = GzEncoder::new(Vec::new(), Compression::default());
+
+    // Writing into a Vec cannot fail
+    e.write_all(data.as_bytes()).expect("writing to Vec failed");
+    e.finish().expect("writing to Vec failed")
+}
+
 pub trait LevelInformationSource {
     fn collect(self) -> Vec<LevelObject>;
 
     fn stats(self) -> Stats;
 
+    /// Computes the [`Timeline`] of the level, breaking it up into segments of constant speed,
+    /// game mode, size, dual and gravity state
+    ///
+    /// Like [`LevelInformationSource::stats`], only speed portals that are `checked` are taken into
+    /// account. All other portals affect the timeline regardless of their `checked` property. Note
+    /// that the timeline's [duration](Timeline::duration) can differ from the one in the level's
+    /// [`Stats`], which is computed separately via
+    /// [`get_seconds_from_x_pos`](crate::level::data::portal::get_seconds_from_x_pos) and rounded
+    /// down to full seconds
+    fn timeline(self) -> Timeline;
+
     fn metadata(&self) -> LevelMetadata;
 }
@@ -9,3 +9,77 @@ pub const S_NORMAL_PORTAL: &str = "201";
 pub const S_MEDIUM_PORTAL: &str = "202";
 pub const S_FAST_PORTAL: &str = "203";
 pub const S_VERY_FAST_PORTAL: &str = "1334";
+
+pub const NORMAL_GRAVITY_PORTAL: u16 = 10;
+pub const INVERTED_GRAVITY_PORTAL: u16 = 11;
+pub const CUBE_PORTAL: u16 = 12;
+pub const SHIP_PORTAL: u16 = 13;
+pub const MIRROR_PORTAL: u16 = 45;
+pub const UNMIRROR_PORTAL: u16 = 46;
+pub const BALL_PORTAL: u16 = 47;
+pub const NORMAL_SIZE_PORTAL: u16 = 99;
+pub const MINI_PORTAL: u16 = 101;
+pub const UFO_PORTAL: u16 = 111;
+pub const DUAL_PORTAL: u16 = 286;
+pub const SINGLE_PORTAL: u16 = 287;
+pub const WAVE_PORTAL: u16 = 660;
+pub const ROBOT_PORTAL: u16 = 745;
+pub const TELEPORT_PORTAL: u16 = 747;
+pub const TELEPORT_EXIT_PORTAL: u16 = 749;
+pub const SPIDER_PORTAL: u16 = 1331;
+
+pub const S_NORMAL_GRAVITY_PORTAL: &str = "10";
+pub const S_INVERTED_GRAVITY_PORTAL: &str = "11";
+pub const S_CUBE_PORTAL: &str = "12";
+pub const S_SHIP_PORTAL: &str = "13";
+pub const S_MIRROR_PORTAL: &str = "45";
+pub const S_UNMIRROR_PORTAL: &str = "46";
+pub const S_BALL_PORTAL: &str = "47";
+pub const S_NORMAL_SIZE_PORTAL: &str = "99";
+pub const S_MINI_PORTAL: &str = "101";
+pub const S_UFO_PORTAL: &str = "111";
+pub const S_DUAL_PORTAL: &str = "286";
+pub const S_SINGLE_PORTAL: &str = "287";
+pub const S_WAVE_PORTAL: &str = "660";
+pub const S_ROBOT_PORTAL: &str = "745";
+pub const S_TELEPORT_PORTAL: &str = "747";
+pub const S_TELEPORT_EXIT_PORTAL: &str = "749";
+pub const S_SPIDER_PORTAL: &str = "1331";
+
+pub const COLOR_TRIGGER: u16 = 899;
+pub const MOVE_TRIGGER: u16 = 901;
+pub const PULSE_TRIGGER: u16 = 1006;
+pub const ALPHA_TRIGGER: u16 = 1007;
+pub const TOGGLE_TRIGGER: u16 = 1049;
+pub const SPAWN_TRIGGER: u16 = 1268;
+pub const ROTATE_TRIGGER: u16 = 1346;
+pub const FOLLOW_TRIGGER: u16 = 1347;
+pub const SHAKE_TRIGGER: u16 = 1520;
+pub const ANIMATE_TRIGGER: u16 = 1585;
+pub const TOUCH_TRIGGER: u16 = 1595;
+pub const COUNT_TRIGGER: u16 = 1611;
+pub const STOP_TRIGGER: u16 = 1616;
+pub const INSTANT_COUNT_TRIGGER: u16 = 1811;
+pub const ON_DEATH_TRIGGER: u16 = 1812;
+pub const FOLLOW_PLAYER_Y_TRIGGER: u16 = 1814;
+pub const COLLISION_TRIGGER: u16 = 1815;
+pub const PICKUP_TRIGGER: u16 = 1817;
+
+pub const S_COLOR_TRIGGER: &str = "899";
+pub const S_MOVE_TRIGGER: &str = "901";
+pub const S_PULSE_TRIGGER: &str = "1006";
+pub const S_ALPHA_TRIGGER: &str = "1007";
+pub const S_TOGGLE_TRIGGER: &str = "1049";
+pub const S_SPAWN_TRIGGER: &str = "1268";
+pub const S_ROTATE_TRIGGER: &str = "1346";
+pub const S_FOLLOW_TRIGGER: &str = "1347";
+pub const S_SHAKE_TRIGGER: &str = "1520";
+pub const S_ANIMATE_TRIGGER: &str = "1585";
+pub const S_TOUCH_TRIGGER: &str = "1595";
+pub const S_COUNT_TRIGGER: &str = "1611";
+pub const S_STOP_TRIGGER: &str = "1616";
+pub const S_INSTANT_COUNT_TRIGGER: &str = "1811";
+pub const S_ON_DEATH_TRIGGER: &str = "1812";
+pub const S_FOLLOW_PLAYER_Y_TRIGGER: &str = "1814";
+pub const S_COLLISION_TRIGGER: &str = "1815";
+pub const S_PICKUP_TRIGGER: &str = "1817";
@@ -1,4 +1,4 @@
-use crate::level::data::ids;
+use crate::{level::data::ids, GameMode};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Speed {
@@ -31,9 +31,34 @@ impl Into<f32> for Speed {
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum PortalType {
+    /// The object isn't a portal
     Nonsense,
+
+    /// A speed portal, changing the speed the player moves at
     Speed(Speed),
-    // .. all the other portals ..
+
+    /// A gamemode portal, turning the player into the given [`GameMode`]
+    GameMode(GameMode),
+
+    /// A gravity portal. The yellow portal flips gravity upside down, the blue one flips it back
+    Gravity { upside_down: bool },
+
+    /// A mirror portal. The orange portal mirrors the screen horizontally, the blue one reverts
+    /// this
+    Mirror { mirrored: bool },
+
+    /// A size portal. The pink portal makes the player mini, the green one reverts this
+    Size { mini: bool },
+
+    /// A dual portal. The orange portal splits the player in two, the blue one reverts this
+    Dual { dual: bool },
+
+    /// A blue teleport portal, moving the player to its linked orange exit. See
+    /// [`PortalData::teleport_offset`]
+    Teleport,
+
+    /// A standalone orange teleport portal, which doesn't do anything on its own
+    TeleportExit,
 }
 
 impl PortalType {
@@ -44,6 +69,23 @@ impl PortalType {
             ids::S_MEDIUM_PORTAL => PortalType::Speed(Speed::Medium),
             ids::S_FAST_PORTAL => PortalType::Speed(Speed::Fast),
             ids::S_VERY_FAST_PORTAL => PortalType::Speed(Speed::VeryFast),
+            ids::S_CUBE_PORTAL => PortalType::GameMode(GameMode::Cube),
+            ids::S_SHIP_PORTAL => PortalType::GameMode(GameMode::Ship),
+            ids::S_BALL_PORTAL => PortalType::GameMode(GameMode::Ball),
+            ids::S_UFO_PORTAL => PortalType::GameMode(GameMode::Ufo),
+            ids::S_WAVE_PORTAL => PortalType::GameMode(GameMode::Wave),
+            ids::S_ROBOT_PORTAL => PortalType::GameMode(GameMode::Robot),
+            ids::S_SPIDER_PORTAL => PortalType::GameMode(GameMode::Spider),
+            ids::S_NORMAL_GRAVITY_PORTAL => PortalType::Gravity { upside_down: false },
+            ids::S_INVERTED_GRAVITY_PORTAL => PortalType::Gravity { upside_down: true },
+            ids::S_MIRROR_PORTAL => PortalType::Mirror { mirrored: true },
+            ids::S_UNMIRROR_PORTAL => PortalType::Mirror { mirrored: false },
+            ids::S_MINI_PORTAL => PortalType::Size { mini: true },
+            ids::S_NORMAL_SIZE_PORTAL => PortalType::Size { mini: false },
+            ids::S_DUAL_PORTAL => PortalType::Dual { dual: true },
+            ids::S_SINGLE_PORTAL => PortalType::Dual { dual: false },
+            ids::S_TELEPORT_PORTAL => PortalType::Teleport,
+            ids::S_TELEPORT_EXIT_PORTAL => PortalType::TeleportExit,
             _ => PortalType::Nonsense,
         }
     }
@@ -55,15 +97,40 @@ impl PortalType {
             ids::MEDIUM_PORTAL => PortalType::Speed(Speed::Medium),
             ids::FAST_PORTAL => PortalType::Speed(Speed::Fast),
             ids::VERY_FAST_PORTAL => PortalType::Speed(Speed::VeryFast),
+            ids::CUBE_PORTAL => PortalType::GameMode(GameMode::Cube),
+            ids::SHIP_PORTAL => PortalType::GameMode(GameMode::Ship),
+            ids::BALL_PORTAL => PortalType::GameMode(GameMode::Ball),
+            ids::UFO_PORTAL => PortalType::GameMode(GameMode::Ufo),
+            ids::WAVE_PORTAL => PortalType::GameMode(GameMode::Wave),
+            ids::ROBOT_PORTAL => PortalType::GameMode(GameMode::Robot),
+            ids::SPIDER_PORTAL => PortalType::GameMode(GameMode::Spider),
+            ids::NORMAL_GRAVITY_PORTAL => PortalType::Gravity { upside_down: false },
+            ids::INVERTED_GRAVITY_PORTAL => PortalType::Gravity { upside_down: true },
+            ids::MIRROR_PORTAL => PortalType::Mirror { mirrored: true },
+            ids::UNMIRROR_PORTAL => PortalType::Mirror { mirrored: false },
+            ids::MINI_PORTAL => PortalType::Size { mini: true },
+            ids::NORMAL_SIZE_PORTAL => PortalType::Size { mini: false },
+            ids::DUAL_PORTAL => PortalType::Dual { dual: true },
+            ids::SINGLE_PORTAL => PortalType::Dual { dual: false },
+            ids::TELEPORT_PORTAL => PortalType::Teleport,
+            ids::TELEPORT_EXIT_PORTAL => PortalType::TeleportExit,
             _ => PortalType::Nonsense,
         }
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub struct PortalData {
     pub checked: bool,
     pub portal_type: PortalType,
+
+    /// The vertical distance between a [teleport portal](PortalType::Teleport) and its linked
+    /// exit. The player is moved by this distance when entering the portal. Always `0` for other
+    /// portals.
+    ///
+    /// ## GD Internals:
+    /// This value is provided at key `54`
+    pub teleport_offset: f32,
 }
 
 pub fn get_seconds_from_x_pos(pos: f32, start_speed: Speed, portals: &[(f32, Speed)]) -> f32 {
@@ -0,0 +1,212 @@
+//! Module containing the analysis of a level's gameplay over time, based on the portals it contains
+
+use crate::{
+    level::data::{
+        portal::{PortalType, Speed},
+        LevelMetadata,
+    },
+    GameMode,
+};
+
+/// The state the player is in during a [`Segment`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PlayerState {
+    pub speed: Speed,
+    pub game_mode: GameMode,
+    pub mini: bool,
+    pub dual: bool,
+    pub upside_down: bool,
+}
+
+/// A part of a level throughout which the [`PlayerState`] doesn't change
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Segment {
+    /// The x position at which this segment starts
+    pub start_x: f32,
+
+    /// The x position at which this segment ends
+    pub end_x: f32,
+
+    /// The time, in seconds since the level started, at which the player reaches this segment
+    pub start_time: f32,
+
+    /// The time, in seconds, the player spends in this segment
+    pub seconds: f32,
+
+    pub state: PlayerState,
+}
+
+/// A portal the player passes through while playing a level
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PortalHit {
+    pub x: f32,
+
+    /// The time, in seconds since the level started, at which the player passes the portal
+    pub time: f32,
+
+    pub portal_type: PortalType,
+}
+
+/// The timeline of a level, splitting it up into [`Segment`]s of constant [`PlayerState`]
+///
+/// Just like [`get_seconds_from_x_pos`](crate::level::data::portal::get_seconds_from_x_pos), the
+/// timeline assumes that the player passes every portal in the level, in order of their x
+/// positions. Portals which don't affect the player's movement (mirror and teleport portals) are
+/// ignored.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Timeline {
+    /// The segments of the level, in the order the player passes through them. Adjacent segments
+    /// always have different [`PlayerState`]s
+    pub segments: Vec<Segment>,
+
+    /// All portals affecting the [`PlayerState`], in the order the player passes them. This
+    /// includes portals that don't actually change anything, e.g. a cube portal while the
+    /// player already is a cube
+    pub portal_hits: Vec<PortalHit>,
+}
+
+impl PlayerState {
+    /// The state the player is in at the start of the level with the given metadata
+    pub fn initial(metadata: &LevelMetadata) -> PlayerState {
+        PlayerState {
+            speed: metadata.starting_speed,
+            game_mode: metadata.starting_game_mode,
+            mini: metadata.start_mini,
+            dual: metadata.dual_start,
+            upside_down: metadata.start_gravity_inverted,
+        }
+    }
+
+    /// Applies the effect of the given portal to this state
+    pub fn apply(&mut self, portal_type: PortalType) {
+        match portal_type {
+            PortalType::Speed(speed) => self.speed = speed,
+            PortalType::GameMode(game_mode) => self.game_mode = game_mode,
+            PortalType::Size { mini } => self.mini = mini,
+            PortalType::Dual { dual } => self.dual = dual,
+            PortalType::Gravity { upside_down } => self.upside_down = upside_down,
+            PortalType::Mirror { .. } | PortalType::Teleport | PortalType::TeleportExit | PortalType::Nonsense => (),
+        }
+    }
+}
+
+impl Timeline {
+    /// Computes the timeline of a level with the given metadata, which contains the given portals,
+    /// given as `(x position, portal type)` pairs, and ends at the given x position.
+    ///
+    /// The portals don't need to be sorted. Portals at non-finite x positions are ignored, as is a
+    /// non-finite end position (in which case the level ends at its last portal).
+    pub fn compute(metadata: &LevelMetadata, mut portals: Vec<(f32, PortalType)>, end_x: f32) -> Timeline {
+        let end_x = if end_x.is_finite() { end_x } else { 0.0 };
+
+        portals.retain(|(x, _)| x.is_finite());
+        portals.sort_by(|(x1, _), (x2, _)| x1.total_cmp(x2));
+
+        let mut timeline = Timeline::default();
+        let mut state = PlayerState::initial(metadata);
+        let mut start_x = 0.0;
+        let mut start_time = 0.0;
+
+        for (x, portal_type) in portals {
+            if let PortalType::Mirror { .. } | PortalType::Teleport | PortalType::TeleportExit | PortalType::Nonsense = portal_type {
+                continue
+            }
+
+            let time = start_time + segment_seconds(start_x, x, state.speed);
+
+            timeline.portal_hits.push(PortalHit { x, time, portal_type });
+
+            let mut new_state = state;
+
+            new_state.apply(portal_type);
+
+            if new_state == state {
+                continue
+            }
+
+            // Portals at the very start of the level don't produce an empty segment
+            if x > start_x {
+                timeline.segments.push(Segment {
+                    start_x,
+                    end_x: x,
+                    start_time,
+                    seconds: time - start_time,
+                    state,
+                });
+            }
+
+            state = new_state;
+            start_x = f32::max(start_x, x);
+            start_time = time;
+        }
+
+        if end_x > start_x || timeline.segments.is_empty() {
+            timeline.segments.push(Segment {
+                start_x,
+                end_x: f32::max(start_x, end_x),
+                start_time,
+                seconds: segment_seconds(start_x, end_x, state.speed),
+                state,
+            });
+        }
+
+        timeline
+    }
+
+    /// The total time, in seconds, it takes to complete the level
+    pub fn duration(&self) -> f32 {
+        // Summing an empty iterator of floats yields `-0.0`, which we don't want to leak out
+        self.segments.iter().fold(0.0, |duration, segment| duration + segment.seconds)
+    }
+
+    /// The fraction (between `0` and `1`) of the level's duration during which the given predicate
+    /// holds for the [`PlayerState`]
+    pub fn share<P>(&self, predicate: P) -> f32
+    where
+        P: Fn(&PlayerState) -> bool,
+    {
+        let duration = self.duration();
+
+        if duration.is_nan() || duration <= 0.0 {
+            return 0.0
+        }
+
+        self.segments
+            .iter()
+            .filter(|segment| predicate(&segment.state))
+            .fold(0.0, |seconds, segment| seconds + segment.seconds)
+            / duration
+    }
+
+    /// The fraction (between `0` and `1`) of the level's duration the player spends in the given
+    /// game mode
+    pub fn game_mode_share(&self, game_mode: GameMode) -> f32 {
+        self.share(|state| state.game_mode == game_mode)
+    }
+
+    /// The fraction (between `0` and `1`) of the level's duration the player moves at the given
+    /// speed
+    pub fn speed_share(&self, speed: Speed) -> f32 {
+        self.share(|state| state.speed == speed)
+    }
+
+    /// Iterates over all speed portals the player passes, as `(time in seconds, speed)` pairs
+    pub fn speed_portals<'a>(&'a self) -> impl Iterator<Item = (f32, Speed)> + 'a {
+        self.portal_hits.iter().filter_map(|hit| {
+            match hit.portal_type {
+                PortalType::Speed(speed) => Some((hit.time, speed)),
+                _ => None,
+            }
+        })
+    }
+}
+
+fn segment_seconds(start_x: f32, end_x: f32, speed: Speed) -> f32 {
+    let speed: f32 = speed.into();
+
+    if speed <= 0.0 || end_x <= start_x {
+        0.0
+    } else {
+        (end_x - start_x) / speed
+    }
+}
@@ -1,11 +1,575 @@
+//! Module containing the data of all triggers known to GDCF
+//!
+//! Field documentation mentions the key at which a value is stored in the object string. Keys that
+//! are missing from the object string take the default value of the field.
+//!
+//! Version 2.1 of the game has no dedicated camera triggers, the only trigger affecting the camera
+//! is the [shake trigger](ShakeTriggerData).
+
 #[cfg(feature = "serde_support")]
 use serde_derive::{Deserialize, Serialize};
 
-#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+/// The easing function a trigger uses for its transition
+///
+/// ## GD Internals:
+/// This value is provided at key `30`, as an integer from `0` to `18`, in the order the variants
+/// are declared in
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, Default)]
+#[cfg_attr(feature = "serde_support", derive(Serialize, Deserialize))]
+pub enum Easing {
+    #[default]
+    None,
+    EaseInOut,
+    EaseIn,
+    EaseOut,
+    ElasticInOut,
+    ElasticIn,
+    ElasticOut,
+    BounceInOut,
+    BounceIn,
+    BounceOut,
+    ExponentialInOut,
+    ExponentialIn,
+    ExponentialOut,
+    SineInOut,
+    SineIn,
+    SineOut,
+    BackInOut,
+    BackIn,
+    BackOut,
+    Unknown(u8),
+}
+
+/// A hue-saturation-brightness adjustment, as used by triggers copying or pulsing colors
+///
+/// ## GD Internals:
+/// This value is represented as
+/// `{hue}a{saturation}a{brightness}a{saturation_additive}a{brightness_additive}`
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "serde_support", derive(Serialize, Deserialize))]
+pub struct Hsv {
+    pub hue: i16,
+    pub saturation: f32,
+    pub brightness: f32,
+    pub saturation_additive: bool,
+    pub brightness_additive: bool,
+}
+
+impl Default for Hsv {
+    fn default() -> Hsv {
+        Hsv {
+            hue: 0,
+            saturation: 1.0,
+            brightness: 1.0,
+            saturation_additive: false,
+            brightness_additive: false,
+        }
+    }
+}
+
+/// The state a toggling trigger puts its target group in
+///
+/// ## GD Internals:
+/// This value is provided at key `82` of touch triggers, as `0`, `1` or `2`
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, Default)]
+#[cfg_attr(feature = "serde_support", derive(Serialize, Deserialize))]
+pub enum ToggleMode {
+    /// The target group is toggled
+    #[default]
+    Toggle,
+
+    /// The target group is activated
+    On,
+
+    /// The target group is deactivated
+    Off,
+
+    /// Any value not known to GDCF, kept so that the trigger is serialized again unchanged
+    Unknown(u8),
+}
+
+/// The comparison an instant count trigger performs
+///
+/// ## GD Internals:
+/// This value is provided at key `88`, as `0`, `1` or `2`
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, Default)]
+#[cfg_attr(feature = "serde_support", derive(Serialize, Deserialize))]
+pub enum Comparison {
+    #[default]
+    Equals,
+    Larger,
+    Smaller,
+
+    /// Any value not known to GDCF, kept so that the trigger is serialized again unchanged
+    Unknown(u8),
+}
+
+/// The conditions under which a trigger is activated, shared by all triggers
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, Default)]
+#[cfg_attr(feature = "serde_support", derive(Serialize, Deserialize))]
+pub struct TriggerFlags {
+    /// Whether the trigger is activated by the player touching it instead of passing its x
+    /// position (key `11`)
+    pub touch_triggered: bool,
+
+    /// Whether the trigger is only activated by a spawn trigger (key `62`)
+    pub spawn_triggered: bool,
+
+    /// Whether the trigger can be activated multiple times (key `87`)
+    pub multi_trigger: bool,
+}
+
+/// Color trigger
+///
+/// ## GD Internals:
+/// Object ID `899`
+#[derive(Debug, PartialEq, Clone, Copy)]
 #[cfg_attr(feature = "serde_support", derive(Serialize, Deserialize))]
 pub struct ColorTriggerData {
+    /// Key `7`
     pub r: u8,
+
+    /// Key `8`
     pub g: u8,
+
+    /// Key `9`
     pub b: u8,
+
+    /// Key `17`
     pub blending_enabled: bool,
+
+    /// The duration of the color transition, in seconds (key `10`)
+    pub duration: f32,
+
+    /// The color channel to change (key `23`)
+    pub target_channel: u16,
+
+    /// Key `35`
+    pub opacity: f32,
+
+    /// Whether to use the player's primary color instead of the RGB values (key `15`)
+    pub player_color_1: bool,
+
+    /// Whether to use the player's secondary color instead of the RGB values (key `16`)
+    pub player_color_2: bool,
+
+    /// The color channel to copy the color from, or `0` to not copy a color (key `50`)
+    pub copy_color: u16,
+
+    /// The adjustment applied to the copied color (key `49`)
+    pub copy_hsv: Hsv,
+
+    /// Whether to copy the opacity of the copied color (key `60`)
+    pub copy_opacity: bool,
+
+    pub flags: TriggerFlags,
+}
+
+/// Move trigger
+///
+/// ## GD Internals:
+/// Object ID `901`
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "serde_support", derive(Serialize, Deserialize))]
+pub struct MoveTriggerData {
+    /// Key `51`
+    pub target_group: u16,
+
+    /// Key `28`
+    pub x: f32,
+
+    /// Key `29`
+    pub y: f32,
+
+    /// Key `10`
+    pub duration: f32,
+
+    /// Key `30`
+    pub easing: Easing,
+
+    /// Key `85`
+    pub easing_rate: f32,
+
+    /// Key `58`
+    pub lock_to_player_x: bool,
+
+    /// Key `59`
+    pub lock_to_player_y: bool,
+
+    /// Whether to move the target group to the position of `target_position_group` instead of by
+    /// an offset (key `100`)
+    pub use_target: bool,
+
+    /// Key `71`
+    pub target_position_group: u16,
+
+    pub flags: TriggerFlags,
+}
+
+/// Rotate trigger
+///
+/// ## GD Internals:
+/// Object ID `1346`
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "serde_support", derive(Serialize, Deserialize))]
+pub struct RotateTriggerData {
+    /// Key `51`
+    pub target_group: u16,
+
+    /// The group to rotate around (key `71`)
+    pub center_group: u16,
+
+    /// Key `68`
+    pub degrees: f32,
+
+    /// Additional full rotations (key `69`)
+    pub times_360: i32,
+
+    /// Key `70`
+    pub lock_object_rotation: bool,
+
+    /// Key `10`
+    pub duration: f32,
+
+    /// Key `30`
+    pub easing: Easing,
+
+    /// Key `85`
+    pub easing_rate: f32,
+
+    pub flags: TriggerFlags,
+}
+
+/// Alpha trigger
+///
+/// ## GD Internals:
+/// Object ID `1007`
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "serde_support", derive(Serialize, Deserialize))]
+pub struct AlphaTriggerData {
+    /// Key `51`
+    pub target_group: u16,
+
+    /// Key `35`
+    pub opacity: f32,
+
+    /// Key `10`
+    pub duration: f32,
+
+    pub flags: TriggerFlags,
+}
+
+/// Toggle trigger
+///
+/// ## GD Internals:
+/// Object ID `1049`
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+#[cfg_attr(feature = "serde_support", derive(Serialize, Deserialize))]
+pub struct ToggleTriggerData {
+    /// Key `51`
+    pub target_group: u16,
+
+    /// Whether the target group is activated instead of deactivated (key `56`)
+    pub activate_group: bool,
+
+    pub flags: TriggerFlags,
+}
+
+/// Pulse trigger
+///
+/// ## GD Internals:
+/// Object ID `1006`
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "serde_support", derive(Serialize, Deserialize))]
+pub struct PulseTriggerData {
+    /// Key `7`
+    pub r: u8,
+
+    /// Key `8`
+    pub g: u8,
+
+    /// Key `9`
+    pub b: u8,
+
+    /// Key `45`
+    pub fade_in: f32,
+
+    /// Key `46`
+    pub hold: f32,
+
+    /// Key `47`
+    pub fade_out: f32,
+
+    /// Whether the pulse copies a color channel with an HSV adjustment instead of using the RGB
+    /// values (key `48`)
+    pub hsv_mode: bool,
+
+    /// Key `49`
+    pub hsv: Hsv,
+
+    /// The color channel to copy in HSV mode (key `50`)
+    pub copy_color: u16,
+
+    /// Whether `target` refers to a group instead of a color channel (key `52`)
+    pub target_is_group: bool,
+
+    /// The ID of the group or color channel to pulse (key `51`)
+    pub target: u16,
+
+    /// Key `65`
+    pub main_only: bool,
+
+    /// Key `66`
+    pub detail_only: bool,
+
+    /// Whether this pulse overrides all other pulses on the same target (key `86`)
+    pub exclusive: bool,
+
+    pub flags: TriggerFlags,
+}
+
+/// Spawn trigger
+///
+/// ## GD Internals:
+/// Object ID `1268`
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "serde_support", derive(Serialize, Deserialize))]
+pub struct SpawnTriggerData {
+    /// The group whose triggers get activated (key `51`)
+    pub target_group: u16,
+
+    /// Key `63`
+    pub delay: f32,
+
+    pub flags: TriggerFlags,
+}
+
+/// Stop trigger
+///
+/// ## GD Internals:
+/// Object ID `1616`
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+#[cfg_attr(feature = "serde_support", derive(Serialize, Deserialize))]
+pub struct StopTriggerData {
+    /// The group whose triggers get stopped (key `51`)
+    pub target_group: u16,
+
+    pub flags: TriggerFlags,
+}
+
+/// Follow trigger
+///
+/// ## GD Internals:
+/// Object ID `1347`
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "serde_support", derive(Serialize, Deserialize))]
+pub struct FollowTriggerData {
+    /// Key `51`
+    pub target_group: u16,
+
+    /// The group to follow (key `71`)
+    pub follow_group: u16,
+
+    /// Key `72`
+    pub x_mod: f32,
+
+    /// Key `73`
+    pub y_mod: f32,
+
+    /// Key `10`
+    pub duration: f32,
+
+    pub flags: TriggerFlags,
+}
+
+/// Follow player Y trigger
+///
+/// ## GD Internals:
+/// Object ID `1814`
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "serde_support", derive(Serialize, Deserialize))]
+pub struct FollowPlayerYTriggerData {
+    /// Key `51`
+    pub target_group: u16,
+
+    /// Key `90`
+    pub speed: f32,
+
+    /// Key `91`
+    pub delay: f32,
+
+    /// Key `92`
+    pub offset: f32,
+
+    /// Key `105`
+    pub max_speed: f32,
+
+    /// Key `10`
+    pub duration: f32,
+
+    pub flags: TriggerFlags,
+}
+
+/// Shake trigger, shaking the camera
+///
+/// ## GD Internals:
+/// Object ID `1520`
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "serde_support", derive(Serialize, Deserialize))]
+pub struct ShakeTriggerData {
+    /// Key `75`
+    pub strength: f32,
+
+    /// Key `84`
+    pub interval: f32,
+
+    /// Key `10`
+    pub duration: f32,
+
+    pub flags: TriggerFlags,
+}
+
+/// Animate trigger
+///
+/// ## GD Internals:
+/// Object ID `1585`
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+#[cfg_attr(feature = "serde_support", derive(Serialize, Deserialize))]
+pub struct AnimateTriggerData {
+    /// Key `51`
+    pub target_group: u16,
+
+    /// Key `76`
+    pub animation_id: u16,
+
+    pub flags: TriggerFlags,
+}
+
+/// Touch trigger
+///
+/// ## GD Internals:
+/// Object ID `1595`
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+#[cfg_attr(feature = "serde_support", derive(Serialize, Deserialize))]
+pub struct TouchTriggerData {
+    /// Key `51`
+    pub target_group: u16,
+
+    /// Whether the target group only stays toggled while the player holds (key `81`)
+    pub hold_mode: bool,
+
+    /// Key `82`
+    pub toggle_mode: ToggleMode,
+
+    /// Whether only touches on the player two side of the screen count in dual mode (key `89`)
+    pub dual_mode: bool,
+
+    pub flags: TriggerFlags,
+}
+
+/// Count trigger
+///
+/// ## GD Internals:
+/// Object ID `1611`
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+#[cfg_attr(feature = "serde_support", derive(Serialize, Deserialize))]
+pub struct CountTriggerData {
+    /// Key `80`
+    pub item_id: u16,
+
+    /// Key `77`
+    pub target_count: i32,
+
+    /// Key `51`
+    pub target_group: u16,
+
+    /// Key `56`
+    pub activate_group: bool,
+
+    /// Whether the trigger activates every time the count is reached (key `104`)
+    pub multi_activate: bool,
+
+    pub flags: TriggerFlags,
+}
+
+/// Instant count trigger
+///
+/// ## GD Internals:
+/// Object ID `1811`
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+#[cfg_attr(feature = "serde_support", derive(Serialize, Deserialize))]
+pub struct InstantCountTriggerData {
+    /// Key `80`
+    pub item_id: u16,
+
+    /// Key `77`
+    pub target_count: i32,
+
+    /// Key `51`
+    pub target_group: u16,
+
+    /// Key `56`
+    pub activate_group: bool,
+
+    /// Key `88`
+    pub comparison: Comparison,
+
+    pub flags: TriggerFlags,
+}
+
+/// Pickup trigger
+///
+/// ## GD Internals:
+/// Object ID `1817`
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+#[cfg_attr(feature = "serde_support", derive(Serialize, Deserialize))]
+pub struct PickupTriggerData {
+    /// Key `80`
+    pub item_id: u16,
+
+    /// The amount to add to the item (key `77`)
+    pub count: i32,
+
+    pub flags: TriggerFlags,
+}
+
+/// Collision trigger
+///
+/// ## GD Internals:
+/// Object ID `1815`
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+#[cfg_attr(feature = "serde_support", derive(Serialize, Deserialize))]
+pub struct CollisionTriggerData {
+    /// Key `80`
+    pub block_a: u16,
+
+    /// Key `95`
+    pub block_b: u16,
+
+    /// Key `51`
+    pub target_group: u16,
+
+    /// Key `56`
+    pub activate_group: bool,
+
+    /// Whether the trigger activates when the blocks stop colliding (key `93`)
+    pub trigger_on_exit: bool,
+
+    pub flags: TriggerFlags,
+}
+
+/// On death trigger
+///
+/// ## GD Internals:
+/// Object ID `1812`
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+#[cfg_attr(feature = "serde_support", derive(Serialize, Deserialize))]
+pub struct OnDeathTriggerData {
+    /// Key `51`
+    pub target_group: u16,
+
+    /// Key `56`
+    pub activate_group: bool,
+
+    pub flags: TriggerFlags,
 }
@@ -0,0 +1,151 @@
+//! Module containing models for levels stored locally in the game's save files
+
+use crate::{
+    level::{data, LevelLength},
+    song::MainSong,
+};
+use std::fmt::{Display, Error, Formatter};
+
+#[cfg(feature = "serde_support")]
+use serde_derive::Serialize;
+
+/// Struct representing a level from the "created levels" section of the game, as stored in the
+/// `CCLocalLevels.dat` save file.
+///
+/// The fields mirror the ones of [`PartialLevel`](crate::level::PartialLevel) and
+/// [`Level`](crate::level::Level) where the save file provides the same information, and
+/// additionally contain some metadata the level editor keeps track of.
+///
+/// ## GD Internals:
+/// Each local level is stored as a dictionary inside the `LLM_01` dictionary of the save file. The
+/// game omits every key whose value is the default value of its type (`0`, `false` or the empty
+/// string).
+#[derive(Debug, Eq, PartialEq, Clone)]
+#[cfg_attr(feature = "serde_support", derive(Serialize))]
+pub struct LocalLevel {
+    /// The ID the level got assigned when it was uploaded, or `0` if it was never uploaded
+    ///
+    /// ## GD Internals:
+    /// This value is provided at key `k1`
+    pub level_id: u64,
+
+    /// The level's name
+    ///
+    /// ## GD Internals:
+    /// This value is provided at key `k2`
+    pub name: String,
+
+    /// The level's description, or [`None`] if the creator didn't set one
+    ///
+    /// ## GD Internals:
+    /// This value is provided at key `k3` and encoded using urlsafe base 64.
+    pub description: Option<String>,
+
+    /// The raw level data, base64 decoded, but still compressed. See [`Level::level_data`]
+    ///
+    /// This is empty for levels that have never been opened in the editor.
+    ///
+    /// ## GD Internals:
+    /// This value is provided at key `k4`, and is urlsafe base64 encoded and `DEFLATE` compressed
+    ///
+    /// [`Level::level_data`]: crate::level::Level::level_data
+    #[cfg_attr(feature = "serde_support", serde(skip_serializing))]
+    pub level_data: Vec<u8>,
+
+    /// The name of the level's creator
+    ///
+    /// ## GD Internals:
+    /// This value is provided at key `k5`
+    pub creator_name: String,
+
+    /// The user ID of the level's creator, or `0` if the creator wasn't logged in when creating
+    /// the level
+    ///
+    /// ## GD Internals:
+    /// This value is provided at key `k6`
+    pub creator: u64,
+
+    /// The [`MainSong`] the level uses, if any.
+    ///
+    /// ## GD Internals:
+    /// This value is provided at key `k8`. Just like with
+    /// [`PartialLevel::main_song`](crate::level::PartialLevel::main_song), it is only meaningful
+    /// if no custom song is set.
+    #[cfg_attr(feature = "serde_support", serde(skip_serializing))]
+    pub main_song: Option<&'static MainSong>,
+
+    /// Whether the level has been verified in its current state
+    ///
+    /// ## GD Internals:
+    /// This value is provided at key `k14`
+    pub verified: bool,
+
+    /// The level's version. It is incremented every time the level is uploaded.
+    ///
+    /// ## GD Internals:
+    /// This value is provided at key `k16`
+    pub version: u32,
+
+    /// The amount of times the level has been play tested
+    ///
+    /// ## GD Internals:
+    /// This value is provided at key `k18`
+    pub attempts: u32,
+
+    /// The level's length, as computed by the editor when the level was last saved
+    ///
+    /// ## GD Internals:
+    /// This value is provided as an integer representation of the [`LevelLength`] enum at key
+    /// `k23`
+    pub length: LevelLength,
+
+    /// The ID of the level this level is a copy of, or [`None`], if it isn't a copy
+    ///
+    /// ## GD Internals:
+    /// This value is provided at key `k42`
+    pub copy_of: Option<u64>,
+
+    /// Whether the level uses two player mode
+    ///
+    /// ## GD Internals:
+    /// This value is provided at key `k43`
+    pub two_player: bool,
+
+    /// The ID of the custom song used, if any
+    ///
+    /// ## GD Internals:
+    /// This value is provided at key `k45`
+    pub custom_song: Option<u64>,
+
+    /// The amount of objects in the level, as counted by the editor
+    ///
+    /// ## GD Internals:
+    /// This value is provided at key `k48`
+    pub object_amount: u32,
+
+    /// The time, in seconds, that has been spent in the editor working on this level
+    ///
+    /// ## GD Internals:
+    /// This value is provided at key `k80`
+    pub editor_time: u64,
+}
+
+impl LocalLevel {
+    /// Decompresses this [`LocalLevel`]'s level data. See
+    /// [`Level::decompress_data`](crate::level::Level::decompress_data)
+    pub fn decompress_data(&self) -> std::io::Result<String> {
+        data::decompress(&self.level_data)
+    }
+
+    /// Compresses the given level string and stores it as this [`LocalLevel`]'s data. See
+    /// [`Level::compress_data`](crate::level::Level::compress_data)
+    pub fn compress_data(&mut self, level_string: &str) {
+        self.level_data = data::compress(level_string)
+    }
+}
+
+impl Display for LocalLevel {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), Error> {
+        write!(f, "LocalLevel({})", self.name)
+    }
+}
@@ -3,6 +3,7 @@
 
 pub mod comment;
 pub mod level;
+pub mod message;
 pub mod song;
 pub mod user;
 
@@ -29,8 +30,10 @@ pub enum GameVersion {
     Version { minor: u8, major: u8 },
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde_support", derive(Serialize, Deserialize))]
 pub enum GameMode {
+    #[default]
     Cube,
     Ship,
     Ball,
@@ -0,0 +1,88 @@
+//! Module containing all models related to private messages
+
+#[cfg(feature = "serde_support")]
+use serde_derive::{Deserialize, Serialize};
+use std::fmt::{Display, Error, Formatter};
+
+/// Struct representing a private message as it is provided in the list of messages in a player's
+/// inbox (or sent messages). These are missing the actual content of the message.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde_support", derive(Serialize, Deserialize))]
+pub struct PartialMessage {
+    /// The unique id of this [`PartialMessage`]
+    ///
+    /// ## GD Internals
+    /// This value is provided at index `1`
+    pub message_id: u64,
+
+    /// The account ID of the other party of this message, meaning the sender for received messages
+    /// and the recipient for sent messages
+    ///
+    /// ## GD Internals
+    /// This value is provided at index `2`
+    pub account_id: u64,
+
+    /// The user ID of the other party of this message
+    ///
+    /// ## GD Internals
+    /// This value is provided at index `3`
+    pub user_id: u64,
+
+    /// The subject line of this message
+    ///
+    /// ## GD Internals
+    /// This value is provided at index `4`, encoded using urlsafe base64
+    pub subject: String,
+
+    /// The name of the other party of this message
+    ///
+    /// ## GD Internals
+    /// This value is provided at index `6`
+    pub username: String,
+
+    /// Robtop's completely braindead way of keeping track of when this message was sent
+    ///
+    /// ## GD Internals
+    /// This value is provided at index `7`
+    pub time_since_sent: String,
+
+    /// Whether this message has been read by its recipient
+    ///
+    /// ## GD Internals
+    /// This value is provided at index `8`
+    pub read: bool,
+
+    /// Whether this message was sent by the player that retrieved it (as opposed to having been
+    /// received by them)
+    ///
+    /// ## GD Internals
+    /// This value is provided at index `9`
+    pub sent: bool,
+}
+
+/// Struct representing a private message, including its content
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde_support", derive(Serialize, Deserialize))]
+pub struct Message {
+    /// The [`PartialMessage`] this [`Message`] is an extended version of
+    pub base: PartialMessage,
+
+    /// The content of this message
+    ///
+    /// ## GD Internals
+    /// This value is provided at index `5`, encoded using robtop's XOR cipher (with key `14251`)
+    /// and urlsafe base64
+    pub body: String,
+}
+
+impl Display for PartialMessage {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), Error> {
+        write!(f, "PartialMessage({}, {})", self.message_id, self.subject)
+    }
+}
+
+impl Display for Message {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), Error> {
+        write!(f, "Message({}, {})", self.base.message_id, self.base.subject)
+    }
+}
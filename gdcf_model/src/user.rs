@@ -1,5 +1,7 @@
 //! Module containing all models related to users and their profiles
 
+pub mod local;
+
 use std::fmt::{Display, Error, Formatter};
 
 use crate::GameMode;
@@ -291,6 +293,7 @@ impl Display for User {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde_support", derive(Serialize, Deserialize))]
 pub struct SearchedUser {
     /// This [`SearchedUser`]'s name
     ///
@@ -386,6 +389,211 @@ impl Display for SearchedUser {
     }
 }
 
+/// Struct representing a user as they appear in a player's friend list or list of blocked users.
+///
+/// ## GD Internals:
+/// These minimal representations of a [`User`] are provided by the Geometry Dash servers in a
+/// `getGJUserList20` response. Unlike [`SearchedUser`]s, they do not contain any stats.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde_support", derive(Serialize, Deserialize))]
+pub struct ListedUser {
+    /// This [`ListedUser`]'s name
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `1`
+    pub name: String,
+
+    /// This [`ListedUser`]'s unique user ID
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `2`
+    pub user_id: u64,
+
+    /// The index of the icon being displayed.
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `9`
+    pub icon_index: u16,
+
+    /// This [`ListedUser`]'s primary color
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `10`.
+    pub primary_color: Color,
+
+    /// This [`ListedUser`]'s secondary color
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `11`.
+    pub secondary_color: Color,
+
+    /// The type of icon being displayed
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `14`
+    pub icon_type: GameMode,
+
+    /// Values indicating whether this [`ListedUser`] has glow activated or not.
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `15`
+    pub has_glow: bool,
+
+    /// The [`ListedUser`]'s unique account ID
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `16`
+    pub account_id: u64,
+}
+
+impl Display for ListedUser {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), Error> {
+        write!(f, "ListedUser({}, {})", self.user_id, self.name)
+    }
+}
+
+/// Struct representing a friend request, as they are provided by the Geometry Dash servers in a
+/// `getGJFriendRequests20` response.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde_support", derive(Serialize, Deserialize))]
+pub struct FriendRequest {
+    /// The other party of this friend request, meaning the sender for received friend requests and
+    /// the recipient for sent ones
+    pub user: ListedUser,
+
+    /// The unique ID of this [`FriendRequest`]
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `32`
+    pub request_id: u64,
+
+    /// The message attached to this [`FriendRequest`], if any
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `35`, encoded using urlsafe base64
+    pub message: Option<String>,
+
+    /// Robtop's completely braindead way of keeping track of when this friend request was sent
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `37`
+    pub time_since_sent: String,
+
+    /// Whether this friend request hasn't been looked at by its recipient yet
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `41`
+    pub unread: bool,
+}
+
+impl Display for FriendRequest {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), Error> {
+        write!(f, "FriendRequest({}, {})", self.request_id, self.user.name)
+    }
+}
+
+/// Struct modelling a single entry of one of the in-game leaderboards
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde_support", derive(Serialize, Deserialize))]
+pub struct LeaderboardEntry {
+    /// This [`LeaderboardEntry`]'s placement on the leaderboard it was retrieved from
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `6`
+    pub rank: u32,
+
+    /// The name of the player this [`LeaderboardEntry`] is about
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `1`
+    pub name: String,
+
+    /// The player's unique user ID
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `2`
+    pub user_id: u64,
+
+    /// The player's stars
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `3`
+    pub stars: u32,
+
+    /// The player's beaten demons
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `4`
+    pub demons: u16,
+
+    /// The player's creator points
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `8`
+    pub creator_points: u16,
+
+    /// The index of the icon being displayed.
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `9`
+    pub icon_index: u16,
+
+    /// The player's primary color
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `10`. See [`SearchedUser::primary_color`] for how the
+    /// value is converted
+    pub primary_color: Color,
+
+    /// The player's secondary color
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `11`. Same things as above apply
+    pub secondary_color: Color,
+
+    /// The amount of secret coins the player has collected.
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `13`
+    pub secret_coins: u8,
+
+    /// The type of icon being displayed
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `14`
+    pub icon_type: GameMode,
+
+    /// Values indicating whether the player has glow activated or not.
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `15`
+    pub has_glow: bool,
+
+    /// The player's unique account ID
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `16`
+    pub account_id: u64,
+
+    /// The amount of user coins the player has collected.
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `17`
+    pub user_coins: u16,
+
+    /// The amount of diamonds the player has collected.
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `46`
+    pub diamonds: u16,
+}
+
+impl Display for LeaderboardEntry {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), Error> {
+        write!(f, "LeaderboardEntry(#{}, {}, {})", self.rank, self.user_id, self.name)
+    }
+}
+
 impl Into<u8> for ModLevel {
     fn into(self) -> u8 {
         match self {
@@ -0,0 +1,302 @@
+//! Module containing models for the player data stored locally in the game's save files
+
+use crate::{user::Color, GameMode};
+use std::fmt::{Display, Error, Formatter};
+
+#[cfg(feature = "serde_support")]
+use serde_derive::{Deserialize, Serialize};
+
+/// Struct representing the content of a `CCGameManager.dat` save file relevant to the player's
+/// profile
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde_support", derive(Serialize, Deserialize))]
+pub struct LocalProfile {
+    /// The player and their current icon selection
+    pub player: LocalPlayer,
+
+    /// The player's statistics
+    ///
+    /// ## GD Internals:
+    /// These values are provided in the `GS_value` dictionary
+    pub stats: PlayerStats,
+
+    /// The IDs of all online levels the player has completed
+    ///
+    /// ## GD Internals:
+    /// These values are provided in the `GS_completed` dictionary, as keys of the form `c_{id}`
+    pub completed_levels: Vec<u64>,
+
+    /// The IDs of all official levels the player has completed
+    ///
+    /// ## GD Internals:
+    /// These values are provided in the `GS_completed` dictionary, as keys of the form `n_{id}`
+    pub completed_main_levels: Vec<u64>,
+
+    /// All icons and colors the player has unlocked
+    ///
+    /// ## GD Internals:
+    /// These values are provided in the `valueKeeper` dictionary, as keys of the form
+    /// `{type}_{index}` with value `1`
+    pub unlocked: Vec<Unlockable>,
+}
+
+/// Struct representing the player of a local save file. The icon fields use the same indices as
+/// the corresponding fields of [`User`](crate::user::User)
+///
+/// ## GD Internals:
+/// These values are provided in the root dictionary of `CCGameManager.dat`. Just like in
+/// `CCLocalLevels.dat`, keys whose value is the default one are omitted.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde_support", derive(Serialize, Deserialize))]
+pub struct LocalPlayer {
+    /// The player's name
+    ///
+    /// ## GD Internals:
+    /// This value is provided at key `playerName`
+    pub name: String,
+
+    /// The player's user ID
+    ///
+    /// ## GD Internals:
+    /// This value is provided at key `playerUserID`
+    pub user_id: u64,
+
+    /// The ID of the account the player is logged into, if any
+    ///
+    /// ## GD Internals:
+    /// This value is provided at key `GJA_003`
+    pub account_id: Option<u64>,
+
+    /// The 1-based index of the cube the player currently uses
+    ///
+    /// ## GD Internals:
+    /// This value is provided at key `playerFrame`
+    pub cube_index: u16,
+
+    /// The 1-based index of the ship the player currently uses
+    ///
+    /// ## GD Internals:
+    /// This value is provided at key `playerShip`
+    pub ship_index: u8,
+
+    /// The 1-based index of the ball the player currently uses
+    ///
+    /// ## GD Internals:
+    /// This value is provided at key `playerBall`
+    pub ball_index: u8,
+
+    /// The 1-based index of the UFO the player currently uses
+    ///
+    /// ## GD Internals:
+    /// This value is provided at key `playerBird`
+    pub ufo_index: u8,
+
+    /// The 1-based index of the wave the player currently uses
+    ///
+    /// ## GD Internals:
+    /// This value is provided at key `playerDart`
+    pub wave_index: u8,
+
+    /// The 1-based index of the robot the player currently uses
+    ///
+    /// ## GD Internals:
+    /// This value is provided at key `playerRobot`
+    pub robot_index: u8,
+
+    /// The 1-based index of the spider the player currently uses
+    ///
+    /// ## GD Internals:
+    /// This value is provided at key `playerSpider`
+    pub spider_index: u8,
+
+    /// The 1-based index of the trail the player currently uses
+    ///
+    /// ## GD Internals:
+    /// This value is provided at key `playerStreak`
+    pub trail_index: u8,
+
+    /// The 1-based index of the death effect the player currently uses
+    ///
+    /// ## GD Internals:
+    /// This value is provided at key `playerDeathEffect`
+    pub death_effect_index: u8,
+
+    /// The player's primary color
+    ///
+    /// ## GD Internals:
+    /// This value is provided at key `playerColor`
+    pub primary_color: Color,
+
+    /// The player's secondary color
+    ///
+    /// ## GD Internals:
+    /// This value is provided at key `playerColor2`
+    pub secondary_color: Color,
+
+    /// Whether the player has glow activated
+    ///
+    /// ## GD Internals:
+    /// This value is provided at key `playerGlow`
+    pub has_glow: bool,
+
+    /// The game mode whose icon is displayed for the player (e.g. on leaderboards)
+    ///
+    /// ## GD Internals:
+    /// This value is provided at key `playerIconType`
+    pub icon_type: GameMode,
+}
+
+/// Struct containing the statistics the game tracks for the player. Fields also present on
+/// [`User`](crate::user::User) have the same names and types
+///
+/// ## GD Internals:
+/// These values are provided in the `GS_value` dictionary of `CCGameManager.dat`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde_support", derive(Serialize, Deserialize))]
+pub struct PlayerStats {
+    /// The total amount of jumps
+    ///
+    /// ## GD Internals:
+    /// This value is provided at key `1`
+    pub jumps: u32,
+
+    /// The total amount of attempts
+    ///
+    /// ## GD Internals:
+    /// This value is provided at key `2`
+    pub attempts: u32,
+
+    /// The amount of official levels completed
+    ///
+    /// ## GD Internals:
+    /// This value is provided at key `3`
+    pub completed_main_levels: u32,
+
+    /// The amount of online levels completed
+    ///
+    /// ## GD Internals:
+    /// This value is provided at key `4`
+    pub completed_online_levels: u32,
+
+    /// The amount of demons beaten
+    ///
+    /// ## GD Internals:
+    /// This value is provided at key `5`
+    pub demons: u16,
+
+    /// The amount of stars collected
+    ///
+    /// ## GD Internals:
+    /// This value is provided at key `6`
+    pub stars: u32,
+
+    /// The amount of map packs completed
+    ///
+    /// ## GD Internals:
+    /// This value is provided at key `7`
+    pub map_packs: u32,
+
+    /// The amount of secret coins collected
+    ///
+    /// ## GD Internals:
+    /// This value is provided at key `8`
+    pub secret_coins: u8,
+
+    /// The amount of other players destroyed (on the main menu)
+    ///
+    /// ## GD Internals:
+    /// This value is provided at key `9`
+    pub destroyed_players: u32,
+
+    /// The amount of levels liked or disliked
+    ///
+    /// ## GD Internals:
+    /// This value is provided at key `10`
+    pub liked_levels: u32,
+
+    /// The amount of levels rated
+    ///
+    /// ## GD Internals:
+    /// This value is provided at key `11`
+    pub rated_levels: u32,
+
+    /// The amount of user coins collected
+    ///
+    /// ## GD Internals:
+    /// This value is provided at key `12`
+    pub user_coins: u16,
+
+    /// The amount of diamonds collected
+    ///
+    /// ## GD Internals:
+    /// This value is provided at key `13`
+    pub diamonds: u16,
+
+    /// The amount of mana orbs collected
+    ///
+    /// ## GD Internals:
+    /// This value is provided at key `14`
+    pub orbs: u32,
+}
+
+/// Enum representing an icon or color that can be unlocked. Icons are identified by their
+/// 1-based index, colors by their color index
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde_support", derive(Serialize, Deserialize))]
+pub enum Unlockable {
+    /// ## GD Internals:
+    /// This variant is represented by the key prefix `i`
+    Cube(u16),
+
+    /// ## GD Internals:
+    /// This variant is represented by the key prefix `ship`
+    Ship(u8),
+
+    /// ## GD Internals:
+    /// This variant is represented by the key prefix `ball`
+    Ball(u8),
+
+    /// ## GD Internals:
+    /// This variant is represented by the key prefix `bird`
+    Ufo(u8),
+
+    /// ## GD Internals:
+    /// This variant is represented by the key prefix `dart`
+    Wave(u8),
+
+    /// ## GD Internals:
+    /// This variant is represented by the key prefix `robot`
+    Robot(u8),
+
+    /// ## GD Internals:
+    /// This variant is represented by the key prefix `spider`
+    Spider(u8),
+
+    /// ## GD Internals:
+    /// This variant is represented by the key prefix `special`
+    Trail(u8),
+
+    /// ## GD Internals:
+    /// This variant is represented by the key prefix `death`
+    DeathEffect(u8),
+
+    /// ## GD Internals:
+    /// This variant is represented by the key prefix `c0`
+    PrimaryColor(u8),
+
+    /// ## GD Internals:
+    /// This variant is represented by the key prefix `c1`
+    SecondaryColor(u8),
+}
+
+impl Display for LocalPlayer {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), Error> {
+        write!(f, "LocalPlayer({}, {})", self.name, self.user_id)
+    }
+}
+
+impl Display for LocalProfile {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), Error> {
+        write!(f, "LocalProfile({}, {})", self.player.name, self.player.user_id)
+    }
+}
@@ -0,0 +1,87 @@
+use gdcf_model::{
+    level::data::{
+        portal::{PortalType, Speed},
+        timeline::Timeline,
+        LevelMetadata,
+    },
+    GameMode,
+};
+
+fn normal_speed() -> f32 {
+    Speed::Normal.into()
+}
+
+#[test]
+fn level_without_portals() {
+    let timeline = Timeline::compute(&LevelMetadata::default(), Vec::new(), normal_speed() * 10.0);
+
+    assert_eq!(timeline.segments.len(), 1);
+    assert!(timeline.portal_hits.is_empty());
+    assert!((timeline.duration() - 10.0).abs() < 1e-4);
+    assert_eq!(timeline.game_mode_share(GameMode::Cube), 1.0);
+}
+
+#[test]
+fn segments_follow_sorted_portals() {
+    let portals = vec![
+        (normal_speed() * 6.0, PortalType::GameMode(GameMode::Cube)),
+        (normal_speed() * 2.0, PortalType::GameMode(GameMode::Ship)),
+        // Doesn't change the player's state, so it doesn't start a new segment
+        (normal_speed() * 3.0, PortalType::GameMode(GameMode::Ship)),
+        // Doesn't affect the player's movement at all
+        (normal_speed() * 4.0, PortalType::Mirror { mirrored: true }),
+    ];
+
+    let timeline = Timeline::compute(&LevelMetadata::default(), portals, normal_speed() * 10.0);
+
+    let modes: Vec<_> = timeline.segments.iter().map(|segment| segment.state.game_mode).collect();
+
+    assert_eq!(modes, vec![GameMode::Cube, GameMode::Ship, GameMode::Cube]);
+    assert_eq!(timeline.portal_hits.len(), 3);
+    assert!((timeline.segments[1].start_time - 2.0).abs() < 1e-4);
+    assert!((timeline.game_mode_share(GameMode::Ship) - 0.4).abs() < 1e-4);
+}
+
+#[test]
+fn speed_portals_are_timed() {
+    let fast: f32 = Speed::Fast.into();
+    let portals = vec![(normal_speed() * 2.0, PortalType::Speed(Speed::Fast))];
+
+    let timeline = Timeline::compute(&LevelMetadata::default(), portals, normal_speed() * 2.0 + fast * 3.0);
+
+    assert_eq!(timeline.speed_portals().collect::<Vec<_>>().len(), 1);
+    assert!((timeline.speed_portals().next().unwrap().0 - 2.0).abs() < 1e-4);
+    assert!((timeline.duration() - 5.0).abs() < 1e-4);
+    assert!((timeline.speed_share(Speed::Fast) - 0.6).abs() < 1e-4);
+}
+
+#[test]
+fn absent_states_have_positive_zero_share() {
+    let timeline = Timeline::compute(&LevelMetadata::default(), Vec::new(), 1000.0);
+    let share = timeline.game_mode_share(GameMode::Wave);
+
+    assert_eq!(share, 0.0);
+    assert!(share.is_sign_positive());
+
+    let empty = Timeline::compute(&LevelMetadata::default(), Vec::new(), 0.0);
+
+    assert!(empty.duration().is_sign_positive());
+    assert!(empty.game_mode_share(GameMode::Cube).is_sign_positive());
+}
+
+#[test]
+fn non_finite_positions_are_ignored() {
+    let portals = vec![
+        (f32::NAN, PortalType::GameMode(GameMode::Ship)),
+        (f32::INFINITY, PortalType::GameMode(GameMode::Wave)),
+        (500.0, PortalType::GameMode(GameMode::Ball)),
+    ];
+
+    let timeline = Timeline::compute(&LevelMetadata::default(), portals, f32::NAN);
+
+    assert_eq!(timeline.portal_hits.len(), 1);
+    assert!(timeline.duration().is_finite());
+    assert!(!timeline.game_mode_share(GameMode::Cube).is_nan());
+    assert_eq!(timeline.game_mode_share(GameMode::Ship), 0.0);
+    assert_eq!(timeline.game_mode_share(GameMode::Cube), 1.0);
+}
@@ -1,6 +1,12 @@
 use crate::util::{self, b64_decode_string, xor_decrypt};
 use gdcf_model::{
-    level::{data::portal::Speed, DemonRating, Featured, LevelLength, LevelRating, Password},
+    level::{
+        data::{
+            portal::Speed,
+            trigger::{Comparison, Easing, Hsv, ToggleMode},
+        },
+        DemonRating, Featured, LevelLength, LevelRating, Password,
+    },
     user::{Color, ModLevel},
     GameMode, GameVersion,
 };
@@ -416,6 +422,149 @@ impl RobtopInto<Speed, String> for Speed {
     }
 }
 
+impl RobtopFrom<Easing, &str> for Easing {
+    fn robtop_from(t: &str) -> Result<Easing, String> {
+        Ok(match t {
+            "0" => Easing::None,
+            "1" => Easing::EaseInOut,
+            "2" => Easing::EaseIn,
+            "3" => Easing::EaseOut,
+            "4" => Easing::ElasticInOut,
+            "5" => Easing::ElasticIn,
+            "6" => Easing::ElasticOut,
+            "7" => Easing::BounceInOut,
+            "8" => Easing::BounceIn,
+            "9" => Easing::BounceOut,
+            "10" => Easing::ExponentialInOut,
+            "11" => Easing::ExponentialIn,
+            "12" => Easing::ExponentialOut,
+            "13" => Easing::SineInOut,
+            "14" => Easing::SineIn,
+            "15" => Easing::SineOut,
+            "16" => Easing::BackInOut,
+            "17" => Easing::BackIn,
+            "18" => Easing::BackOut,
+            t => Easing::Unknown(u8::robtop_from(t)?),
+        })
+    }
+}
+
+impl RobtopInto<Easing, String> for Easing {
+    fn robtop_into(self) -> String {
+        match self {
+            Easing::None => "0".to_string(),
+            Easing::EaseInOut => "1".to_string(),
+            Easing::EaseIn => "2".to_string(),
+            Easing::EaseOut => "3".to_string(),
+            Easing::ElasticInOut => "4".to_string(),
+            Easing::ElasticIn => "5".to_string(),
+            Easing::ElasticOut => "6".to_string(),
+            Easing::BounceInOut => "7".to_string(),
+            Easing::BounceIn => "8".to_string(),
+            Easing::BounceOut => "9".to_string(),
+            Easing::ExponentialInOut => "10".to_string(),
+            Easing::ExponentialIn => "11".to_string(),
+            Easing::ExponentialOut => "12".to_string(),
+            Easing::SineInOut => "13".to_string(),
+            Easing::SineIn => "14".to_string(),
+            Easing::SineOut => "15".to_string(),
+            Easing::BackInOut => "16".to_string(),
+            Easing::BackIn => "17".to_string(),
+            Easing::BackOut => "18".to_string(),
+            Easing::Unknown(value) => value.to_string(),
+        }
+    }
+
+    fn can_omit(&self) -> bool {
+        *self == Easing::None
+    }
+}
+
+impl RobtopFrom<ToggleMode, &str> for ToggleMode {
+    fn robtop_from(t: &str) -> Result<ToggleMode, String> {
+        Ok(match t {
+            "0" => ToggleMode::Toggle,
+            "1" => ToggleMode::On,
+            "2" => ToggleMode::Off,
+            t => ToggleMode::Unknown(u8::robtop_from(t)?),
+        })
+    }
+}
+
+impl RobtopInto<ToggleMode, String> for ToggleMode {
+    fn robtop_into(self) -> String {
+        match self {
+            ToggleMode::Toggle => "0".to_string(),
+            ToggleMode::On => "1".to_string(),
+            ToggleMode::Off => "2".to_string(),
+            ToggleMode::Unknown(value) => value.to_string(),
+        }
+    }
+
+    fn can_omit(&self) -> bool {
+        *self == ToggleMode::Toggle
+    }
+}
+
+impl RobtopFrom<Comparison, &str> for Comparison {
+    fn robtop_from(t: &str) -> Result<Comparison, String> {
+        Ok(match t {
+            "0" => Comparison::Equals,
+            "1" => Comparison::Larger,
+            "2" => Comparison::Smaller,
+            t => Comparison::Unknown(u8::robtop_from(t)?),
+        })
+    }
+}
+
+impl RobtopInto<Comparison, String> for Comparison {
+    fn robtop_into(self) -> String {
+        match self {
+            Comparison::Equals => "0".to_string(),
+            Comparison::Larger => "1".to_string(),
+            Comparison::Smaller => "2".to_string(),
+            Comparison::Unknown(value) => value.to_string(),
+        }
+    }
+
+    fn can_omit(&self) -> bool {
+        *self == Comparison::Equals
+    }
+}
+
+impl RobtopFrom<Hsv, &str> for Hsv {
+    fn robtop_from(t: &str) -> Result<Hsv, String> {
+        let mut split = t.split('a');
+
+        if let (Some(hue), Some(saturation), Some(brightness), Some(saturation_additive), Some(brightness_additive)) =
+            (split.next(), split.next(), split.next(), split.next(), split.next())
+        {
+            Ok(Hsv {
+                hue: i16::robtop_from(hue)?,
+                saturation: f32::robtop_from(saturation)?,
+                brightness: f32::robtop_from(brightness)?,
+                saturation_additive: bool::robtop_from(saturation_additive)?,
+                brightness_additive: bool::robtop_from(brightness_additive)?,
+            })
+        } else {
+            Err(format!("Malformed HSV string {}", t))
+        }
+    }
+}
+
+impl RobtopInto<Hsv, String> for Hsv {
+    fn robtop_into(self) -> String {
+        format!(
+            "{}a{}a{}a{}a{}",
+            self.hue, self.saturation, self.brightness, self.saturation_additive as u8, self.brightness_additive as u8
+        )
+    }
+
+    fn can_omit(&self) -> bool {
+        *self == Hsv::default()
+    }
+}
+
 impl RobtopInto<ModLevel, String> for ModLevel {
     fn robtop_into(self) -> String {
         match self {
@@ -540,6 +689,66 @@ impl RobtopFrom<Color, &str> for RGBColor {
     }
 }
 
+pub struct LevelIdList;
+
+impl RobtopFrom<Vec<u64>, &str> for LevelIdList {
+    fn robtop_from(ids: &str) -> Result<Vec<u64>, String> {
+        ids.split(',')
+            .filter(|id| !id.is_empty())
+            .map(|id| id.parse().map_err(|e: ParseIntError| e.to_string()))
+            .collect()
+    }
+}
+
+impl RobtopInto<LevelIdList, String> for Vec<u64> {
+    fn robtop_into(self) -> String {
+        self.iter().map(ToString::to_string).collect::<Vec<_>>().join(",")
+    }
+}
+
+pub struct MapPackDifficulty;
+
+impl RobtopFrom<LevelRating, &str> for MapPackDifficulty {
+    fn robtop_from(t: &str) -> Result<LevelRating, String> {
+        Ok(match t {
+            "0" => LevelRating::Auto,
+            "1" => LevelRating::Easy,
+            "2" => LevelRating::Normal,
+            "3" => LevelRating::Hard,
+            "4" => LevelRating::Harder,
+            "5" => LevelRating::Insane,
+            "6" => LevelRating::Demon(DemonRating::Hard),
+            "7" => LevelRating::Demon(DemonRating::Easy),
+            "8" => LevelRating::Demon(DemonRating::Medium),
+            "9" => LevelRating::Demon(DemonRating::Insane),
+            "10" => LevelRating::Demon(DemonRating::Extreme),
+            t => LevelRating::Unknown(i32::robtop_from(t)?),
+        })
+    }
+}
+
+impl RobtopInto<MapPackDifficulty, String> for LevelRating {
+    fn robtop_into(self) -> String {
+        match self {
+            LevelRating::Auto => "0".to_string(),
+            LevelRating::Easy => "1".to_string(),
+            LevelRating::Normal => "2".to_string(),
+            LevelRating::Hard => "3".to_string(),
+            LevelRating::Harder => "4".to_string(),
+            LevelRating::Insane => "5".to_string(),
+            LevelRating::Demon(DemonRating::Hard) => "6".to_string(),
+            LevelRating::Demon(DemonRating::Easy) => "7".to_string(),
+            LevelRating::Demon(DemonRating::Medium) => "8".to_string(),
+            LevelRating::Demon(DemonRating::Insane) => "9".to_string(),
+            LevelRating::Demon(DemonRating::Extreme) => "10".to_string(),
+            LevelRating::Demon(DemonRating::Unknown(value)) | LevelRating::Unknown(value) => value.to_string(),
+            // Map packs always have a difficulty, so parsed map packs never end up here. We fall back
+            // to the lowest difficulty
+            LevelRating::NotAvailable => "0".to_string(),
+        }
+    }
+}
+
 pub struct Base64BytesConverter;
 
 impl RobtopFrom<Vec<u8>, &str> for Base64BytesConverter {
@@ -583,6 +792,24 @@ impl RobtopInto<Base64Converter, String> for String {
     }
 }
 
+/// Converter for the contents of private messages, which are XOR-encrypted with the key `14251`
+/// and then urlsafe base64 encoded
+pub struct MessageBody;
+
+impl RobtopFrom<String, &str> for MessageBody {
+    fn robtop_from(s: &str) -> Result<String, String> {
+        b64_decode_string(s)
+            .map(|decoded| xor_decrypt(&decoded, "14251"))
+            .map_err(|e| e.to_string())
+    }
+}
+
+impl RobtopInto<MessageBody, String> for String {
+    fn robtop_into(self) -> String {
+        base64::encode_config(&xor_decrypt(&self, "14251"), base64::URL_SAFE)
+    }
+}
+
 pub struct UrlConverter;
 
 impl RobtopFrom<String, &str> for UrlConverter {
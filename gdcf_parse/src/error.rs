@@ -14,3 +14,50 @@ impl std::fmt::Display for ValueError<'_> {
         }
     }
 }
+
+/// Error that can occur while reading one of the game's save files
+#[derive(Debug)]
+pub enum SavefileError {
+    /// The save file, after XOR decryption, wasn't valid urlsafe base64
+    Base64(base64::DecodeError),
+
+    /// The save file couldn't be decompressed
+    Decompression(std::io::Error),
+
+    /// The decompressed save file wasn't valid plist XML. Contains the byte offset at which parsing
+    /// failed and a description of what went wrong
+    Plist(usize, String),
+
+    /// A key required to be present in the save file was missing
+    MissingKey(&'static str),
+
+    /// An entry of the save file couldn't be parsed. Contains the key of the entry and the
+    /// description of the [`ValueError`] that occurred
+    Value(String, String),
+}
+
+impl std::error::Error for SavefileError {}
+
+impl std::fmt::Display for SavefileError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            SavefileError::Base64(err) => write!(f, "The save file isn't valid base64: {}", err),
+            SavefileError::Decompression(err) => write!(f, "The save file couldn't be decompressed: {}", err),
+            SavefileError::Plist(offset, cause) => write!(f, "Malformed plist at byte {}: {}", offset, cause),
+            SavefileError::MissingKey(key) => write!(f, "The save file is missing the key {}", key),
+            SavefileError::Value(key, cause) => write!(f, "The entry at key {} could not be parsed: {}", key, cause),
+        }
+    }
+}
+
+impl From<base64::DecodeError> for SavefileError {
+    fn from(err: base64::DecodeError) -> Self {
+        SavefileError::Base64(err)
+    }
+}
+
+impl From<std::io::Error> for SavefileError {
+    fn from(err: std::io::Error) -> Self {
+        SavefileError::Decompression(err)
+    }
+}
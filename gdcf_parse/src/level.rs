@@ -1,14 +1,15 @@
 use crate::{
-    convert::{Base64BytesConverter, Base64Converter, RobtopFrom, RobtopInto},
+    convert::{Base64BytesConverter, Base64Converter, LevelIdList, MapPackDifficulty, RGBColor, RobtopFrom, RobtopInto, TwoBool},
     error::ValueError,
     Parse,
 };
 use gdcf_model::{
-    level::{DemonRating, Level, LevelRating, PartialLevel},
+    level::{DailyLevel, DemonRating, Gauntlet, Level, LevelRating, LevelScore, MapPack, PartialLevel},
     song::{MainSong, MAIN_SONGS, UNKNOWN},
 };
 
 pub mod data;
+pub mod local;
 pub mod object;
 
 pub fn process_difficulty(rating: &str, is_auto: bool, is_demon: bool) -> LevelRating {
@@ -65,12 +66,12 @@ parser! {
     is_na(index = 8, ignore, extract = extract_is_na[difficulty]),
 }
 
-fn extract_main_song_id(main_song: Option<&'static MainSong>) -> String {
+pub(crate) fn extract_main_song_id(main_song: Option<&'static MainSong>) -> String {
     main_song.map(|s| s.main_song_id).unwrap_or_default().robtop_into()
 }
 
 fn extract_rating(rating: LevelRating) -> String {
-    rating.robtop_into()
+    RobtopInto::<LevelRating, String>::robtop_into(rating)
 }
 
 fn extract_is_demon(rating: LevelRating) -> String {
@@ -98,3 +99,71 @@ parser! {
         index_36(index = 36, default),
     }
 }
+
+parser! {
+    LevelScore => {
+        rank(index = 6),
+        name(index = 1),
+        user_id(index = 2),
+        percent(index = 3),
+        icon_index(index = 9),
+        primary_color(index = 10),
+        secondary_color(index = 11),
+        coins(index = 13),
+        icon_type(index = 14),
+        has_glow(index = 15, parse = TwoBool),
+        account_id(index = 16),
+        time_since_set(index = 42),
+    }
+}
+
+parser! {
+    MapPack => {
+        pack_id(index = 1),
+        name(index = 2),
+        levels(index = 3, parse = LevelIdList),
+        stars(index = 4),
+        coins(index = 5),
+        difficulty(index = 6, parse = MapPackDifficulty),
+        text_color(index = 7, parse = RGBColor),
+        bar_color(index = 8, parse = RGBColor),
+    }
+}
+
+parser! {
+    Gauntlet => {
+        gauntlet_id(index = 1),
+        levels(index = 3, parse = LevelIdList),
+    }
+}
+
+parser! {
+    DailyLevel => {
+        level(custom = dummy[]),
+        index(custom = process_daily_index[raw_index]),
+        weekly(custom = process_is_weekly[raw_index]),
+        seconds_left(index = 2),
+    },
+    raw_index(index = 1, extract = extract_raw_index[index, weekly]),
+}
+
+/// The offset the servers add to the index of weekly demons
+const WEEKLY_OFFSET: u32 = 100_000;
+
+fn dummy() {}
+
+fn process_daily_index(raw_index: u32) -> u32 {
+    raw_index % WEEKLY_OFFSET
+}
+
+fn process_is_weekly(raw_index: u32) -> bool {
+    raw_index >= WEEKLY_OFFSET
+}
+
+fn extract_raw_index(index: u32, weekly: bool) -> String {
+    if weekly {
+        (index + WEEKLY_OFFSET).to_string()
+    } else {
+        index.to_string()
+    }
+}
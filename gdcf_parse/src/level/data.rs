@@ -1,6 +1,7 @@
 use crate::{error::ValueError, Parse};
 use gdcf_model::level::data::{
     portal::{self, PortalData, PortalType},
+    timeline::Timeline,
     LevelInformationSource, LevelMetadata, LevelObject, ObjectData, Stats,
 };
 #[cfg(feature = "parallel")]
@@ -24,7 +25,8 @@ pub fn parse_lazy<'a>(level_string: &'a str) -> Result<IterSource<impl Iterator<
         Some(s) => LevelMetadata::parse_str(s, ',')?,
     };
 
-    let iter = iter.filter_map(|obj| {
+    // Level strings end with a trailing ';', which would otherwise result in an empty object
+    let iter = iter.filter(|obj| !obj.is_empty()).filter_map(|obj| {
         LevelObject::parse_str(obj, ',')
             .map_err(|err| error!("Ignoring error during parsing of object {} - {}", obj, err))
             .ok()
@@ -44,7 +46,7 @@ pub fn parse_lazy_parallel<'a>(
 
     let metadata = LevelMetadata::parse_str(metadata_str, ',')?;
 
-    let iter = object_str.par_split(';').filter_map(|obj| {
+    let iter = object_str.par_split(';').filter(|obj| !obj.is_empty()).filter_map(|obj| {
         LevelObject::parse_str(obj, ',')
             .map_err(|err| error!("Ignoring error during parsing of object {} - {}", obj, err))
             .ok()
@@ -53,6 +55,42 @@ pub fn parse_lazy_parallel<'a>(
     Ok(ParIterSource(metadata, iter))
 }
 
+/// Serializes the given metadata and objects into a level string. This is the inverse of
+/// [`parse_lazy`]
+///
+/// The result can be stored in a level via
+/// [`Level::compress_data`](gdcf_model::level::Level::compress_data)
+pub fn unparse_level(metadata: LevelMetadata, objects: impl IntoIterator<Item = LevelObject>) -> String {
+    let mut level_string = metadata.unparse_str(',');
+
+    level_string.push(';');
+
+    for object in objects {
+        level_string.push_str(&object.unparse_str(','));
+        level_string.push(';');
+    }
+
+    level_string
+}
+
+/// Gets the `(x position, portal type)` pair the given object contributes to a level's
+/// [`Timeline`], if any
+///
+/// The `checked` property of speed portals is respected, just like in
+/// [`LevelInformationSource::stats`]. For all other portals it is ignored, as it is usually unset
+/// for them even though the player does pass through them.
+fn timeline_portal(object: &LevelObject) -> Option<(f32, PortalType)> {
+    match object.metadata {
+        ObjectData::Portal(PortalData {
+            checked: false,
+            portal_type: PortalType::Speed(_),
+            ..
+        }) => None,
+        ObjectData::Portal(PortalData { portal_type, .. }) => Some((object.x, portal_type)),
+        _ => None,
+    }
+}
+
 impl<I> LevelInformationSource for IterSource<I>
 where
     I: Iterator<Item = LevelObject>,
@@ -74,6 +112,7 @@ where
             if let ObjectData::Portal(PortalData {
                 checked: true,
                 portal_type: PortalType::Speed(speed),
+                ..
             }) = object.metadata
             {
                 portals.push((object.x, speed))
@@ -82,13 +121,28 @@ where
             furthest_x = f32::max(furthest_x, object.x);
         }
 
-        portals.sort_by(|(x1, _), (x2, _)| x1.partial_cmp(x2).unwrap());
+        portals.sort_by(|(x1, _), (x2, _)| x1.total_cmp(x2));
 
         let duration = Duration::from_secs(portal::get_seconds_from_x_pos(furthest_x, metadata.starting_speed, &portals) as u64);
 
         Stats { object_count, duration }
     }
 
+    fn timeline(self) -> Timeline {
+        let IterSource(metadata, iter) = self;
+
+        let mut portals = Vec::new();
+        let mut furthest_x = 0.0;
+
+        for object in iter {
+            portals.extend(timeline_portal(&object));
+
+            furthest_x = f32::max(furthest_x, object.x);
+        }
+
+        Timeline::compute(&metadata, portals, furthest_x)
+    }
+
     fn metadata(&self) -> LevelMetadata {
         self.0.clone()
     }
@@ -113,6 +167,7 @@ where
                     if let ObjectData::Portal(PortalData {
                         checked: true,
                         portal_type: PortalType::Speed(speed),
+                        ..
                     }) = object.metadata
                     {
                         portals.push((object.x, speed))
@@ -131,28 +186,38 @@ where
 
         // The parallel parsing fucked up the order already anyway, so we wont have to bother using a stable
         // sort
-        portals.sort_unstable_by(|(x1, _), (x2, _)| x1.partial_cmp(x2).unwrap());
+        portals.sort_unstable_by(|(x1, _), (x2, _)| x1.total_cmp(x2));
 
         let duration = Duration::from_secs(portal::get_seconds_from_x_pos(max_x, metadata.starting_speed, &portals) as u64);
 
         Stats { object_count, duration }
     }
 
-    fn metadata(&self) -> LevelMetadata {
-        self.0.clone()
+    fn timeline(self) -> Timeline {
+        let ParIterSource(metadata, iter) = self;
+
+        let (portals, max_x) = iter
+            .fold(
+                || (Vec::new(), 0.0),
+                |(mut portals, max_x), object| {
+                    portals.extend(timeline_portal(&object));
+
+                    (portals, f32::max(max_x, object.x))
+                },
+            )
+            .reduce(
+                || (Vec::with_capacity(32), 0.0),
+                |(mut v1, x1), (v2, x2)| {
+                    v1.extend(v2);
+                    (v1, f32::max(x1, x2))
+                },
+            );
+
+        Timeline::compute(&metadata, portals, max_x)
     }
-}
 
-parser! {
-    LevelObject => {
-        id(index = 1),
-        x(index = 2),
-        y(index = 3),
-        flipped_y(index = 4, optional),
-        flipped_x(index = 5, optional),
-        rotation(index = 6, optional),
-        // ... all the other fields ...
-        metadata(delegate),
+    fn metadata(&self) -> LevelMetadata {
+        self.0.clone()
     }
 }
 
@@ -168,12 +233,13 @@ parser! {
         // ground line index: kA17
         // font: kA18
         // color page (???): kS39
-        // starting game mode: kA2
-        // starting size: kA3
+        starting_game_mode(index = kA2, optional),
+        start_mini(index = kA3, optional),
         dual_start(index = kA8),
         // level/start pos (???): kA9
         two_player_controls(index = kA10),
         start_gravity_inverted(index = kA11, optional),
         // ... all the other fields ...
+        unknown_properties(unknown),
     }
 }
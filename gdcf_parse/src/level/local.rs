@@ -0,0 +1,188 @@
+use crate::{
+    convert::{Base64BytesConverter, Base64Converter},
+    error::{SavefileError, ValueError},
+    level::{extract_main_song_id, process_song},
+    savefile::{
+        self,
+        plist::{Dictionary, Document, Value},
+    },
+    Parse,
+};
+use gdcf_model::level::{local::LocalLevel, LevelLength};
+use std::collections::HashMap;
+
+const LEVELS_KEY: &str = "LLM_01";
+
+// Keys whose values the game stores as `<s>` and `<t />` respectively. Everything else we know
+// about is an `<i>`
+const STRING_KEYS: [&str; 4] = ["k2", "k3", "k4", "k5"];
+const BOOL_KEYS: [&str; 2] = ["k14", "k43"];
+
+/// The content of a `CCLocalLevels.dat` save file
+///
+/// Writing an unmodified instance back via [`LocalLevels::write`] reproduces the original
+/// document byte for byte (after decryption), as all data GDCF doesn't know about is preserved.
+#[derive(Debug, Clone)]
+pub struct LocalLevels {
+    /// The levels in the "created levels" section of the game, in the order the game displays
+    /// them, meaning the most recently created one comes first.
+    ///
+    /// Levels can be freely modified, reordered, removed or added.
+    pub levels: Vec<LocalLevelEntry>,
+
+    document: Document,
+}
+
+/// A [`LocalLevel`] together with the save file entry it was read from
+#[derive(Debug, Clone)]
+pub struct LocalLevelEntry {
+    pub level: LocalLevel,
+
+    /// The level as originally read and the dictionary it was read from, or [`None`] if the level
+    /// wasn't read from a save file
+    original: Option<(LocalLevel, Dictionary)>,
+}
+
+impl LocalLevels {
+    /// Decrypts and parses the content of a `CCLocalLevels.dat` save file
+    pub fn read(savefile: &[u8]) -> Result<LocalLevels, SavefileError> {
+        let document = savefile::read(savefile)?;
+
+        let levels = document
+            .root
+            .get(LEVELS_KEY)
+            .and_then(Value::as_dictionary)
+            .ok_or(SavefileError::MissingKey(LEVELS_KEY))?
+            .dictionaries()
+            .filter(|(key, _)| key.starts_with("k_"))
+            .map(|(key, dict)| {
+                LocalLevel::parse(dict.scalars(), |_, _| Ok(()))
+                    .map(|level| {
+                        LocalLevelEntry {
+                            level: level.clone(),
+                            original: Some((level, dict.clone())),
+                        }
+                    })
+                    .map_err(|err| SavefileError::Value(key.to_string(), err.to_string()))
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(LocalLevels { levels, document })
+    }
+
+    /// Builds the root [`Dictionary`] of the save file from the current state of the levels
+    pub fn to_dictionary(&self) -> Dictionary {
+        let mut root = self.document.root.clone();
+
+        let mut levels = match root.get(LEVELS_KEY).and_then(Value::as_dictionary) {
+            Some(dict) => dict.clone(),
+            None => Dictionary::default(),
+        };
+
+        levels.entries.retain(|(key, _)| !key.starts_with("k_"));
+        levels.entries.extend(
+            self.levels
+                .iter()
+                .enumerate()
+                .map(|(idx, entry)| (format!("k_{}", idx).into(), Value::Dictionary(entry.to_dictionary()))),
+        );
+
+        root.insert(LEVELS_KEY, Value::Dictionary(levels));
+        root
+    }
+
+    /// Serializes and encrypts the current state of the levels into the save file format
+    pub fn write(&self) -> Vec<u8> {
+        savefile::write(&self.document.with_root(self.to_dictionary()))
+    }
+}
+
+impl LocalLevelEntry {
+    /// Builds the save file entry for this level
+    ///
+    /// Only the values of fields that were modified since the level was read are updated, all
+    /// other values (including the ones at keys GDCF doesn't know about) remain exactly as they
+    /// were.
+    pub fn to_dictionary(&self) -> Dictionary {
+        let (mut dict, old) = match self.original {
+            Some((ref level, ref dict)) => (dict.clone(), level.clone().unparse()),
+            None => (Dictionary::default(), HashMap::new()),
+        };
+
+        let new = self.level.clone().unparse();
+
+        for (key, value) in &new {
+            if old.get(key) != Some(value) {
+                match plist_value(key, value) {
+                    Some(value) => dict.insert(key, value),
+                    None => {
+                        dict.remove(key);
+                    },
+                }
+            }
+        }
+
+        for key in old.keys() {
+            if !new.contains_key(key) {
+                dict.remove(key);
+            }
+        }
+
+        dict
+    }
+}
+
+impl From<LocalLevel> for LocalLevelEntry {
+    fn from(level: LocalLevel) -> Self {
+        LocalLevelEntry { level, original: None }
+    }
+}
+
+/// Converts an unparsed value into the plist value the game would store at the given key, or
+/// [`None`] if the game would omit the key entirely because the value is the default one
+fn plist_value(key: &str, value: &str) -> Option<Value> {
+    if value.is_empty() || value == "0" {
+        None
+    } else if BOOL_KEYS.contains(&key) {
+        Some(Value::Bool(true))
+    } else if STRING_KEYS.contains(&key) {
+        Some(Value::String(value.into()))
+    } else {
+        Some(Value::Integer(value.into()))
+    }
+}
+
+/// Reads all levels from the "created levels" section of the game out of the content of a
+/// `CCLocalLevels.dat` save file
+///
+/// The levels are returned in the order the game displays them, meaning the most recently
+/// created one comes first.
+pub fn parse_local_levels(savefile: &[u8]) -> Result<Vec<LocalLevel>, SavefileError> {
+    Ok(LocalLevels::read(savefile)?.levels.into_iter().map(|entry| entry.level).collect())
+}
+
+fn default_length() -> LevelLength {
+    LevelLength::Tiny
+}
+
+parser! {
+    LocalLevel => {
+        level_id(index = k1, default),
+        name(index = k2, default),
+        description(index = k3, parse_infallible = Base64Converter, default),
+        level_data(index = k4, parse = Base64BytesConverter, default),
+        creator_name(index = k5, default),
+        creator(index = k6, default),
+        main_song(custom = process_song[main_song_id, &custom_song]),
+        verified(index = k14, default),
+        version(index = k16, default),
+        attempts(index = k18, default),
+        length(index = k23, default_with = default_length),
+        copy_of(index = k42, default),
+        two_player(index = k43, default),
+        custom_song(index = k45, default),
+        object_amount(index = k48, default),
+        editor_time(index = k80, default),
+    },
+    main_song_id(index = k8, extract = extract_main_song_id[main_song], default),
+}
@@ -1,12 +1,47 @@
-use crate::{error::ValueError, Parse};
+use crate::{
+    convert::{RobtopFrom, RobtopInto},
+    error::ValueError,
+    Parse,
+};
 use gdcf_model::level::data::{
     ids,
     portal::{PortalData, PortalType},
     text::TextData,
-    trigger::ColorTriggerData,
-    ObjectData,
+    trigger::{
+        AlphaTriggerData, AnimateTriggerData, CollisionTriggerData, ColorTriggerData, Comparison, CountTriggerData, Easing,
+        FollowPlayerYTriggerData, FollowTriggerData, Hsv, InstantCountTriggerData, MoveTriggerData, OnDeathTriggerData, PickupTriggerData,
+        PulseTriggerData, RotateTriggerData, ShakeTriggerData, SpawnTriggerData, StopTriggerData, ToggleMode, ToggleTriggerData,
+        TouchTriggerData, TriggerFlags,
+    },
+    LevelObject, ObjectData,
 };
-use std::collections::HashMap;
+use std::{borrow::Cow, collections::HashMap, num::ParseIntError};
+
+/// Converter for the `.`-separated group ID lists of objects
+pub struct GroupIdList;
+
+impl RobtopFrom<Vec<u16>, &str> for GroupIdList {
+    fn robtop_from(ids: &str) -> Result<Vec<u16>, String> {
+        ids.split('.')
+            .filter(|id| !id.is_empty())
+            .map(|id| id.parse().map_err(|e: ParseIntError| e.to_string()))
+            .collect()
+    }
+}
+
+impl RobtopInto<GroupIdList, String> for Vec<u16> {
+    fn robtop_into(self) -> String {
+        self.iter().map(ToString::to_string).collect::<Vec<_>>().join(".")
+    }
+
+    fn can_omit(&self) -> bool {
+        self.is_empty()
+    }
+}
+
+fn default_scale() -> f32 {
+    1.0
+}
 
 impl<'a> Parse<'a> for ObjectData {
     fn parse<I, F>(iter: I, mut f: F) -> Result<Self, ValueError<'a>>
@@ -21,8 +56,25 @@ impl<'a> Parse<'a> for ObjectData {
             .ok_or(ValueError::NoValue("1"))?;
 
         match id {
-            ids::S_SLOW_PORTAL | ids::S_NORMAL_PORTAL | ids::S_MEDIUM_PORTAL | ids::S_FAST_PORTAL | ids::S_VERY_FAST_PORTAL =>
-                Ok(ObjectData::Portal(PortalData::parse(iter, f)?)),
+            id if PortalType::from_id_str(id) != PortalType::Nonsense => Ok(ObjectData::Portal(PortalData::parse(iter, f)?)),
+            ids::S_COLOR_TRIGGER => Ok(ObjectData::ColorTrigger(ColorTriggerData::parse(iter, f)?)),
+            ids::S_MOVE_TRIGGER => Ok(ObjectData::MoveTrigger(MoveTriggerData::parse(iter, f)?)),
+            ids::S_ROTATE_TRIGGER => Ok(ObjectData::RotateTrigger(RotateTriggerData::parse(iter, f)?)),
+            ids::S_ALPHA_TRIGGER => Ok(ObjectData::AlphaTrigger(AlphaTriggerData::parse(iter, f)?)),
+            ids::S_TOGGLE_TRIGGER => Ok(ObjectData::ToggleTrigger(ToggleTriggerData::parse(iter, f)?)),
+            ids::S_PULSE_TRIGGER => Ok(ObjectData::PulseTrigger(PulseTriggerData::parse(iter, f)?)),
+            ids::S_SPAWN_TRIGGER => Ok(ObjectData::SpawnTrigger(SpawnTriggerData::parse(iter, f)?)),
+            ids::S_STOP_TRIGGER => Ok(ObjectData::StopTrigger(StopTriggerData::parse(iter, f)?)),
+            ids::S_FOLLOW_TRIGGER => Ok(ObjectData::FollowTrigger(FollowTriggerData::parse(iter, f)?)),
+            ids::S_FOLLOW_PLAYER_Y_TRIGGER => Ok(ObjectData::FollowPlayerYTrigger(FollowPlayerYTriggerData::parse(iter, f)?)),
+            ids::S_SHAKE_TRIGGER => Ok(ObjectData::ShakeTrigger(ShakeTriggerData::parse(iter, f)?)),
+            ids::S_ANIMATE_TRIGGER => Ok(ObjectData::AnimateTrigger(AnimateTriggerData::parse(iter, f)?)),
+            ids::S_TOUCH_TRIGGER => Ok(ObjectData::TouchTrigger(TouchTriggerData::parse(iter, f)?)),
+            ids::S_COUNT_TRIGGER => Ok(ObjectData::CountTrigger(CountTriggerData::parse(iter, f)?)),
+            ids::S_INSTANT_COUNT_TRIGGER => Ok(ObjectData::InstantCountTrigger(InstantCountTriggerData::parse(iter, f)?)),
+            ids::S_PICKUP_TRIGGER => Ok(ObjectData::PickupTrigger(PickupTriggerData::parse(iter, f)?)),
+            ids::S_COLLISION_TRIGGER => Ok(ObjectData::CollisionTrigger(CollisionTriggerData::parse(iter, f)?)),
+            ids::S_ON_DEATH_TRIGGER => Ok(ObjectData::OnDeathTrigger(OnDeathTriggerData::parse(iter, f)?)),
             // .. all the other types of metadata, which might have proper parsers ...
             _ => {
                 // We aren't delegating further, so we gotta drive the iterator to completion
@@ -35,30 +87,270 @@ impl<'a> Parse<'a> for ObjectData {
         }
     }
 
-    fn unparse(self) -> HashMap<&'a str, String> {
+    fn unparse(self) -> HashMap<Cow<'a, str>, String> {
         match self {
             ObjectData::None => HashMap::default(),
             ObjectData::Portal(portal_data) => portal_data.unparse(),
             ObjectData::Text(text_data) => text_data.unparse(),
             ObjectData::ColorTrigger(color_trigger_data) => color_trigger_data.unparse(),
+            ObjectData::MoveTrigger(move_trigger_data) => move_trigger_data.unparse(),
+            ObjectData::RotateTrigger(rotate_trigger_data) => rotate_trigger_data.unparse(),
+            ObjectData::AlphaTrigger(alpha_trigger_data) => alpha_trigger_data.unparse(),
+            ObjectData::ToggleTrigger(toggle_trigger_data) => toggle_trigger_data.unparse(),
+            ObjectData::PulseTrigger(pulse_trigger_data) => pulse_trigger_data.unparse(),
+            ObjectData::SpawnTrigger(spawn_trigger_data) => spawn_trigger_data.unparse(),
+            ObjectData::StopTrigger(stop_trigger_data) => stop_trigger_data.unparse(),
+            ObjectData::FollowTrigger(follow_trigger_data) => follow_trigger_data.unparse(),
+            ObjectData::FollowPlayerYTrigger(follow_player_y_trigger_data) => follow_player_y_trigger_data.unparse(),
+            ObjectData::ShakeTrigger(shake_trigger_data) => shake_trigger_data.unparse(),
+            ObjectData::AnimateTrigger(animate_trigger_data) => animate_trigger_data.unparse(),
+            ObjectData::TouchTrigger(touch_trigger_data) => touch_trigger_data.unparse(),
+            ObjectData::CountTrigger(count_trigger_data) => count_trigger_data.unparse(),
+            ObjectData::InstantCountTrigger(instant_count_trigger_data) => instant_count_trigger_data.unparse(),
+            ObjectData::PickupTrigger(pickup_trigger_data) => pickup_trigger_data.unparse(),
+            ObjectData::CollisionTrigger(collision_trigger_data) => collision_trigger_data.unparse(),
+            ObjectData::OnDeathTrigger(on_death_trigger_data) => on_death_trigger_data.unparse(),
         }
     }
 }
 
+parser! {
+    LevelObject => {
+        id(index = 1),
+        x(index = 2),
+        y(index = 3),
+        flipped_y(index = 4, optional),
+        flipped_x(index = 5, optional),
+        rotation(index = 6, optional),
+        groups(index = 57, parse = GroupIdList, optional),
+        main_color(index = 21, optional),
+        secondary_color(index = 22, optional),
+        z_layer(index = 24, optional),
+        z_order(index = 25, optional),
+        scale(index = 32, default_with = default_scale),
+        editor_layer_1(index = 20, optional),
+        editor_layer_2(index = 61, optional),
+        hidden(index = 135, optional),
+        no_touch(index = 121, optional),
+        // ... all the other fields ...
+        metadata(delegate),
+        unknown_properties(unknown),
+    }
+}
+
 parser! {
     PortalData => {
-        checked(index = 13),
+        checked(index = 13, optional),
+        teleport_offset(index = 54, optional),
         portal_type(custom = PortalType::from_id_str[id]),
     },
     id(^index = 1, noparse),
 }
 
+parser! {
+    TriggerFlags => {
+        touch_triggered(index = 11, optional),
+        spawn_triggered(index = 62, optional),
+        multi_trigger(index = 87, optional),
+    }
+}
+
 parser! {
     ColorTriggerData => {
         r(index = 7),
         g(index = 8),
         b(index = 9),
-        blending_enabled(index = 17),
+        blending_enabled(index = 17, optional),
+        duration(index = 10, optional),
+        target_channel(index = 23, optional),
+        opacity(index = 35, optional),
+        player_color_1(index = 15, optional),
+        player_color_2(index = 16, optional),
+        copy_color(index = 50, optional),
+        copy_hsv(index = 49, parse = Hsv, optional),
+        copy_opacity(index = 60, optional),
+        flags(delegate),
+    }
+}
+
+parser! {
+    MoveTriggerData => {
+        target_group(index = 51, optional),
+        x(index = 28, optional),
+        y(index = 29, optional),
+        duration(index = 10, optional),
+        easing(index = 30, parse = Easing, optional),
+        easing_rate(index = 85, optional),
+        lock_to_player_x(index = 58, optional),
+        lock_to_player_y(index = 59, optional),
+        use_target(index = 100, optional),
+        target_position_group(index = 71, optional),
+        flags(delegate),
+    }
+}
+
+parser! {
+    RotateTriggerData => {
+        target_group(index = 51, optional),
+        center_group(index = 71, optional),
+        degrees(index = 68, optional),
+        times_360(index = 69, optional),
+        lock_object_rotation(index = 70, optional),
+        duration(index = 10, optional),
+        easing(index = 30, parse = Easing, optional),
+        easing_rate(index = 85, optional),
+        flags(delegate),
+    }
+}
+
+parser! {
+    AlphaTriggerData => {
+        target_group(index = 51, optional),
+        opacity(index = 35, optional),
+        duration(index = 10, optional),
+        flags(delegate),
+    }
+}
+
+parser! {
+    ToggleTriggerData => {
+        target_group(index = 51, optional),
+        activate_group(index = 56, optional),
+        flags(delegate),
+    }
+}
+
+parser! {
+    PulseTriggerData => {
+        r(index = 7, optional),
+        g(index = 8, optional),
+        b(index = 9, optional),
+        fade_in(index = 45, optional),
+        hold(index = 46, optional),
+        fade_out(index = 47, optional),
+        hsv_mode(index = 48, optional),
+        hsv(index = 49, parse = Hsv, optional),
+        copy_color(index = 50, optional),
+        target_is_group(index = 52, optional),
+        target(index = 51, optional),
+        main_only(index = 65, optional),
+        detail_only(index = 66, optional),
+        exclusive(index = 86, optional),
+        flags(delegate),
+    }
+}
+
+parser! {
+    SpawnTriggerData => {
+        target_group(index = 51, optional),
+        delay(index = 63, optional),
+        flags(delegate),
+    }
+}
+
+parser! {
+    StopTriggerData => {
+        target_group(index = 51, optional),
+        flags(delegate),
+    }
+}
+
+parser! {
+    FollowTriggerData => {
+        target_group(index = 51, optional),
+        follow_group(index = 71, optional),
+        x_mod(index = 72, optional),
+        y_mod(index = 73, optional),
+        duration(index = 10, optional),
+        flags(delegate),
+    }
+}
+
+parser! {
+    FollowPlayerYTriggerData => {
+        target_group(index = 51, optional),
+        speed(index = 90, optional),
+        delay(index = 91, optional),
+        offset(index = 92, optional),
+        max_speed(index = 105, optional),
+        duration(index = 10, optional),
+        flags(delegate),
+    }
+}
+
+parser! {
+    ShakeTriggerData => {
+        strength(index = 75, optional),
+        interval(index = 84, optional),
+        duration(index = 10, optional),
+        flags(delegate),
+    }
+}
+
+parser! {
+    AnimateTriggerData => {
+        target_group(index = 51, optional),
+        animation_id(index = 76, optional),
+        flags(delegate),
+    }
+}
+
+parser! {
+    TouchTriggerData => {
+        target_group(index = 51, optional),
+        hold_mode(index = 81, optional),
+        toggle_mode(index = 82, parse = ToggleMode, optional),
+        dual_mode(index = 89, optional),
+        flags(delegate),
+    }
+}
+
+parser! {
+    CountTriggerData => {
+        item_id(index = 80, optional),
+        target_count(index = 77, optional),
+        target_group(index = 51, optional),
+        activate_group(index = 56, optional),
+        multi_activate(index = 104, optional),
+        flags(delegate),
+    }
+}
+
+parser! {
+    InstantCountTriggerData => {
+        item_id(index = 80, optional),
+        target_count(index = 77, optional),
+        target_group(index = 51, optional),
+        activate_group(index = 56, optional),
+        comparison(index = 88, parse = Comparison, optional),
+        flags(delegate),
+    }
+}
+
+parser! {
+    PickupTriggerData => {
+        item_id(index = 80, optional),
+        count(index = 77, optional),
+        flags(delegate),
+    }
+}
+
+parser! {
+    CollisionTriggerData => {
+        block_a(index = 80, optional),
+        block_b(index = 95, optional),
+        target_group(index = 51, optional),
+        activate_group(index = 56, optional),
+        trigger_on_exit(index = 93, optional),
+        flags(delegate),
+    }
+}
+
+parser! {
+    OnDeathTriggerData => {
+        target_group(index = 51, optional),
+        activate_group(index = 56, optional),
+        flags(delegate),
     }
 }
 
@@ -6,7 +6,7 @@
 //trace_macros!(true);
 
 use crate::{error::ValueError, util::SelfZipExt};
-use std::collections::HashMap;
+use std::{borrow::Cow, cmp::Ordering, collections::HashMap};
 
 #[macro_use]
 extern crate log;
@@ -18,6 +18,8 @@ pub mod comment;
 pub mod convert;
 pub mod error;
 pub mod level;
+pub mod message;
+pub mod savefile;
 pub mod song;
 pub mod user;
 
@@ -32,7 +34,7 @@ pub trait Parse<'a>: Sized {
     where
         I: Iterator<Item = (&'a str, &'a str)> + Clone,
         F: FnMut(&'a str, &'a str) -> Result<(), ValueError<'a>>;
-    fn unparse(self) -> HashMap<&'a str, String>;
+    fn unparse(self) -> HashMap<Cow<'a, str>, String>;
 
     fn parse_iter(iter: impl Iterator<Item = &'a str> + Clone) -> Result<Self, ValueError<'a>> {
         Self::parse(iter.self_zip(), |i, v| {
@@ -68,6 +70,58 @@ pub trait Parse<'a>: Sized {
     fn parse_unindexed_str2(input: &'a str, delimiter: &'a str) -> Result<Self, ValueError<'a>> {
         Self::parse_unindexed_iter(input.split(delimiter))
     }
+
+    /// Unparses `self` into a string of the form `index<delimiter>value<delimiter>...`. This is
+    /// the inverse of [`Parse::parse_str`].
+    ///
+    /// The indices are sorted (numerically, where possible), so that the output is deterministic
+    fn unparse_str(self, delimiter: char) -> String {
+        self.unparse_str2(delimiter.encode_utf8(&mut [0; 4]))
+    }
+
+    /// Unparses `self` into a string of the form `index<delimiter>value<delimiter>...`. This is
+    /// the inverse of [`Parse::parse_str2`].
+    fn unparse_str2(self, delimiter: &str) -> String {
+        let mut result = String::new();
+
+        for (idx, value) in self.unparse_sorted() {
+            if !result.is_empty() {
+                result.push_str(delimiter);
+            }
+
+            result.push_str(&idx);
+            result.push_str(delimiter);
+            result.push_str(&value);
+        }
+
+        result
+    }
+
+    /// Unparses `self` into a string of the form `value<delimiter>value<delimiter>...`, ordered by
+    /// index. This is the inverse of [`Parse::parse_unindexed_str`], as long as the values are at
+    /// consecutive indices starting at `1`.
+    fn unparse_unindexed_str(self, delimiter: char) -> String {
+        let values: Vec<_> = self.unparse_sorted().into_iter().map(|(_, value)| value).collect();
+
+        values.join(delimiter.encode_utf8(&mut [0; 4]))
+    }
+
+    /// Unparses `self`, sorting the resulting entries by their indices (numerically, where
+    /// possible)
+    fn unparse_sorted(self) -> Vec<(Cow<'a, str>, String)> {
+        let mut entries: Vec<_> = self.unparse().into_iter().collect();
+
+        entries.sort_by(|(idx1, _), (idx2, _)| {
+            match (idx1.parse::<u32>(), idx2.parse::<u32>()) {
+                (Ok(idx1), Ok(idx2)) => idx1.cmp(&idx2),
+                (Ok(_), Err(_)) => Ordering::Less,
+                (Err(_), Ok(_)) => Ordering::Greater,
+                (Err(_), Err(_)) => idx1.cmp(idx2),
+            }
+        });
+
+        entries
+    }
 }
 
 pub struct UnparseSafe<'a, P: Parse<'a>> {
@@ -91,9 +145,13 @@ impl<'a, P: Parse<'a>> Parse<'a> for UnparseSafe<'a, P> {
         Ok(UnparseSafe { unparsed, parsed })
     }
 
-    fn unparse(self) -> HashMap<&'a str, String> {
+    fn unparse(self) -> HashMap<Cow<'a, str>, String> {
         let mut inner_unparsed = self.parsed.unparse();
-        inner_unparsed.extend(self.unparsed.into_iter().map(|(index, value)| (index, value.to_owned())));
+        inner_unparsed.extend(
+            self.unparsed
+                .into_iter()
+                .map(|(index, value)| (Cow::Borrowed(index), value.to_owned())),
+        );
         inner_unparsed
     }
 }
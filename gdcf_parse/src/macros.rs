@@ -81,22 +81,22 @@ macro_rules! __match_arm_expr {
 
 macro_rules! __into_expr {
     (@ $map: expr, $value: expr, index = $idx: expr, parse = $external: ident, optional $(, $($__:tt)*)?) => {{
-        if RobtopInto::<$external, _>::can_omit(&$value) {
-            $map.insert(stringify!($idx), RobtopInto::<$external, _>::robtop_into($value));
+        if !RobtopInto::<$external, _>::can_omit(&$value) {
+            $map.insert(stringify!($idx).into(), RobtopInto::<$external, _>::robtop_into($value));
         }
     }};
 
     (@ $map: expr, $value: expr, index = $idx: expr, parse = $external: ident, optional_non_default $(, $($__:tt)*)?) => {{
         if let Some(value) = $value {
             if !RobtopInto::<$external, _>::can_omit(&value) {
-                $map.insert(stringify!($idx), RobtopInto::<$external, _>::robtop_into(value));
+                $map.insert(stringify!($idx).into(), RobtopInto::<$external, _>::robtop_into(value));
             }
         }
     }};
 
     // Custom parser function
     (@ $map: expr, $value: expr, index = $idx: expr, parse = $external: ident $(, $($__:tt)*)?) => {{
-        $map.insert(stringify!($idx), RobtopInto::<$external, _>::robtop_into($value))
+        $map.insert(stringify!($idx).into(), RobtopInto::<$external, _>::robtop_into($value))
     }};
 
     // Custom parser that cannot fail
@@ -104,21 +104,35 @@ macro_rules! __into_expr {
         __into_expr!(@ $map, $value, index = $idx, parse = $external)
     }};
 
+    // Built-in parsing of values that are left out if they are equal to the value of their default function
+    (@ $map: expr, $value: expr, index = $idx: expr, default_with = $default_func: path $(, $($__:tt)*)?) => {{
+        if $value != $default_func() {
+            $map.insert(stringify!($idx).into(), crate::util::unparse($value));
+        }
+    }};
+
+    // Built-in parsing of values that can be left out if they have their default value
+    (@ $map: expr, $value: expr, index = $idx: expr, optional $(, $($__:tt)*)?) => {{
+        if !crate::util::can_omit(&$value) {
+            $map.insert(stringify!($idx).into(), crate::util::unparse($value));
+        }
+    }};
+
     // Built-in parsing
     (@ $map: expr, $value: expr, index = $idx: expr $(, $($__:tt)*)?) => {{
         let value = crate::util::unparse($value);
         if !crate::util::can_omit(&value) {
-            $map.insert(stringify!($idx), value);
+            $map.insert(stringify!($idx).into(), value);
         }
     }};
 
     (@ $map: expr, $value: expr, index = $idx: expr $(, $($__:tt)*)?) => {{
-        $map.insert(stringify!($idx), crate::util::unparse($value))
+        $map.insert(stringify!($idx).into(), crate::util::unparse($value))
     }};
 
     // Unparsing of helper variables
     (! $map: expr, index = $idx: expr $(, parse = $_: ident)? $(, ignore)? $(, noparse)? $(, parse_infallible = $t: ident)?, extract = $extractor: path[$($arg: expr),*] $(, $($__:tt)*)?) => {{
-        $map.insert(stringify!($idx), $extractor($($arg,)*))
+        $map.insert(stringify!($idx).into(), $extractor($($arg,)*))
     }};
 
     (! $map: expr, ^index = $idx: expr $(, $($__:tt)*)?) => {{
@@ -165,6 +179,10 @@ macro_rules! __unwrap {
         $field_name.unwrap_or_else($default_func)
     };
 
+    ($field_name: ident($(^)?index = $idx: expr, default_with = $default_func: path)) => {
+        $field_name.unwrap_or_else($default_func)
+    };
+
     ($field_name: ident($(^)?index = $idx: expr, parse_infallible = $_: ty $(, $($crap:tt)*)?)) => {
         __unwrap!($field_name(index = $idx $(, $($crap)*)?))
     };
@@ -182,6 +200,17 @@ macro_rules! __unwrap {
     };
 }
 
+macro_rules! __unknown {
+    // No field to store unknown values in, pass them upward
+    ($f: expr, $idx: expr, $value: expr) => {
+        $f($idx, $value)?
+    };
+
+    ($f: expr, $idx: expr, $value: expr, $unknown: ident) => {{
+        $unknown.insert($idx.to_string(), $value.to_string());
+    }};
+}
+
 macro_rules! __declare {
     ($field_name: ident, index = $idx: expr, ignore $(, $($t:tt)*)?) => {{}};
     ($field_name: ident, $($t:tt)*) => {
@@ -191,23 +220,27 @@ macro_rules! __declare {
 
 macro_rules! parser {
     ($struct_name: ty => {$($tokens:tt)*}$(, $($tokens2:tt)*)?) => {
-        parser!(@ $struct_name [] [] [] [$($tokens)*] [$($($tokens2)*)?]);
+        parser!(@ $struct_name [] [] [] [$($tokens)*] [$($($tokens2)*)?] []);
+    };
+
+    (@ $struct_name: ty [$($crap:tt)*] [] [$($crap3:tt)*] [$field_name: ident(custom $($data: tt)*), $($tokens:tt)*] [$($rest:tt)*] [$($unknown:tt)*]) => {
+        parser!(@ $struct_name [$($crap)*] [] [$($crap3)*, $field_name(custom $($data)*)] [$($tokens)*] [$($rest)*] [$($unknown)*]);
     };
 
-    (@ $struct_name: ty [$($crap:tt)*] [] [$($crap3:tt)*] [$field_name: ident(custom $($data: tt)*), $($tokens:tt)*] [$($rest:tt)*]) => {
-        parser!(@ $struct_name [$($crap)*] [] [$($crap3)*, $field_name(custom $($data)*)] [$($tokens)*] [$($rest)*]);
+    (@ $struct_name: ty [$($crap:tt)*] [] [$($crap3:tt)*] [$field_name: ident(delegate), $($tokens:tt)*] [$($rest:tt)*] [$($unknown:tt)*]) => {
+        parser!(@@ $struct_name, $field_name [$($crap)*] [] [$($crap3)*] [$($tokens)*] [$($rest)*] [$($unknown)*]);
     };
 
-    (@ $struct_name: ty [$($crap:tt)*] [] [$($crap3:tt)*] [$field_name: ident(delegate), $($tokens:tt)*] [$($rest:tt)*]) => {
-        parser!(@@ $struct_name, $field_name [$($crap)*] [] [$($crap3)*] [$($tokens)*] [$($rest)*]);
+    (@ $struct_name: ty [$($crap:tt)*] [] [$($crap3:tt)*] [$field_name: ident(unknown), $($tokens:tt)*] [$($rest:tt)*] []) => {
+        parser!(@ $struct_name [$($crap)*] [] [$($crap3)*] [$($tokens)*] [$($rest)*] [$field_name]);
     };
 
-    (@ $struct_name: ty [$($crap:tt)*] [] [$($crap3:tt)*] [$field_name: ident($($data: tt)*), $($tokens:tt)*] [$($rest:tt)*]) => {
-        parser!(@ $struct_name [$($crap)*, $field_name($($data)*)] [] [$($crap3)*] [$($tokens)*] [$($rest)*]);
+    (@ $struct_name: ty [$($crap:tt)*] [] [$($crap3:tt)*] [$field_name: ident($($data: tt)*), $($tokens:tt)*] [$($rest:tt)*] [$($unknown:tt)*]) => {
+        parser!(@ $struct_name [$($crap)*, $field_name($($data)*)] [] [$($crap3)*] [$($tokens)*] [$($rest)*] [$($unknown)*]);
     };
 
-    (@ $struct_name: ty [$($crap:tt)*] [$($crap2:tt)*] [$($crap3:tt)*] [] [$field_name: ident($($data: tt)*), $($rest:tt)*]) => {
-        parser!(@ $struct_name [$($crap)*] [$($crap2)*, $field_name($($data)*)] [$($crap3)*] [] [$($rest)*]);
+    (@ $struct_name: ty [$($crap:tt)*] [$($crap2:tt)*] [$($crap3:tt)*] [] [$field_name: ident($($data: tt)*), $($rest:tt)*] [$($unknown:tt)*]) => {
+        parser!(@ $struct_name [$($crap)*] [$($crap2)*, $field_name($($data)*)] [$($crap3)*] [] [$($rest)*] [$($unknown)*]);
     };
 
     (@ $struct_name: ty
@@ -215,9 +248,11 @@ macro_rules! parser {
         [$(, $helper_field: ident($($tokens2:tt)*))*]
         [$(, $custom_field: ident(custom = $func: path[$($field: expr),*]))*]
         [] []
+        [$($unknown: ident)?]
     ) => {
         impl<'a> Parse<'a> for $struct_name {
             #[inline]
+            #[allow(unused_mut, unused_variables)] // 'f' is unused if unknown values are stored in a field
             fn parse<I, F>(iter: I, mut f: F) -> Result<Self, ValueError<'a>>
             where
                 I: Iterator<Item = (&'a str, &'a str)> + Clone,
@@ -240,6 +275,10 @@ macro_rules! parser {
                     __declare!($helper_field, $($tokens2)*);
                 )*
 
+                $(
+                    let mut $unknown = std::collections::HashMap::new();
+                )?
+
                 for (idx, value) in iter.into_iter() {
                     match idx {
                         $(
@@ -248,7 +287,7 @@ macro_rules! parser {
                         $(
                             __index!($($tokens2)*) => __match_arm_expr!(@ f, $helper_field, value, $($tokens2)*),
                         )*
-                        _ => f(idx, value)?
+                        _ => __unknown!(f, idx, value $(, $unknown)?)
                     }
                 }
 
@@ -263,6 +302,9 @@ macro_rules! parser {
                 trace!("Finished parsing {}", stringify!($struct_name));
 
                 Ok(Self {
+                    $(
+                        $unknown,
+                    )?
                     $(
                         $field_name,
                     )*
@@ -272,11 +314,16 @@ macro_rules! parser {
                 })
             }
 
-            fn unparse(self) -> std::collections::HashMap<&'a str, String> {
+            fn unparse(self) -> std::collections::HashMap<std::borrow::Cow<'a, str>, String> {
                 #[allow(unused_imports)]
                 use crate::convert::RobtopInto;
 
+                // Custom fields are only used if some other field is extracted from them
+                #[allow(unused_variables)]
                 let Self {
+                    $(
+                        $unknown,
+                    )?
                     $(
                         $field_name,
                     )*
@@ -287,6 +334,10 @@ macro_rules! parser {
 
                 let mut map = std::collections::HashMap::new();
 
+                $(
+                    map.extend($unknown.into_iter().map(|(idx, value)| (std::borrow::Cow::Owned(idx), value)));
+                )?
+
                 $(
                     __into_expr!(! map, $($tokens2)*);
                 )*
@@ -300,16 +351,20 @@ macro_rules! parser {
         }
     };
 
-    (@@ $struct_name: ty, $delegated: ident [$($crap:tt)*] [] [$($crap3:tt)*] [$field_name: ident(custom $($data: tt)*), $($tokens:tt)*] [$($rest:tt)*]) => {
-        parser!(@@ $struct_name, $delegated [$($crap)*] [] [$($crap3)*, $field_name(custom $($data)*)] [$($tokens)*] [$($rest)*]);
+    (@@ $struct_name: ty, $delegated: ident [$($crap:tt)*] [] [$($crap3:tt)*] [$field_name: ident(custom $($data: tt)*), $($tokens:tt)*] [$($rest:tt)*] [$($unknown:tt)*]) => {
+        parser!(@@ $struct_name, $delegated [$($crap)*] [] [$($crap3)*, $field_name(custom $($data)*)] [$($tokens)*] [$($rest)*] [$($unknown)*]);
     };
 
-    (@@ $struct_name: ty, $delegated: ident [$($crap:tt)*] [] [$($crap3:tt)*] [$field_name: ident($($data: tt)*), $($tokens:tt)*] [$($rest:tt)*]) => {
-        parser!(@@ $struct_name, $delegated [$($crap)*, $field_name($($data)*)] [] [$($crap3)*] [$($tokens)*] [$($rest)*]);
+    (@@ $struct_name: ty, $delegated: ident [$($crap:tt)*] [] [$($crap3:tt)*] [$field_name: ident(unknown), $($tokens:tt)*] [$($rest:tt)*] []) => {
+        parser!(@@ $struct_name, $delegated [$($crap)*] [] [$($crap3)*] [$($tokens)*] [$($rest)*] [$field_name]);
     };
 
-    (@@ $struct_name: ty, $delegated: ident [$($crap:tt)*] [$($crap2:tt)*] [$($crap3:tt)*] [] [$field_name: ident($($data: tt)*), $($rest:tt)*]) => {
-        parser!(@@ $struct_name, $delegated [$($crap)*] [$($crap2)*, $field_name($($data)*)] [$($crap3)*] [] [$($rest)*]);
+    (@@ $struct_name: ty, $delegated: ident [$($crap:tt)*] [] [$($crap3:tt)*] [$field_name: ident($($data: tt)*), $($tokens:tt)*] [$($rest:tt)*] [$($unknown:tt)*]) => {
+        parser!(@@ $struct_name, $delegated [$($crap)*, $field_name($($data)*)] [] [$($crap3)*] [$($tokens)*] [$($rest)*] [$($unknown)*]);
+    };
+
+    (@@ $struct_name: ty, $delegated: ident [$($crap:tt)*] [$($crap2:tt)*] [$($crap3:tt)*] [] [$field_name: ident($($data: tt)*), $($rest:tt)*] [$($unknown:tt)*]) => {
+        parser!(@@ $struct_name, $delegated [$($crap)*] [$($crap2)*, $field_name($($data)*)] [$($crap3)*] [] [$($rest)*] [$($unknown)*]);
     };
 
     (@@ $struct_name: ty, $delegated: ident
@@ -317,9 +372,11 @@ macro_rules! parser {
         [$(, $helper_field: ident($($tokens2:tt)*))*]
         [$(, $custom_field: ident(custom = $func: path, depends_on = [$($field: expr),*]))*]
         [] []
+        [$($unknown: ident)?]
     ) => {
         impl<'a> Parse<'a> for $struct_name {
             #[inline]
+            #[allow(unused_mut, unused_variables)] // 'f' is unused if unknown values are stored in a field
             fn parse<I, F>(iter: I, mut f: F) -> Result<Self, ValueError<'a>>
             where
                 I: Iterator<Item = (&'a str, &'a str)> + Clone,
@@ -342,6 +399,10 @@ macro_rules! parser {
                     __declare!($helper_field, $($tokens2)*);
                 )*
 
+                $(
+                    let mut $unknown = std::collections::HashMap::new();
+                )?
+
                 let closure = |idx: &'a str, value: &'a str| -> Result<(), ValueError<'a>> {
                     match idx {
                         $(
@@ -350,7 +411,7 @@ macro_rules! parser {
                         $(
                             __index!($($tokens2)*) => __match_arm_expr!(@ f, $helper_field, value, $($tokens2)*),
                         )*
-                        _ => f(idx, value)?
+                        _ => __unknown!(f, idx, value $(, $unknown)?)
                     }
 
                     Ok(())
@@ -369,6 +430,9 @@ macro_rules! parser {
                 trace!("Finished parsing {}", stringify!($struct_name));
 
                 Ok(Self {
+                    $(
+                        $unknown,
+                    )?
                     $delegated,
                     $(
                         $field_name,
@@ -379,11 +443,16 @@ macro_rules! parser {
                 })
             }
 
-            fn unparse(self) -> std::collections::HashMap<&'a str, String> {
+            fn unparse(self) -> std::collections::HashMap<std::borrow::Cow<'a, str>, String> {
                 #[allow(unused_imports)]
                 use crate::convert::RobtopInto;
 
+                // Custom fields are only used if some other field is extracted from them
+                #[allow(unused_variables)]
                 let Self {
+                    $(
+                        $unknown,
+                    )?
                     $(
                         $field_name,
                     )*
@@ -395,6 +464,10 @@ macro_rules! parser {
 
                 let mut map = $delegated.unparse();//std::collections::HashMap::new();
 
+                $(
+                    map.extend($unknown.into_iter().map(|(idx, value)| (std::borrow::Cow::Owned(idx), value)));
+                )?
+
                 $(
                     __into_expr!(! map, $($tokens2)*);
                 )*
@@ -0,0 +1,25 @@
+use crate::{
+    convert::{Base64Converter, MessageBody},
+    Parse, ValueError,
+};
+use gdcf_model::message::{Message, PartialMessage};
+
+parser! {
+    PartialMessage => {
+        message_id(index = 1),
+        account_id(index = 2),
+        user_id(index = 3),
+        subject(index = 4, parse = Base64Converter),
+        username(index = 6),
+        time_since_sent(index = 7),
+        read(index = 8),
+        sent(index = 9),
+    }
+}
+
+parser! {
+    Message => {
+        base(delegate),
+        body(index = 5, parse = MessageBody),
+    }
+}
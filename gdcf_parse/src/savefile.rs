@@ -0,0 +1,48 @@
+//! Module containing functions for reading and writing the game's save files (`CCLocalLevels.dat`
+//! and `CCGameManager.dat`)
+//!
+//! The save files are gzip compressed plist-like XML documents, which are urlsafe base64 encoded
+//! and then XOR-ed with the key `11`. This is the format used by the Windows version of the game.
+
+pub mod plist;
+
+use crate::{error::SavefileError, util};
+use base64::URL_SAFE;
+use gdcf_model::level::data;
+
+const SAVEFILE_KEY: &str = "\u{b}";
+
+/// Decrypts the raw content of a save file into its plist XML representation
+///
+/// # Errors
+/// If the data isn't valid base64 after XOR decryption, a [`SavefileError::Base64`] is
+/// returned. If it cannot be decompressed, a [`SavefileError::Decompression`] is returned
+pub fn decrypt(savefile: &[u8]) -> Result<String, SavefileError> {
+    let xored = util::xor_decrypt(&String::from_utf8_lossy(savefile), SAVEFILE_KEY);
+
+    // The game pads the file with null bytes (before encryption, so they're 0x0b in the file) and
+    // doesn't always get the base64 padding right, so we strip both and decode without padding.
+    let encoded = xored.trim_end_matches(|c| c == '\0' || c == '=' || char::is_whitespace(c));
+    let compressed = base64::decode_config(encoded, base64::URL_SAFE_NO_PAD)?;
+
+    Ok(data::decompress(&compressed)?)
+}
+
+/// Decrypts the given save file and parses it into a [`plist::Document`]
+pub fn read(savefile: &[u8]) -> Result<plist::Document, SavefileError> {
+    plist::parse(&decrypt(savefile)?)
+}
+
+/// Encrypts the given plist XML into the format the game expects its save files in. This is the
+/// inverse of [`decrypt`]
+pub fn encrypt(plist: &str) -> Vec<u8> {
+    let encoded = base64::encode_config(&data::compress(plist), URL_SAFE);
+
+    util::xor_decrypt(&encoded, SAVEFILE_KEY).into_bytes()
+}
+
+/// Serializes the given [`plist::Document`] and encrypts it into a save file. This is the inverse
+/// of [`read`]
+pub fn write(document: &plist::Document) -> Vec<u8> {
+    encrypt(&plist::write(document))
+}
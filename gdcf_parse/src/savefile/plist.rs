@@ -0,0 +1,448 @@
+//! Module containing a parser and a writer for the plist-like XML format the game's save files use
+//!
+//! The game uses abbreviated tag names (`<d>`, `<k>`, `<i>`, `<r>`, `<s>`, `<t/>`) everywhere but
+//! for the root dictionary. The full plist tag names are accepted when parsing as well, but the
+//! writer always produces the abbreviated format. Everything surrounding the root dictionary and
+//! the text content of all elements is kept exactly as read, so that documents written by the game
+//! are reproduced byte for byte.
+
+use crate::error::SavefileError;
+use std::{fmt::Display, ops::Deref};
+
+const DEFAULT_PROLOG: &str = r#"<?xml version="1.0"?><plist version="1.0" gjver="2.0"><dict>"#;
+const DEFAULT_EPILOG: &str = "</dict></plist>";
+
+/// A plist document, consisting of its root [`Dictionary`] and everything surrounding it
+#[derive(Debug, Clone, PartialEq)]
+pub struct Document {
+    pub root: Dictionary,
+
+    /// Everything up to and including the opening tag of the root dictionary, as read
+    prolog: String,
+
+    /// Everything starting at the closing tag of the root dictionary, as read
+    epilog: String,
+}
+
+impl Document {
+    /// Constructs a new document with the given root dictionary and the prolog the game uses
+    pub fn new(root: Dictionary) -> Document {
+        Document {
+            root,
+            prolog: DEFAULT_PROLOG.to_string(),
+            epilog: DEFAULT_EPILOG.to_string(),
+        }
+    }
+
+    /// Constructs a new document with the given root dictionary, which keeps the prolog and epilog
+    /// of this document
+    pub fn with_root(&self, root: Dictionary) -> Document {
+        Document {
+            root,
+            prolog: self.prolog.clone(),
+            epilog: self.epilog.clone(),
+        }
+    }
+}
+
+/// The text content of a plist element, used for keys and for all values that aren't
+/// dictionaries or booleans
+///
+/// If the text was read from a document, its original, still escaped representation is
+/// remembered and written back unchanged.
+#[derive(Debug, Clone, Default)]
+pub struct Text {
+    value: String,
+    raw: Option<String>,
+}
+
+impl Text {
+    pub fn new(value: impl Into<String>) -> Text {
+        Text {
+            value: value.into(),
+            raw: None,
+        }
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.value
+    }
+
+    fn read(raw: &str) -> Text {
+        Text {
+            value: unescape(raw),
+            raw: Some(raw.to_string()),
+        }
+    }
+}
+
+impl Deref for Text {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.value
+    }
+}
+
+impl PartialEq for Text {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl Eq for Text {}
+
+impl Display for Text {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(&self.value)
+    }
+}
+
+impl From<String> for Text {
+    fn from(value: String) -> Self {
+        Text::new(value)
+    }
+}
+
+impl From<&str> for Text {
+    fn from(value: &str) -> Self {
+        Text::new(value)
+    }
+}
+
+/// A value stored in a plist [`Dictionary`]
+///
+/// Numbers are kept in their textual representation, so that they can be passed to the
+/// [`Parse`](crate::Parse) implementations just like the values of the API responses.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    /// An `<i>` (`<integer>`) value
+    Integer(Text),
+
+    /// An `<r>` (`<real>`) value
+    Real(Text),
+
+    /// An `<s>` (`<string>`) value
+    String(Text),
+
+    /// A `<t/>` (`<true/>`) or `<false/>` value
+    Bool(bool),
+
+    /// A nested `<d>` (`<dict>`) value
+    Dictionary(Dictionary),
+}
+
+/// A plist dictionary, with its entries in the order they appeared in the document
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Dictionary {
+    pub entries: Vec<(Text, Value)>,
+}
+
+impl Value {
+    /// Gets the textual representation of this value, in the format the [`Parse`](crate::Parse)
+    /// implementations expect, or [`None`] if this is a [`Value::Dictionary`]
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Value::Integer(value) | Value::Real(value) | Value::String(value) => Some(value.as_str()),
+            Value::Bool(true) => Some("1"),
+            Value::Bool(false) => Some("0"),
+            Value::Dictionary(_) => None,
+        }
+    }
+
+    pub fn as_dictionary(&self) -> Option<&Dictionary> {
+        match self {
+            Value::Dictionary(dict) => Some(dict),
+            _ => None,
+        }
+    }
+}
+
+impl Dictionary {
+    pub fn get(&self, key: &str) -> Option<&Value> {
+        self.entries.iter().find(|(k, _)| k.as_str() == key).map(|(_, value)| value)
+    }
+
+    /// Sets the value at the given key. Existing entries are updated in place, new entries are
+    /// appended to the end of the dictionary
+    pub fn insert(&mut self, key: &str, value: Value) {
+        match self.entries.iter_mut().find(|(k, _)| k.as_str() == key) {
+            Some((_, old)) => *old = value,
+            None => self.entries.push((key.into(), value)),
+        }
+    }
+
+    pub fn remove(&mut self, key: &str) -> Option<Value> {
+        let idx = self.entries.iter().position(|(k, _)| k.as_str() == key)?;
+
+        Some(self.entries.remove(idx).1)
+    }
+
+    /// Iterates over all entries of this dictionary that aren't nested dictionaries, in the
+    /// `(key, value)` format the [`Parse`](crate::Parse) implementations expect.
+    pub fn scalars(&self) -> impl Iterator<Item = (&str, &str)> + Clone {
+        self.entries
+            .iter()
+            .filter_map(|(key, value)| value.as_str().map(|value| (key.as_str(), value)))
+    }
+
+    /// Iterates over all entries of this dictionary whose values are nested dictionaries
+    pub fn dictionaries(&self) -> impl Iterator<Item = (&str, &Dictionary)> {
+        self.entries
+            .iter()
+            .filter_map(|(key, value)| value.as_dictionary().map(|dict| (key.as_str(), dict)))
+    }
+}
+
+/// Parses a plist XML document
+pub fn parse(document: &str) -> Result<Document, SavefileError> {
+    let mut reader = Reader { document, offset: 0 };
+
+    reader.skip_whitespace();
+
+    if reader.remaining().starts_with("<?xml") {
+        reader.skip_past("?>")?;
+    }
+
+    let (name, _) = reader.open_tag()?;
+
+    if name != "plist" {
+        return Err(reader.error(format!("expected <plist>, found <{}>", name)))
+    }
+
+    reader.skip_whitespace();
+
+    let root_start = reader.offset;
+
+    let (root, prolog, epilog) = match reader.open_tag()? {
+        ("d", false) | ("dict", false) => {
+            let prolog = document[..reader.offset].to_string();
+            let root = reader.entries()?;
+            let epilog_start = reader.offset;
+
+            reader.skip_past(">")?;
+            reader.close_tag("plist")?;
+
+            (root, prolog, document[epilog_start..].to_string())
+        },
+        // An empty root dictionary is normalized to the non-self-closing form, as it'd otherwise
+        // need special treatment when writing
+        ("d", true) | ("dict", true) => {
+            let root_end = reader.offset;
+
+            reader.close_tag("plist")?;
+
+            (
+                Dictionary::default(),
+                format!("{}<dict>", &document[..root_start]),
+                format!("</dict>{}", &document[root_end..]),
+            )
+        },
+        (name, _) => return Err(reader.error(format!("expected root dictionary, found <{}>", name))),
+    };
+
+    Ok(Document { root, prolog, epilog })
+}
+
+/// Writes the given [`Document`] into plist XML
+pub fn write(document: &Document) -> String {
+    let mut xml = document.prolog.clone();
+
+    write_entries(&mut xml, &document.root);
+
+    xml.push_str(&document.epilog);
+    xml
+}
+
+fn write_entries(document: &mut String, dict: &Dictionary) {
+    for (key, value) in &dict.entries {
+        write_element(document, "k", key);
+
+        match value {
+            Value::Integer(value) => write_element(document, "i", value),
+            Value::Real(value) => write_element(document, "r", value),
+            Value::String(value) => write_element(document, "s", value),
+            Value::Bool(true) => document.push_str("<t />"),
+            Value::Bool(false) => document.push_str("<f />"),
+            Value::Dictionary(dict) if dict.entries.is_empty() => document.push_str("<d />"),
+            Value::Dictionary(dict) => {
+                document.push_str("<d>");
+                write_entries(document, dict);
+                document.push_str("</d>");
+            },
+        }
+    }
+}
+
+fn write_element(document: &mut String, tag: &str, text: &Text) {
+    match text.raw {
+        Some(ref raw) => document.push_str(&format!("<{}>{}</{}>", tag, raw, tag)),
+        None if text.value.is_empty() => document.push_str(&format!("<{} />", tag)),
+        None => document.push_str(&format!("<{}>{}</{}>", tag, escape(&text.value), tag)),
+    }
+}
+
+struct Reader<'a> {
+    document: &'a str,
+    offset: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn remaining(&self) -> &'a str {
+        &self.document[self.offset..]
+    }
+
+    fn error(&self, cause: String) -> SavefileError {
+        SavefileError::Plist(self.offset, cause)
+    }
+
+    fn skip_whitespace(&mut self) {
+        let remaining = self.remaining();
+
+        self.offset += remaining.len() - remaining.trim_start().len();
+    }
+
+    fn skip_past(&mut self, pattern: &str) -> Result<(), SavefileError> {
+        match self.remaining().find(pattern) {
+            Some(idx) => self.offset += idx + pattern.len(),
+            None => return Err(self.error(format!("expected '{}'", pattern))),
+        }
+
+        Ok(())
+    }
+
+    /// Reads everything up to the next tag
+    fn text(&mut self) -> Result<Text, SavefileError> {
+        let remaining = self.remaining();
+
+        match remaining.find('<') {
+            Some(idx) => {
+                self.offset += idx;
+                Ok(Text::read(&remaining[..idx]))
+            },
+            None => Err(self.error("unexpected end of document".to_string())),
+        }
+    }
+
+    /// Reads an opening tag, returning its name and whether it was self-closing. Attributes are
+    /// ignored
+    fn open_tag(&mut self) -> Result<(&'a str, bool), SavefileError> {
+        self.skip_whitespace();
+
+        let remaining = self.remaining();
+
+        if !remaining.starts_with('<') || remaining.starts_with("</") {
+            return Err(self.error("expected opening tag".to_string()))
+        }
+
+        let end = match remaining.find('>') {
+            Some(end) => end,
+            None => return Err(self.error("unterminated tag".to_string())),
+        };
+
+        let content = &remaining[1..end];
+        let self_closing = content.ends_with('/');
+        let name = content.trim_end_matches('/').split_whitespace().next().unwrap_or_default();
+
+        self.offset += end + 1;
+
+        Ok((name, self_closing))
+    }
+
+    /// Reads the closing tag of the element with the given name
+    fn close_tag(&mut self, name: &str) -> Result<(), SavefileError> {
+        self.skip_whitespace();
+
+        let remaining = self.remaining();
+
+        if remaining.starts_with("</") && remaining[2..].starts_with(name) && remaining[2 + name.len()..].starts_with('>') {
+            self.offset += name.len() + 3;
+            Ok(())
+        } else {
+            Err(self.error(format!("expected </{}>", name)))
+        }
+    }
+
+    /// Reads the entries of a dictionary whose opening tag has already been read, up to and
+    /// including its closing tag
+    fn dictionary(&mut self) -> Result<Dictionary, SavefileError> {
+        let dictionary = self.entries()?;
+
+        self.skip_past(">")?;
+
+        Ok(dictionary)
+    }
+
+    /// Reads the entries of a dictionary whose opening tag has already been read, up to (but not
+    /// including) its closing tag
+    fn entries(&mut self) -> Result<Dictionary, SavefileError> {
+        let mut entries = Vec::new();
+
+        loop {
+            self.skip_whitespace();
+
+            if self.remaining().starts_with("</") {
+                return Ok(Dictionary { entries })
+            }
+
+            let key = match self.open_tag()? {
+                (name @ "k", false) | (name @ "key", false) => {
+                    let key = self.text()?;
+                    self.close_tag(name)?;
+                    key
+                },
+                (name, _) => return Err(self.error(format!("expected key, found <{}>", name))),
+            };
+
+            let value = self.value()?;
+
+            entries.push((key, value));
+        }
+    }
+
+    fn value(&mut self) -> Result<Value, SavefileError> {
+        let (name, self_closing) = self.open_tag()?;
+
+        let value = match name {
+            "d" | "dict" if self_closing => return Ok(Value::Dictionary(Dictionary::default())),
+            "d" | "dict" => return self.dictionary().map(Value::Dictionary),
+            "t" | "true" => Value::Bool(true),
+            "f" | "false" => Value::Bool(false),
+            "s" | "string" if self_closing => return Ok(Value::String(Text::default())),
+            "i" | "integer" if !self_closing => Value::Integer(self.text()?),
+            "r" | "real" if !self_closing => Value::Real(self.text()?),
+            "s" | "string" => Value::String(self.text()?),
+            _ => return Err(self.error(format!("unexpected value <{}>", name))),
+        };
+
+        if !self_closing {
+            self.close_tag(name)?;
+        }
+
+        Ok(value)
+    }
+}
+
+fn unescape(text: &str) -> String {
+    if !text.contains('&') {
+        return text.to_string()
+    }
+
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&apos;", "'")
+        .replace("&amp;", "&")
+}
+
+fn escape(text: &str) -> String {
+    if !text.contains(&['&', '<', '>', '"', '\''][..]) {
+        return text.to_string()
+    }
+
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
@@ -1,9 +1,11 @@
 use crate::{
-    convert::{TwitchConverter, TwitterConverter, TwoBool, YoutubeConverter},
+    convert::{Base64Converter, TwitchConverter, TwitterConverter, TwoBool, YoutubeConverter},
     error::ValueError,
     Parse,
 };
-use gdcf_model::user::{Creator, SearchedUser, User};
+use gdcf_model::user::{Creator, FriendRequest, LeaderboardEntry, ListedUser, SearchedUser, User};
+
+pub mod local;
 
 pub fn youtube(value: &str) -> Option<String> {
     if value.is_empty() {
@@ -90,3 +92,46 @@ parser! {
         user_coins(index = 17),
     }
 }
+
+parser! {
+    ListedUser => {
+        name(index = 1),
+        user_id(index = 2),
+        icon_index(index = 9),
+        primary_color(index = 10),
+        secondary_color(index = 11),
+        icon_type(index = 14),
+        has_glow(index = 15, parse = TwoBool),
+        account_id(index = 16),
+    }
+}
+
+parser! {
+    FriendRequest => {
+        user(delegate),
+        request_id(index = 32),
+        message(index = 35, parse_infallible = Base64Converter, default),
+        time_since_sent(index = 37),
+        unread(index = 41, default),
+    }
+}
+
+parser! {
+    LeaderboardEntry => {
+        rank(index = 6),
+        name(index = 1),
+        user_id(index = 2),
+        stars(index = 3),
+        demons(index = 4),
+        creator_points(index = 8),
+        icon_index(index = 9),
+        primary_color(index = 10),
+        secondary_color(index = 11),
+        secret_coins(index = 13),
+        icon_type(index = 14),
+        has_glow(index = 15, parse = TwoBool),
+        account_id(index = 16),
+        user_coins(index = 17),
+        diamonds(index = 46),
+    }
+}
@@ -0,0 +1,121 @@
+use crate::{
+    error::{SavefileError, ValueError},
+    savefile::{self, plist::Dictionary},
+    Parse,
+};
+use gdcf_model::{
+    user::{
+        local::{LocalPlayer, LocalProfile, PlayerStats, Unlockable},
+        Color,
+    },
+    GameMode,
+};
+
+/// Reads the player's profile out of the content of a `CCGameManager.dat` save file
+pub fn parse_game_manager(savefile: &[u8]) -> Result<LocalProfile, SavefileError> {
+    let root = savefile::read(savefile)?.root;
+
+    let player =
+        LocalPlayer::parse(root.scalars(), |_, _| Ok(())).map_err(|err| SavefileError::Value("root".to_string(), err.to_string()))?;
+
+    let stats = match root.get("GS_value").and_then(|value| value.as_dictionary()) {
+        Some(dict) =>
+            PlayerStats::parse(dict.scalars(), |_, _| Ok(()))
+                .map_err(|err| SavefileError::Value("GS_value".to_string(), err.to_string()))?,
+        None => PlayerStats::default(),
+    };
+
+    let completed = root.get("GS_completed").and_then(|value| value.as_dictionary());
+
+    Ok(LocalProfile {
+        player,
+        stats,
+        completed_levels: completed.map(|dict| prefixed_ids(dict, "c_")).unwrap_or_default(),
+        completed_main_levels: completed.map(|dict| prefixed_ids(dict, "n_")).unwrap_or_default(),
+        unlocked: root
+            .get("valueKeeper")
+            .and_then(|value| value.as_dictionary())
+            .map(unlocks)
+            .unwrap_or_default(),
+    })
+}
+
+/// Collects the IDs from all keys of the form `{prefix}{id}` in the given dictionary
+fn prefixed_ids(dict: &Dictionary, prefix: &str) -> Vec<u64> {
+    dict.scalars()
+        .filter_map(|(key, _)| key.strip_prefix(prefix)?.parse().ok())
+        .collect()
+}
+
+fn unlocks(dict: &Dictionary) -> Vec<Unlockable> {
+    dict.scalars()
+        .filter(|&(_, value)| value == "1")
+        .filter_map(|(key, _)| {
+            let mut split = key.splitn(2, '_');
+            let (kind, index) = (split.next()?, split.next()?);
+
+            Some(match kind {
+                "i" => Unlockable::Cube(index.parse().ok()?),
+                "ship" => Unlockable::Ship(index.parse().ok()?),
+                "ball" => Unlockable::Ball(index.parse().ok()?),
+                "bird" => Unlockable::Ufo(index.parse().ok()?),
+                "dart" => Unlockable::Wave(index.parse().ok()?),
+                "robot" => Unlockable::Robot(index.parse().ok()?),
+                "spider" => Unlockable::Spider(index.parse().ok()?),
+                "special" => Unlockable::Trail(index.parse().ok()?),
+                "death" => Unlockable::DeathEffect(index.parse().ok()?),
+                "c0" => Unlockable::PrimaryColor(index.parse().ok()?),
+                "c1" => Unlockable::SecondaryColor(index.parse().ok()?),
+                _ => return None,
+            })
+        })
+        .collect()
+}
+
+fn default_color() -> Color {
+    Color::from(0)
+}
+
+fn default_icon_type() -> GameMode {
+    GameMode::Cube
+}
+
+parser! {
+    LocalPlayer => {
+        name(index = playerName, default),
+        user_id(index = playerUserID, default),
+        account_id(index = GJA_003, default),
+        cube_index(index = playerFrame, default),
+        ship_index(index = playerShip, default),
+        ball_index(index = playerBall, default),
+        ufo_index(index = playerBird, default),
+        wave_index(index = playerDart, default),
+        robot_index(index = playerRobot, default),
+        spider_index(index = playerSpider, default),
+        trail_index(index = playerStreak, default),
+        death_effect_index(index = playerDeathEffect, default),
+        primary_color(index = playerColor, default_with = default_color),
+        secondary_color(index = playerColor2, default_with = default_color),
+        has_glow(index = playerGlow, default),
+        icon_type(index = playerIconType, default_with = default_icon_type),
+    }
+}
+
+parser! {
+    PlayerStats => {
+        jumps(index = 1, default),
+        attempts(index = 2, default),
+        completed_main_levels(index = 3, default),
+        completed_online_levels(index = 4, default),
+        demons(index = 5, default),
+        stars(index = 6, default),
+        map_packs(index = 7, default),
+        secret_coins(index = 8, default),
+        destroyed_players(index = 9, default),
+        liked_levels(index = 10, default),
+        rated_levels(index = 11, default),
+        user_coins(index = 12, default),
+        diamonds(index = 13, default),
+        orbs(index = 14, default),
+    }
+}
@@ -3,6 +3,11 @@ use crate::{
     error::ValueError,
 };
 use base64::{DecodeError, URL_SAFE};
+use sha1::{Digest, Sha1};
+
+const GJP_KEY: &str = "37526";
+const LEVEL_SEED_SALT: &str = "xI25fpAapCQg";
+const LEVEL_SEED_KEY: &str = "41274";
 
 #[derive(Debug, Clone)]
 pub struct SelfZip<I> {
@@ -55,6 +60,59 @@ pub fn xor_decrypt(encrypted: &str, key: &str) -> String {
         .collect()
 }
 
+/// Encodes the given plain text password using robtop's "GJP" scheme, which is how account
+/// passwords are transmitted to the boomlings servers
+///
+/// This XORs the password with the key `37526` and URL-safe base64 encodes the result.
+pub fn encode_gjp(password: &str) -> String {
+    base64::encode_config(xor_decrypt(password, GJP_KEY).as_bytes(), URL_SAFE)
+}
+
+/// Decodes the given GJP-encoded password back into plain text. This is the inverse of
+/// [`encode_gjp`]
+///
+/// # Errors
+/// If the given string isn't valid URL-safe base64, a [`DecodeError`] is
+/// returned
+pub fn decode_gjp(gjp: &str) -> Result<String, DecodeError> {
+    b64_decode_string(gjp).map(|xored| xor_decrypt(&xored, GJP_KEY))
+}
+
+/// Computes one of robtop's request checksums (the `chk` field of most requests that modify data
+/// on the servers) over the given values.
+///
+/// The values are concatenated together with the given salt, SHA-1 hashed, XORed with `key` and
+/// finally URL-safe base64 encoded.
+pub fn checksum(values: &[&str], salt: &str, key: &str) -> String {
+    let mut hasher = Sha1::new();
+
+    for value in values {
+        hasher.input(value.as_bytes());
+    }
+    hasher.input(salt.as_bytes());
+
+    let hash = format!("{:x}", hasher.result());
+
+    base64::encode_config(xor_decrypt(&hash, key).as_bytes(), URL_SAFE)
+}
+
+/// Computes the checksum over a level's data which has to be sent alongside it when uploading it
+/// (the `seed2` field of an `uploadGJLevel21` request)
+///
+/// Only (up to) 50 evenly spaced characters of the level string are used for the checksum.
+pub fn level_upload_seed(level_string: &str) -> String {
+    let bytes = level_string.as_bytes();
+
+    if bytes.len() < 50 {
+        return checksum(&[level_string], LEVEL_SEED_SALT, LEVEL_SEED_KEY)
+    }
+
+    let step = bytes.len() / 50;
+    let sample: String = bytes.iter().step_by(step).take(50).map(|&byte| byte as char).collect();
+
+    checksum(&[&sample], LEVEL_SEED_SALT, LEVEL_SEED_KEY)
+}
+
 pub fn parse<'a, T>(idx: &'a str, value: &'a str) -> Result<Option<T>, ValueError<'a>>
 where
     T: RobtopFrom<T, &'a str>,
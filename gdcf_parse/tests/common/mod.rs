@@ -0,0 +1,52 @@
+//! Helpers shared by the tests comparing unparsed data to the original strings
+
+// Not every test uses every helper
+#![allow(dead_code)]
+
+use std::collections::HashMap;
+
+/// Splits the given `,`-separated section of a level string into its `(key, value)` pairs
+pub fn properties(section: &str) -> HashMap<&str, &str> {
+    let parts: Vec<_> = section.split(',').collect();
+
+    parts.chunks(2).map(|pair| (pair[0], pair[1])).collect()
+}
+
+/// Splits the given level string into its metadata section and its objects
+pub fn sections(level_string: &str) -> Vec<HashMap<&str, &str>> {
+    level_string
+        .split(';')
+        .filter(|section| !section.is_empty())
+        .map(properties)
+        .collect()
+}
+
+/// Asserts that the `unparsed` section contains every property of the `original` one. Properties
+/// with the value `0` can be omitted, as that is what the game assumes for missing properties
+pub fn assert_preserved(original: &HashMap<&str, &str>, unparsed: &HashMap<&str, &str>) {
+    for (key, value) in original {
+        match unparsed.get(key) {
+            Some(unparsed_value) => assert_eq!(unparsed_value, value, "value at key {} changed", key),
+            None => assert_eq!(*value, "0", "property {},{} was lost", key, value),
+        }
+    }
+
+    for key in unparsed.keys() {
+        assert!(original.contains_key(key), "property at key {} was added", key)
+    }
+}
+
+/// Asserts that no key appears more than once in the given `,`-separated section
+pub fn assert_unique_keys(section: &str) {
+    let parts: Vec<_> = section.split(',').collect();
+    let mut seen = HashMap::new();
+
+    for pair in parts.chunks(2) {
+        assert!(
+            seen.insert(pair[0], pair[1]).is_none(),
+            "key {} appears twice in {}",
+            pair[0],
+            section
+        );
+    }
+}
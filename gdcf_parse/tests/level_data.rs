@@ -0,0 +1,76 @@
+use gdcf_model::{
+    level::data::{portal::Speed, LevelInformationSource},
+    GameMode,
+};
+use gdcf_parse::level::data::{parse_lazy, unparse_level};
+
+mod common;
+
+use common::{assert_preserved, sections};
+
+const LEVEL_STRING: &str = include_str!("fixtures/level_string.txt");
+
+#[test]
+fn level_string_round_trip() {
+    let source = parse_lazy(LEVEL_STRING).unwrap();
+    let metadata = source.metadata();
+    let objects = source.collect();
+
+    let unparsed = unparse_level(metadata.clone(), objects.clone());
+    let original_sections = sections(LEVEL_STRING);
+    let unparsed_sections = sections(&unparsed);
+
+    assert_eq!(original_sections.len(), unparsed_sections.len());
+
+    for (original, unparsed) in original_sections.iter().zip(&unparsed_sections) {
+        assert_preserved(original, unparsed);
+    }
+
+    let reparsed = parse_lazy(&unparsed).unwrap();
+
+    assert_eq!(reparsed.metadata(), metadata);
+    assert_eq!(reparsed.collect(), objects);
+}
+
+#[test]
+fn metadata_keeps_unknown_properties() {
+    let metadata = parse_lazy(LEVEL_STRING).unwrap().metadata();
+
+    // Color channels, background, ground, ground line, font and guidelines aren't modelled
+    for key in &["kS38", "kA6", "kA7", "kA17", "kA18", "kA14", "kS39", "kA9"] {
+        assert!(metadata.unknown_properties.contains_key(*key), "{} not kept", key);
+    }
+}
+
+#[test]
+fn unknown_trigger_modes_are_kept() {
+    // A touch trigger with toggle mode 3 and an instant count trigger with comparison 3
+    let header = LEVEL_STRING.split(';').next().unwrap();
+    let level_string = format!("{};1,1595,2,15,3,15,51,1,82,3;1,1811,2,45,3,15,51,1,80,2,88,3;", header);
+
+    let source = parse_lazy(&level_string).unwrap();
+    let metadata = source.metadata();
+    let objects = source.collect();
+
+    assert_eq!(objects.len(), 2);
+
+    let unparsed = unparse_level(metadata, objects);
+    let unparsed_sections = sections(&unparsed);
+
+    assert_eq!(unparsed_sections[1].get("82"), Some(&"3"));
+    assert_eq!(unparsed_sections[2].get("88"), Some(&"3"));
+}
+
+#[test]
+fn timeline_only_requires_speed_portals_to_be_checked() {
+    let header = LEVEL_STRING.split(';').next().unwrap();
+    // An unchecked ship portal at x = 300, an unchecked fast portal at x = 600, a block at x = 900.
+    // The starting speed in the header is normal speed
+    let level_string = format!("{};1,13,2,300,3,15;1,203,2,600,3,15;1,1,2,900,3,15;", header);
+
+    let timeline = parse_lazy(&level_string).unwrap().timeline();
+
+    assert!((timeline.game_mode_share(GameMode::Ship) - 2.0 / 3.0).abs() < 1e-4);
+    assert_eq!(timeline.speed_share(Speed::Fast), 0.0);
+    assert_eq!(timeline.speed_portals().count(), 0);
+}
@@ -0,0 +1,91 @@
+use gdcf_model::level::data::{LevelObject, ObjectData};
+use gdcf_parse::Parse;
+
+mod common;
+
+use common::{assert_preserved, assert_unique_keys, properties};
+
+/// Object strings as found in real levels, one per line. Covers decorations with groups and
+/// colors, every kind of trigger GDCF has a parser for, and portals
+const OBJECTS: &str = include_str!("fixtures/objects.txt");
+
+/// The keys consumed by [`TriggerFlags`](gdcf_model::level::data::trigger::TriggerFlags), which
+/// are parsed by a delegate nested two levels deep
+const TRIGGER_FLAGS: [&str; 3] = ["11", "62", "87"];
+
+fn objects() -> impl Iterator<Item = &'static str> {
+    OBJECTS.lines().filter(|line| !line.is_empty())
+}
+
+#[test]
+fn object_round_trip() {
+    for object_string in objects() {
+        let object = LevelObject::parse_str(object_string, ',').unwrap();
+        let unparsed = object.clone().unparse_str(',');
+
+        assert_unique_keys(&unparsed);
+        assert_preserved(&properties(object_string), &properties(&unparsed));
+        assert_eq!(LevelObject::parse_str(&unparsed, ',').unwrap(), object);
+    }
+}
+
+#[test]
+fn objects_get_specific_metadata() {
+    for object_string in objects() {
+        let object = LevelObject::parse_str(object_string, ',').unwrap();
+
+        match object.id {
+            1764 | 211 => assert_eq!(object.metadata, ObjectData::None, "{}", object_string),
+            _ => assert_ne!(object.metadata, ObjectData::None, "{}", object_string),
+        }
+    }
+}
+
+#[test]
+fn delegated_keys_are_not_unknown() {
+    for object_string in objects() {
+        let object = LevelObject::parse_str(object_string, ',').unwrap();
+
+        // Properties common to all objects, and those specific to the object's metadata
+        for key in &["1", "2", "3", "6", "21", "57", "13", "51", "10"] {
+            assert!(
+                !object.unknown_properties.contains_key(*key),
+                "key {} of {} ended up in unknown_properties",
+                key,
+                object_string
+            );
+        }
+
+        if let ObjectData::None = object.metadata {
+            continue
+        }
+
+        for key in &TRIGGER_FLAGS {
+            assert!(
+                !object.unknown_properties.contains_key(*key),
+                "trigger flag {} of {} ended up in unknown_properties",
+                key,
+                object_string
+            );
+        }
+    }
+}
+
+#[test]
+fn decoration_properties() {
+    let decoration = LevelObject::parse_str(objects().next().unwrap(), ',').unwrap();
+
+    assert_eq!(decoration.groups, vec![3, 7, 12]);
+    assert_eq!(decoration.main_color, 1004);
+    assert_eq!(decoration.secondary_color, 1005);
+    assert_eq!(decoration.z_layer, -1);
+    assert_eq!(decoration.z_order, 2);
+    assert_eq!(decoration.scale, 0.5);
+    assert_eq!(decoration.editor_layer_1, 1);
+    assert_eq!(decoration.editor_layer_2, 2);
+
+    // HSV values, glow and the like aren't modelled yet
+    for key in &["41", "43", "64", "67", "155"] {
+        assert!(decoration.unknown_properties.contains_key(*key), "{} not kept", key);
+    }
+}
@@ -0,0 +1,40 @@
+use gdcf_parse::{
+    level::local::LocalLevels,
+    savefile::{self, plist},
+};
+
+const LOCAL_LEVELS: &str = include_str!("fixtures/CCLocalLevels.xml");
+
+#[test]
+fn plist_round_trip() {
+    let document = plist::parse(LOCAL_LEVELS).unwrap();
+
+    assert_eq!(plist::write(&document), LOCAL_LEVELS);
+}
+
+#[test]
+fn local_levels_round_trip() {
+    let levels = LocalLevels::read(&savefile::encrypt(LOCAL_LEVELS)).unwrap();
+
+    assert_eq!(levels.levels.len(), 2);
+    assert_eq!(levels.levels[0].level.name, "Tom & Jerry's \"Level\"");
+    assert_eq!(savefile::decrypt(&levels.write()).unwrap(), LOCAL_LEVELS);
+}
+
+#[test]
+fn local_levels_modification() {
+    let mut levels = LocalLevels::read(&savefile::encrypt(LOCAL_LEVELS)).unwrap();
+
+    levels.levels[1].level.name = "Renamed & \"quoted\"".to_string();
+
+    let written = savefile::decrypt(&levels.write()).unwrap();
+
+    assert_eq!(
+        written,
+        LOCAL_LEVELS.replace("<s>Second &lt;3</s>", "<s>Renamed &amp; &quot;quoted&quot;</s>")
+    );
+
+    let reread = LocalLevels::read(&savefile::encrypt(&written)).unwrap();
+
+    assert_eq!(reread.levels[1].level.name, "Renamed & \"quoted\"");
+}
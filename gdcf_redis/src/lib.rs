@@ -0,0 +1,529 @@
+//! [`Cache`](gdcf::cache::Cache) implementation for use with `gdcf`, storing objects in a redis
+//! (or any other server speaking the redis protocol)
+//!
+//! Objects are stored as JSON, using the serde support of `gdcf_model`. Unlike the database caches,
+//! this cache doesn't keep expired entries around: every entry is stored with a time-to-live
+//! derived from the [`Expiry`] configuration, and is removed by the server once it runs out.
+//! Lookups thus never yield expired entries, only missing ones.
+
+#![deny(unused_must_use)]
+#![deny(unused_imports)]
+
+mod write;
+
+use chrono::{Duration, NaiveDateTime, Utc};
+use failure::Fail;
+use gdcf::{
+    api::request::{
+        BlockedUsersRequest, DailyLevelRequest, FriendListRequest, FriendRequestsRequest, GauntletsRequest, LeaderboardRequest,
+        LevelCommentsRequest, LevelRequest, LevelScoresRequest, LevelsRequest, MapPacksRequest, MessageRequest, MessagesRequest,
+        ProfileCommentsRequest, SongInfoRequest, UserRequest, UserSearchRequest,
+    },
+    cache::{
+        refresh_seconds_left, special_level_expiry, CacheEntry, CacheEntryMeta, CommentUserKey, CreatorKey, Key, KeyPath, Lookup,
+        NewgroundsSongKey, Store,
+    },
+    error::CacheError,
+};
+use gdcf_model::level::{DailyLevel, Level};
+use log::{debug, warn};
+use r2d2::{Pool, PooledConnection};
+use redis::{Client, Commands};
+use serde::{de::DeserializeOwned, Serialize};
+use serde_derive::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+
+/// Struct describing after how long the different kinds of cached objects are removed from the
+/// cache
+///
+/// Each of the durations is used as the time-to-live of the corresponding redis entries. Since
+/// redis only supports a resolution of seconds here, every duration is rounded down to full
+/// seconds, but is at least one second.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Expiry {
+    /// Expiry of the results of [`LevelsRequest`]s
+    pub level_list: Duration,
+
+    /// Expiry of full levels retrieved via [`LevelRequest`]s. The daily level and weekly demon are
+    /// instead removed once they're replaced, if it's known when that happens.
+    pub level: Duration,
+
+    /// Expiry of newgrounds songs
+    pub song: Duration,
+
+    /// Expiry of creators
+    pub creator: Duration,
+
+    /// Expiry of user profiles and the results of [`UserSearchRequest`]s
+    pub profile: Duration,
+
+    /// Expiry of the results of [`LevelCommentsRequest`]s
+    pub level_comments: Duration,
+
+    /// Expiry of the results of [`ProfileCommentsRequest`]s
+    pub profile_comments: Duration,
+
+    /// Expiry of the user data provided alongside level comments
+    pub comment_user: Duration,
+
+    /// Expiry of the leaderboards of single levels
+    pub level_scores: Duration,
+
+    /// Expiry of the results of [`MapPacksRequest`]s
+    pub map_packs: Duration,
+
+    /// Expiry of the list of gauntlets
+    pub gauntlets: Duration,
+
+    /// Expiry of the results of [`MessagesRequest`]s and [`MessageRequest`]s
+    pub messages: Duration,
+
+    /// Expiry of friend lists and lists of blocked users
+    pub user_lists: Duration,
+
+    /// Expiry of the results of [`FriendRequestsRequest`]s
+    pub friend_requests: Duration,
+
+    /// Expiry of the global leaderboards
+    pub leaderboard: Duration,
+
+    /// Expiry of entries that have been marked as absent, regardless of their kind
+    pub absent: Duration,
+}
+
+impl Expiry {
+    /// Constructs a new [`Expiry`] that uses the given [`Duration`] for every kind of object
+    pub fn uniform(duration: Duration) -> Expiry {
+        Expiry {
+            level_list: duration,
+            level: duration,
+            song: duration,
+            creator: duration,
+            profile: duration,
+            level_comments: duration,
+            profile_comments: duration,
+            comment_user: duration,
+            level_scores: duration,
+            map_packs: duration,
+            gauntlets: duration,
+            messages: duration,
+            user_lists: duration,
+            friend_requests: duration,
+            leaderboard: duration,
+            absent: duration,
+        }
+    }
+
+    /// Sets the expiry of [`LevelsRequest`] results
+    pub fn level_list(mut self, duration: Duration) -> Self {
+        self.level_list = duration;
+        self
+    }
+
+    /// Sets the expiry of full levels
+    pub fn level(mut self, duration: Duration) -> Self {
+        self.level = duration;
+        self
+    }
+
+    /// Sets the expiry of newgrounds songs
+    pub fn song(mut self, duration: Duration) -> Self {
+        self.song = duration;
+        self
+    }
+
+    /// Sets the expiry of creators
+    pub fn creator(mut self, duration: Duration) -> Self {
+        self.creator = duration;
+        self
+    }
+
+    /// Sets the expiry of user profiles and [`UserSearchRequest`] results
+    pub fn profile(mut self, duration: Duration) -> Self {
+        self.profile = duration;
+        self
+    }
+
+    /// Sets the expiry of [`LevelCommentsRequest`] results
+    pub fn level_comments(mut self, duration: Duration) -> Self {
+        self.level_comments = duration;
+        self
+    }
+
+    /// Sets the expiry of [`ProfileCommentsRequest`] results
+    pub fn profile_comments(mut self, duration: Duration) -> Self {
+        self.profile_comments = duration;
+        self
+    }
+
+    /// Sets the expiry of the user data provided alongside level comments
+    pub fn comment_user(mut self, duration: Duration) -> Self {
+        self.comment_user = duration;
+        self
+    }
+
+    /// Sets the expiry of the leaderboards of single levels
+    pub fn level_scores(mut self, duration: Duration) -> Self {
+        self.level_scores = duration;
+        self
+    }
+
+    /// Sets the expiry of [`MapPacksRequest`] results
+    pub fn map_packs(mut self, duration: Duration) -> Self {
+        self.map_packs = duration;
+        self
+    }
+
+    /// Sets the expiry of the list of gauntlets
+    pub fn gauntlets(mut self, duration: Duration) -> Self {
+        self.gauntlets = duration;
+        self
+    }
+
+    /// Sets the expiry of [`MessagesRequest`] and [`MessageRequest`] results
+    pub fn messages(mut self, duration: Duration) -> Self {
+        self.messages = duration;
+        self
+    }
+
+    /// Sets the expiry of friend lists and lists of blocked users
+    pub fn user_lists(mut self, duration: Duration) -> Self {
+        self.user_lists = duration;
+        self
+    }
+
+    /// Sets the expiry of [`FriendRequestsRequest`] results
+    pub fn friend_requests(mut self, duration: Duration) -> Self {
+        self.friend_requests = duration;
+        self
+    }
+
+    /// Sets the expiry of the global leaderboards
+    pub fn leaderboard(mut self, duration: Duration) -> Self {
+        self.leaderboard = duration;
+        self
+    }
+
+    /// Sets the expiry of absent markers
+    pub fn absent(mut self, duration: Duration) -> Self {
+        self.absent = duration;
+        self
+    }
+}
+
+#[derive(Clone)]
+pub struct Cache {
+    pool: Pool<Client>,
+
+    /// Prefix of the keys of all entries created by this cache, allowing multiple caches (or other
+    /// applications) to share a server
+    prefix: String,
+
+    expiry: Expiry,
+}
+
+impl Cache {
+    /// Constructs a new [`Cache`] connecting to the server at the given url (e.g.
+    /// `redis://127.0.0.1:6379/0`)
+    pub fn connect(url: &str) -> Result<Self, Error> {
+        Ok(Cache {
+            pool: Pool::new(Client::open(url)?)?,
+            prefix: "gdcf".to_string(),
+            expiry: Expiry::uniform(Duration::minutes(60)),
+        })
+    }
+
+    /// Sets the [`Expiry`] configuration to use for this cache. Only affects entries stored after
+    /// the change.
+    ///
+    /// Allows builder-style configuration of the cache
+    pub fn with_expiry(mut self, expiry: Expiry) -> Self {
+        self.expiry = expiry;
+        self
+    }
+
+    /// Sets the prefix of the keys used by this cache. Defaults to `gdcf`.
+    ///
+    /// Allows builder-style configuration of the cache
+    pub fn with_prefix(mut self, prefix: impl Into<String>) -> Self {
+        self.prefix = prefix.into();
+        self
+    }
+
+    /// Gets the [`Expiry`] configuration used by this cache
+    pub fn expiry(&self) -> Expiry {
+        self.expiry
+    }
+
+    pub fn prefix(&self) -> &str {
+        &self.prefix
+    }
+
+    fn connection(&self) -> Result<PooledConnection<Client>, Error> {
+        Ok(self.pool.get()?)
+    }
+
+    fn full_key(&self, key: &impl KeyPath) -> String {
+        format!("{}:{}", self.prefix, key.key_path().join(":"))
+    }
+
+    fn lookup_entry<K>(&self, key: &K) -> Result<CacheEntry<K::Result, Entry>, Error>
+    where
+        K: KeyPath,
+        K::Result: DeserializeOwned,
+    {
+        let stored: Option<String> = self.connection()?.get(self.full_key(key))?;
+
+        let stored: Stored<K::Result> = match stored {
+            Some(stored) => serde_json::from_str(&stored)?,
+            None => return Ok(CacheEntry::Missing),
+        };
+
+        let entry = Entry {
+            cached_at: stored.cached_at,
+            absent: stored.object.is_none(),
+        };
+
+        Ok(match stored.object {
+            Some(object) => CacheEntry::Cached(object, entry),
+            None => CacheEntry::MarkedAbsent(entry),
+        })
+    }
+
+    fn store_entry<K>(&self, key: &K, object: Option<&K::Result>, expire_after: Duration) -> Result<Entry, Error>
+    where
+        K: KeyPath,
+        K::Result: Serialize,
+    {
+        let stored = Stored {
+            cached_at: Utc::now().naive_utc(),
+            object,
+        };
+        let expire_after = if object.is_none() { self.expiry.absent } else { expire_after };
+
+        // `SET` with the `EX` option instead of `SETEX`, so that the cache only relies on the
+        // commands every stand-in server implements
+        redis::cmd("SET")
+            .arg(self.full_key(key))
+            .arg(serde_json::to_string(&stored)?)
+            .arg("EX")
+            .arg(ttl(expire_after))
+            .query::<()>(&mut *self.connection()?)?;
+
+        Ok(Entry {
+            cached_at: stored.cached_at,
+            absent: object.is_none(),
+        })
+    }
+
+    /// Removes the entry with the given key
+    pub(crate) fn remove(&self, key: &impl KeyPath) -> Result<(), Error> {
+        Ok(self.connection()?.del(self.full_key(key))?)
+    }
+
+    /// Removes all entries whose keys match the given glob-style pattern, which is relative to the
+    /// prefix of this cache
+    pub(crate) fn remove_matching(&self, pattern: &str) -> Result<(), Error> {
+        let mut connection = self.connection()?;
+        let keys: Vec<String> = connection.scan_match(format!("{}:{}", self.prefix, pattern))?.collect();
+
+        if !keys.is_empty() {
+            connection.del::<_, ()>(keys)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Converts the given [`Duration`] into a time-to-live in seconds, as expected by redis
+fn ttl(duration: Duration) -> usize {
+    duration.num_seconds().max(1) as usize
+}
+
+/// The value of a redis entry
+#[derive(Debug, Serialize, Deserialize)]
+struct Stored<T> {
+    cached_at: NaiveDateTime,
+
+    /// The cached object, or [`None`] if the key was marked as absent
+    object: Option<T>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Entry {
+    cached_at: NaiveDateTime,
+    absent: bool,
+}
+
+impl Entry {
+    pub fn cached_at(&self) -> NaiveDateTime {
+        self.cached_at
+    }
+}
+
+impl Display for Entry {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "Redis entry, cached at {}, absent: {}", self.cached_at, self.absent)
+    }
+}
+
+impl CacheEntryMeta for Entry {
+    // Expired entries are removed by the server
+    fn is_expired(&self) -> bool {
+        false
+    }
+
+    fn is_absent(&self) -> bool {
+        self.absent
+    }
+}
+
+#[derive(Debug, Fail)]
+pub enum Error {
+    #[fail(display = "Redis error: {}", _0)]
+    Redis(#[cause] redis::RedisError),
+
+    #[fail(display = "Connection pool error: {}", _0)]
+    R2D2(#[cause] r2d2::Error),
+
+    #[fail(display = "(De)serialization error: {}", _0)]
+    Serde(#[cause] serde_json::Error),
+}
+
+impl From<redis::RedisError> for Error {
+    fn from(err: redis::RedisError) -> Self {
+        Error::Redis(err)
+    }
+}
+
+impl From<r2d2::Error> for Error {
+    fn from(err: r2d2::Error) -> Self {
+        Error::R2D2(err)
+    }
+}
+
+impl From<serde_json::Error> for Error {
+    fn from(err: serde_json::Error) -> Self {
+        Error::Serde(err)
+    }
+}
+
+impl CacheError for Error {}
+
+impl gdcf::cache::Cache for Cache {
+    type CacheEntryMeta = Entry;
+    type Err = Error;
+}
+
+macro_rules! cache {
+    ($($key_type: ty => $expiry: ident),*) => {
+        $(
+            impl Lookup<$key_type> for Cache {
+                fn lookup(&self, key: &$key_type) -> Result<CacheEntry<<$key_type as Key>::Result, Entry>, Self::Err> {
+                    self.lookup_entry(key)
+                }
+            }
+
+            impl Store<$key_type> for Cache {
+                fn store(&mut self, object: &<$key_type as Key>::Result, key: &$key_type) -> Result<Entry, Self::Err> {
+                    debug!("Storing result of {} with key {}", stringify!($key_type), key);
+
+                    self.store_entry(key, Some(object), self.expiry.$expiry)
+                }
+
+                fn mark_absent(&mut self, key: &$key_type) -> Result<Entry, Self::Err> {
+                    warn!("Marking {} with key {} as absent!", stringify!($key_type), key);
+
+                    self.store_entry(key, None, self.expiry.$expiry)
+                }
+            }
+        )*
+    };
+}
+
+cache!(
+    LevelsRequest => level_list,
+    LevelScoresRequest => level_scores,
+    LevelCommentsRequest => level_comments,
+    ProfileCommentsRequest => profile_comments,
+    MapPacksRequest => map_packs,
+    GauntletsRequest => gauntlets,
+    MessagesRequest => messages,
+    MessageRequest => messages,
+    LeaderboardRequest => leaderboard,
+    UserRequest => profile,
+    UserSearchRequest => profile,
+    FriendListRequest => user_lists,
+    BlockedUsersRequest => user_lists,
+    FriendRequestsRequest => friend_requests,
+    SongInfoRequest => song,
+    NewgroundsSongKey => song,
+    CreatorKey => creator,
+    CommentUserKey => comment_user
+);
+
+impl Lookup<DailyLevelRequest> for Cache {
+    fn lookup(&self, key: &DailyLevelRequest) -> Result<CacheEntry<DailyLevel, Entry>, Self::Err> {
+        let (mut daily, entry) = match self.lookup_entry(key)? {
+            CacheEntry::Cached(daily, entry) => (daily, entry),
+            other => return Ok(other),
+        };
+
+        refresh_seconds_left(&mut daily, entry.cached_at);
+
+        Ok(CacheEntry::Cached(daily, entry))
+    }
+}
+
+impl Store<DailyLevelRequest> for Cache {
+    fn store(&mut self, daily: &DailyLevel, key: &DailyLevelRequest) -> Result<Entry, Self::Err> {
+        debug!("Storing {} under key {}", daily, key);
+
+        // The level cached as the daily level might have been replaced since it was cached, and
+        // it's too late to give it an appropriate time-to-live now
+        self.remove(&key.level_request())?;
+
+        self.store_entry(key, Some(daily), Duration::seconds(daily.seconds_left.into()))
+    }
+
+    fn mark_absent(&mut self, key: &DailyLevelRequest) -> Result<Entry, Self::Err> {
+        warn!("Marking result of DailyLevelRequest with key {} as absent!", key);
+
+        self.store_entry(key, None, self.expiry.absent)
+    }
+}
+
+impl Lookup<LevelRequest> for Cache {
+    fn lookup(&self, key: &LevelRequest) -> Result<CacheEntry<Level<Option<u64>, u64>, Entry>, Self::Err> {
+        self.lookup_entry(key)
+    }
+}
+
+impl Store<LevelRequest> for Cache {
+    fn store(&mut self, level: &Level<Option<u64>, u64>, key: &LevelRequest) -> Result<Entry, Self::Err> {
+        debug!("Storing {} under key {}", level, key);
+
+        let now = Utc::now().naive_utc();
+        let expires_at = match key.daily_level_request() {
+            Some(daily_request) =>
+                match self.lookup_entry(&daily_request)? {
+                    CacheEntry::Cached(daily, entry) => special_level_expiry(&daily, entry.cached_at, now),
+                    _ => None,
+                },
+            None => None,
+        };
+
+        let expire_after = match expires_at {
+            Some(expires_at) => expires_at - now,
+            None => self.expiry.level,
+        };
+
+        self.store_entry(key, Some(level), expire_after)
+    }
+
+    fn mark_absent(&mut self, key: &LevelRequest) -> Result<Entry, Self::Err> {
+        warn!("Marking level with key {} as absent!", key);
+
+        self.store_entry(key, None, self.expiry.level)
+    }
+}
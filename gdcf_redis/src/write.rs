@@ -0,0 +1,26 @@
+use crate::{Cache, Error};
+use gdcf::{
+    api::request::LevelRequest,
+    cache::{path, InvalidateObjects},
+};
+
+impl Cache {
+    /// Removes all entries whose paths start with the given one
+    fn remove_below(&self, path: Vec<String>) -> Result<(), Error> {
+        self.remove_matching(&format!("{}:*", path.join(":")))
+    }
+}
+
+impl InvalidateObjects for Cache {
+    fn invalidate_level(&mut self, level_id: u64) -> Result<(), Self::Err> {
+        self.remove(&LevelRequest::new(level_id))
+    }
+
+    fn invalidate_level_comments(&mut self, level_id: u64) -> Result<(), Self::Err> {
+        self.remove_below(path::level_comments(level_id))
+    }
+
+    fn invalidate_profile_comments(&mut self, account_id: u64) -> Result<(), Self::Err> {
+        self.remove_below(path::profile_comments(account_id))
+    }
+}
@@ -0,0 +1,181 @@
+//! Tests running the cache against a minimal stand-in server, which only implements the commands
+//! the README promises to be sufficient
+
+use chrono::Duration;
+use gdcf::{
+    api::request::{DailyLevelRequest, LevelCommentsRequest},
+    cache::{CacheEntry, CreatorKey, InvalidateObjects, Lookup, Store},
+};
+use gdcf_model::{level::DailyLevel, user::Creator};
+use gdcf_redis::{Cache, Expiry};
+use std::{
+    collections::HashMap,
+    io::{BufRead, BufReader, Write},
+    net::{TcpListener, TcpStream},
+    sync::{Arc, Mutex},
+    thread,
+    time::Instant,
+};
+
+type Entries = Arc<Mutex<HashMap<String, (String, Instant)>>>;
+
+/// Starts a stand-in server on a random port, returning the url to connect to
+fn start_server() -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("redis://{}/0", listener.local_addr().unwrap());
+    let store = Entries::default();
+
+    thread::spawn(move || {
+        for stream in listener.incoming() {
+            let store = store.clone();
+
+            thread::spawn(move || serve(stream.unwrap(), &store));
+        }
+    });
+
+    url
+}
+
+fn serve(stream: TcpStream, store: &Entries) {
+    let mut reader = BufReader::new(stream.try_clone().unwrap());
+    let mut writer = stream;
+
+    while let Some(command) = read_command(&mut reader) {
+        let args: Vec<&str> = command.iter().map(String::as_str).collect();
+        let mut store = store.lock().unwrap();
+
+        store.retain(|_, (_, expires_at)| *expires_at > Instant::now());
+
+        let response = match args.as_slice() {
+            ["PING"] => "+PONG\r\n".to_string(),
+            ["GET", key] =>
+                match store.get(*key) {
+                    Some((value, _)) => bulk(value),
+                    None => "$-1\r\n".to_string(),
+                },
+            ["SET", key, value, "EX", seconds] => {
+                let expires_at = Instant::now() + std::time::Duration::from_secs(seconds.parse().unwrap());
+
+                store.insert(key.to_string(), (value.to_string(), expires_at));
+
+                "+OK\r\n".to_string()
+            },
+            ["DEL", keys @ ..] => format!(":{}\r\n", keys.iter().filter(|key| store.remove(**key).is_some()).count()),
+            ["SCAN", "0", "MATCH", pattern, ..] => {
+                let prefix = pattern.strip_suffix('*').expect("only prefix patterns are supported");
+                let keys: Vec<_> = store.keys().filter(|key| key.starts_with(prefix)).map(|key| bulk(key)).collect();
+
+                format!("*2\r\n{}*{}\r\n{}", bulk("0"), keys.len(), keys.concat())
+            },
+            _ => format!("-ERR unsupported command {:?}\r\n", args),
+        };
+
+        writer.write_all(response.as_bytes()).unwrap();
+    }
+}
+
+fn bulk(value: &str) -> String {
+    format!("${}\r\n{}\r\n", value.len(), value)
+}
+
+/// Reads a command (an array of bulk strings) from the given connection, or [`None`] if it was
+/// closed
+fn read_command(reader: &mut impl BufRead) -> Option<Vec<String>> {
+    let header = read_line(reader)?;
+    let count: usize = header.strip_prefix('*').unwrap().parse().unwrap();
+
+    (0..count)
+        .map(|_| {
+            let length: usize = read_line(reader)?.strip_prefix('$').unwrap().parse().unwrap();
+            let mut value = vec![0; length + 2];
+
+            reader.read_exact(&mut value).ok()?;
+            value.truncate(length);
+
+            Some(String::from_utf8(value).unwrap())
+        })
+        .collect()
+}
+
+fn read_line(reader: &mut impl BufRead) -> Option<String> {
+    let mut line = String::new();
+
+    match reader.read_line(&mut line) {
+        Ok(0) | Err(_) => None,
+        Ok(_) => Some(line.trim_end().to_string()),
+    }
+}
+
+fn creator() -> Creator {
+    Creator {
+        user_id: 16,
+        name: "stadust".to_string(),
+        account_id: Some(8451),
+    }
+}
+
+#[test]
+fn store_and_lookup() {
+    let mut cache = Cache::connect(&start_server()).unwrap();
+
+    assert_eq!(cache.lookup(&CreatorKey(16)).unwrap(), CacheEntry::Missing);
+
+    cache.store(&creator(), &CreatorKey(16)).unwrap();
+
+    match cache.lookup(&CreatorKey(16)).unwrap() {
+        CacheEntry::Cached(cached, _) => assert_eq!(cached, creator()),
+        other => panic!("expected cached creator, got {:?}", other),
+    }
+}
+
+#[test]
+fn entries_expire() {
+    let mut cache = Cache::connect(&start_server())
+        .unwrap()
+        .with_expiry(Expiry::uniform(Duration::hours(1)).absent(Duration::seconds(1)));
+
+    Store::<CreatorKey>::mark_absent(&mut cache, &CreatorKey(16)).unwrap();
+
+    assert!(matches!(cache.lookup(&CreatorKey(16)).unwrap(), CacheEntry::MarkedAbsent(_)));
+
+    thread::sleep(std::time::Duration::from_millis(1100));
+
+    assert_eq!(cache.lookup(&CreatorKey(16)).unwrap(), CacheEntry::Missing);
+}
+
+#[test]
+fn daily_level_expires_at_rollover() {
+    let mut cache = Cache::connect(&start_server()).unwrap();
+    let daily = DailyLevel {
+        level: (),
+        index: 1234,
+        weekly: false,
+        seconds_left: 1,
+    };
+
+    cache.store(&daily, &DailyLevelRequest::daily()).unwrap();
+
+    assert!(matches!(cache.lookup(&DailyLevelRequest::daily()).unwrap(), CacheEntry::Cached(..)));
+
+    thread::sleep(std::time::Duration::from_millis(1100));
+
+    assert_eq!(cache.lookup(&DailyLevelRequest::daily()).unwrap(), CacheEntry::Missing);
+}
+
+#[test]
+fn invalidation_removes_comments() {
+    let mut cache = Cache::connect(&start_server()).unwrap();
+
+    cache.store(&Vec::new(), &LevelCommentsRequest::new(10)).unwrap();
+    cache.store(&Vec::new(), &LevelCommentsRequest::new(10).page(1)).unwrap();
+    cache.store(&Vec::new(), &LevelCommentsRequest::new(11)).unwrap();
+
+    cache.invalidate_level_comments(10).unwrap();
+
+    assert_eq!(cache.lookup(&LevelCommentsRequest::new(10)).unwrap(), CacheEntry::Missing);
+    assert_eq!(cache.lookup(&LevelCommentsRequest::new(10).page(1)).unwrap(), CacheEntry::Missing);
+    assert!(matches!(
+        cache.lookup(&LevelCommentsRequest::new(11)).unwrap(),
+        CacheEntry::Cached(..)
+    ));
+}
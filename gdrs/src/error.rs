@@ -33,6 +33,14 @@ pub enum ApiError {
     #[fail(display = "Required data at index {} missing", _0)]
     MissingData(String),
 
+    /// The server rejected a request modifying data with the given (negative) error code
+    #[fail(display = "The server rejected the request with error code {}", _0)]
+    Rejected(i32),
+
+    /// The server rejected a comment because the account is banned from commenting
+    #[fail(display = "The account is banned from commenting for {} more seconds: {}", duration, reason)]
+    CommentBan { duration: u64, reason: String },
+
     /// An error caused by the underlying api client implementation occured
     #[fail(display = "An API client specific error occurate: {}", _0)]
     Custom(#[cause] hyper::Error),
@@ -5,16 +5,20 @@ use gdcf::{
         request::{
             comment::{LevelCommentsRequest, ProfileCommentsRequest},
             user::UserSearchRequest,
-            LevelRequest, LevelsRequest, Request as GdcfRequest, UserRequest,
+            write::{LikeRequest, RateStarsRequest, UploadLevelCommentRequest, UploadLevelRequest, UploadProfileCommentRequest},
+            BlockedUsersRequest, DailyLevelRequest, FriendListRequest, FriendRequestsRequest, GauntletsRequest, LeaderboardRequest,
+            LevelRequest, LevelScoresRequest, LevelsRequest, MapPacksRequest, MessageRequest, MessagesRequest, Request as GdcfRequest,
+            SongInfoRequest, UserRequest,
         },
     },
     Secondary,
 };
 use gdcf_model::{
     comment::{CommentUser, LevelComment, ProfileComment},
-    level::{Level, PartialLevel},
+    level::{DailyLevel, Gauntlet, Level, LevelScore, MapPack, PartialLevel},
+    message::{Message, PartialMessage},
     song::NewgroundsSong,
-    user::{Creator, SearchedUser, User},
+    user::{Creator, FriendRequest, LeaderboardEntry, ListedUser, SearchedUser, User},
 };
 use gdcf_parse::Parse;
 use log::{info, trace, warn};
@@ -187,6 +191,7 @@ impl Handler for LevelCommentsRequest {
         match sections.next() {
             Some(section) => {
                 let mut comments = Vec::new();
+                let mut users = Vec::new();
 
                 for object in section.split('|') {
                     let mut parts = object.split(':');
@@ -194,27 +199,16 @@ impl Handler for LevelCommentsRequest {
                     if let (Some(raw_comment), Some(raw_user)) = (parts.next(), parts.next()) {
                         trace!("Processing comment {} by user {}", raw_comment, raw_user);
 
-                        let comment = LevelComment::parse_str(raw_comment, '~')?;
+                        let comment: LevelComment = LevelComment::parse_str(raw_comment, '~')?;
 
                         // This is the dummy placeholder object used by robtop when the player has been deleted
-                        let user = if raw_user == "1~~9~~10~~11~~14~~15~~16~" {
-                            None
+                        if raw_user == "1~~9~~10~~11~~14~~15~~16~" {
+                            users.push(Secondary::MissingCommentUser(comment.user_id))
                         } else {
-                            Some(CommentUser::parse_str(raw_user, '~')?)
-                        };
-
-                        comments.push(LevelComment {
-                            user,
-                            content: comment.content,
-                            user_id: comment.user_id,
-                            likes: comment.likes,
-                            comment_id: comment.comment_id,
-                            is_flagged_spam: comment.is_flagged_spam,
-                            time_since_post: comment.time_since_post,
-                            progress: comment.progress,
-                            is_elder_mod: comment.is_elder_mod,
-                            special_color: comment.special_color,
-                        })
+                            users.push(Secondary::CommentUser(comment.user_id, CommentUser::parse_str(raw_user, '~')?))
+                        }
+
+                        comments.push(comment)
                     } else {
                         return Err(ApiError::UnexpectedFormat)
                     }
@@ -222,7 +216,7 @@ impl Handler for LevelCommentsRequest {
 
                 info!("We got a total of {} comments!", comments.len());
 
-                Ok(Response::Exact(comments))
+                Ok(Response::More(comments, users))
             },
             None => Err(ApiError::UnexpectedFormat),
         }
@@ -263,3 +257,392 @@ impl Handler for ProfileCommentsRequest {
         Req::ProfileCommentsRequest(self)
     }
 }
+
+impl Handler for LeaderboardRequest {
+    fn endpoint() -> &'static str {
+        endpoint!("getGJScores20")
+    }
+
+    fn handle(response_body: &str) -> Result<Response<Self::Result>, ApiError> {
+        check_resp!(response_body);
+
+        let entries: Vec<LeaderboardEntry> = response_body
+            .split('|')
+            .filter(|fragment| !fragment.is_empty())
+            .map(|fragment| LeaderboardEntry::parse_str(fragment, ':'))
+            .collect::<Result<_, _>>()?;
+
+        info!("We got a total of {} leaderboard entries!", entries.len());
+
+        Ok(Response::Exact(entries))
+    }
+
+    fn to_req(&self) -> Req {
+        Req::LeaderboardRequest(self)
+    }
+}
+
+impl Handler for LevelScoresRequest {
+    fn endpoint() -> &'static str {
+        endpoint!("getGJLevelScores211")
+    }
+
+    fn handle(response_body: &str) -> Result<Response<Self::Result>, ApiError> {
+        check_resp!(response_body);
+
+        let scores: Vec<LevelScore> = response_body
+            .split('|')
+            .filter(|fragment| !fragment.is_empty())
+            .map(|fragment| LevelScore::parse_str(fragment, ':'))
+            .collect::<Result<_, _>>()?;
+
+        info!("We got a total of {} level scores!", scores.len());
+
+        Ok(Response::Exact(scores))
+    }
+
+    fn to_req(&self) -> Req {
+        Req::LevelScoresRequest(self)
+    }
+}
+
+impl Handler for MapPacksRequest {
+    fn endpoint() -> &'static str {
+        endpoint!("getGJMapPacks21")
+    }
+
+    fn handle(response_body: &str) -> Result<Response<Self::Result>, ApiError> {
+        check_resp!(response_body);
+
+        let mut sections = response_body.split('#');
+
+        match sections.next() {
+            Some(section) => {
+                let packs: Vec<MapPack> = section
+                    .split('|')
+                    .filter(|fragment| !fragment.is_empty())
+                    .map(|fragment| MapPack::parse_str(fragment, ':'))
+                    .collect::<Result<_, _>>()?;
+
+                info!("We got a total of {} map packs!", packs.len());
+
+                Ok(Response::Exact(packs))
+            },
+            None => Err(ApiError::UnexpectedFormat),
+        }
+    }
+
+    fn to_req(&self) -> Req {
+        Req::MapPacksRequest(self)
+    }
+}
+
+impl Handler for GauntletsRequest {
+    fn endpoint() -> &'static str {
+        endpoint!("getGJGauntlets21")
+    }
+
+    fn handle(response_body: &str) -> Result<Response<Self::Result>, ApiError> {
+        check_resp!(response_body);
+
+        let mut sections = response_body.split('#');
+
+        match sections.next() {
+            Some(section) => {
+                let gauntlets: Vec<Gauntlet> = section
+                    .split('|')
+                    .filter(|fragment| !fragment.is_empty())
+                    .map(|fragment| Gauntlet::parse_str(fragment, ':'))
+                    .collect::<Result<_, _>>()?;
+
+                info!("We got a total of {} gauntlets!", gauntlets.len());
+
+                Ok(Response::Exact(gauntlets))
+            },
+            None => Err(ApiError::UnexpectedFormat),
+        }
+    }
+
+    fn to_req(&self) -> Req {
+        Req::GauntletsRequest(self)
+    }
+}
+
+impl Handler for DailyLevelRequest {
+    fn endpoint() -> &'static str {
+        endpoint!("getGJDailyLevel")
+    }
+
+    fn handle(response_body: &str) -> Result<Response<Self::Result>, ApiError> {
+        check_resp!(response_body);
+
+        Ok(Response::Exact(DailyLevel::parse_unindexed_str(response_body, '|')?))
+    }
+
+    fn to_req(&self) -> Req {
+        Req::DailyLevelRequest(self)
+    }
+}
+
+impl Handler for SongInfoRequest {
+    fn endpoint() -> &'static str {
+        endpoint!("getGJSongInfo")
+    }
+
+    fn handle(response_body: &str) -> Result<Response<Self::Result>, ApiError> {
+        check_resp!(response_body);
+
+        // The server responds with -2 for songs that have been disabled for use in Geometry Dash
+        if response_body == "-2" {
+            return Err(ApiError::NoData)
+        }
+
+        Ok(Response::Exact(NewgroundsSong::parse_str2(response_body, "~|~")?))
+    }
+
+    fn to_req(&self) -> Req {
+        Req::SongInfoRequest(self)
+    }
+}
+
+impl Handler for MessagesRequest {
+    fn endpoint() -> &'static str {
+        endpoint!("getGJMessages20")
+    }
+
+    fn handle(response_body: &str) -> Result<Response<Self::Result>, ApiError> {
+        check_resp!(response_body);
+
+        // The server responds with -2 if there are no (more) messages
+        if response_body == "-2" {
+            return Err(ApiError::NoData)
+        }
+
+        let mut sections = response_body.split('#');
+
+        match sections.next() {
+            Some(section) => {
+                let messages: Vec<PartialMessage> = section
+                    .split('|')
+                    .map(|fragment| PartialMessage::parse_str(fragment, ':'))
+                    .collect::<Result<_, _>>()?;
+
+                info!("Found {} messages", messages.len());
+
+                Ok(Response::Exact(messages))
+            },
+            None => Err(ApiError::UnexpectedFormat),
+        }
+    }
+
+    fn to_req(&self) -> Req {
+        Req::MessagesRequest(self)
+    }
+}
+
+impl Handler for MessageRequest {
+    fn endpoint() -> &'static str {
+        endpoint!("downloadGJMessage20")
+    }
+
+    fn handle(response_body: &str) -> Result<Response<Self::Result>, ApiError> {
+        check_resp!(response_body);
+
+        Ok(Response::Exact(Message::parse_str(response_body, ':')?))
+    }
+
+    fn to_req(&self) -> Req {
+        Req::MessageRequest(self)
+    }
+}
+
+fn handle_user_list(response_body: &str) -> Result<Response<Vec<ListedUser>>, ApiError> {
+    check_resp!(response_body);
+
+    // The server responds with -2 if the list is empty
+    if response_body == "-2" {
+        return Err(ApiError::NoData)
+    }
+
+    let users: Vec<ListedUser> = response_body
+        .split('|')
+        .map(|fragment| ListedUser::parse_str(fragment, ':'))
+        .collect::<Result<_, _>>()?;
+
+    info!("Found {} users", users.len());
+
+    Ok(Response::Exact(users))
+}
+
+impl Handler for FriendListRequest {
+    fn endpoint() -> &'static str {
+        endpoint!("getGJUserList20")
+    }
+
+    fn handle(response_body: &str) -> Result<Response<Self::Result>, ApiError> {
+        handle_user_list(response_body)
+    }
+
+    fn to_req(&self) -> Req {
+        Req::FriendListRequest(self)
+    }
+}
+
+impl Handler for BlockedUsersRequest {
+    fn endpoint() -> &'static str {
+        endpoint!("getGJUserList20")
+    }
+
+    fn handle(response_body: &str) -> Result<Response<Self::Result>, ApiError> {
+        handle_user_list(response_body)
+    }
+
+    fn to_req(&self) -> Req {
+        Req::BlockedUsersRequest(self)
+    }
+}
+
+impl Handler for FriendRequestsRequest {
+    fn endpoint() -> &'static str {
+        endpoint!("getGJFriendRequests20")
+    }
+
+    fn handle(response_body: &str) -> Result<Response<Self::Result>, ApiError> {
+        check_resp!(response_body);
+
+        // The server responds with -2 if there are no (more) friend requests
+        if response_body == "-2" {
+            return Err(ApiError::NoData)
+        }
+
+        let mut sections = response_body.split('#');
+
+        match sections.next() {
+            Some(section) => {
+                let requests: Vec<FriendRequest> = section
+                    .split('|')
+                    .map(|fragment| FriendRequest::parse_str(fragment, ':'))
+                    .collect::<Result<_, _>>()?;
+
+                info!("Found {} friend requests", requests.len());
+
+                Ok(Response::Exact(requests))
+            },
+            None => Err(ApiError::UnexpectedFormat),
+        }
+    }
+
+    fn to_req(&self) -> Req {
+        Req::FriendRequestsRequest(self)
+    }
+}
+
+/// Handles the response to a request modifying data on the servers, which is either the ID of the
+/// created object (or `1` if no object was created), or a negative error code
+fn handle_write(response_body: &str) -> Result<u64, ApiError> {
+    // Accounts banned from commenting get a response of the form `temp_{seconds left}_{reason}`
+    if response_body.starts_with("temp_") {
+        let mut parts = response_body.splitn(3, '_').skip(1);
+
+        return match (parts.next().and_then(|duration| duration.parse().ok()), parts.next()) {
+            (Some(duration), Some(reason)) =>
+                Err(ApiError::CommentBan {
+                    duration,
+                    reason: reason.to_string(),
+                }),
+            _ => Err(ApiError::UnexpectedFormat),
+        }
+    }
+
+    match response_body.parse::<i64>() {
+        Ok(code) if code < 0 => Err(ApiError::Rejected(code as i32)),
+        Ok(id) => Ok(id as u64),
+        Err(_) => Err(ApiError::UnexpectedFormat),
+    }
+}
+
+impl Handler for UploadLevelRequest {
+    fn endpoint() -> &'static str {
+        endpoint!("uploadGJLevel21")
+    }
+
+    fn handle(response_body: &str) -> Result<Response<Self::Result>, ApiError> {
+        let level_id = handle_write(response_body)?;
+
+        info!("Uploaded level with id {}", level_id);
+
+        Ok(Response::Exact(level_id))
+    }
+
+    fn to_req(&self) -> Req {
+        Req::UploadLevelRequest(self)
+    }
+}
+
+impl Handler for UploadLevelCommentRequest {
+    fn endpoint() -> &'static str {
+        endpoint!("uploadGJComment21")
+    }
+
+    fn handle(response_body: &str) -> Result<Response<Self::Result>, ApiError> {
+        let comment_id = handle_write(response_body)?;
+
+        info!("Posted level comment with id {}", comment_id);
+
+        Ok(Response::Exact(comment_id))
+    }
+
+    fn to_req(&self) -> Req {
+        Req::UploadLevelCommentRequest(self)
+    }
+}
+
+impl Handler for UploadProfileCommentRequest {
+    fn endpoint() -> &'static str {
+        endpoint!("uploadGJAccComment20")
+    }
+
+    fn handle(response_body: &str) -> Result<Response<Self::Result>, ApiError> {
+        let comment_id = handle_write(response_body)?;
+
+        info!("Posted profile comment with id {}", comment_id);
+
+        Ok(Response::Exact(comment_id))
+    }
+
+    fn to_req(&self) -> Req {
+        Req::UploadProfileCommentRequest(self)
+    }
+}
+
+impl Handler for LikeRequest {
+    fn endpoint() -> &'static str {
+        endpoint!("likeGJItem211")
+    }
+
+    fn handle(response_body: &str) -> Result<Response<Self::Result>, ApiError> {
+        handle_write(response_body)?;
+
+        Ok(Response::Exact(()))
+    }
+
+    fn to_req(&self) -> Req {
+        Req::LikeRequest(self)
+    }
+}
+
+impl Handler for RateStarsRequest {
+    fn endpoint() -> &'static str {
+        endpoint!("rateGJStars211")
+    }
+
+    fn handle(response_body: &str) -> Result<Response<Self::Result>, ApiError> {
+        handle_write(response_body)?;
+
+        Ok(Response::Exact(()))
+    }
+
+    fn to_req(&self) -> Req {
+        Req::RateStarsRequest(self)
+    }
+}
@@ -13,7 +13,11 @@
 use crate::{
     error::ApiError,
     handle::Handler,
-    ser::{LevelCommentsRequestRem, LevelRequestRem, LevelsRequestRem, ProfileCommentsRequestRem, UserRequestRem, UserSearchRequestRem},
+    ser::{
+        BlockedUsersRequestRem, DailyLevelRequestRem, FriendListRequestRem, FriendRequestsRequestRem, GauntletsRequestRem,
+        LeaderboardRequestRem, LevelCommentsRequestRem, LevelRequestRem, LevelScoresRequestRem, LevelsRequestRem, MapPacksRequestRem,
+        MessageRequestRem, MessagesRequestRem, ProfileCommentsRequestRem, SongInfoRequestRem, UserRequestRem, UserSearchRequestRem,
+    },
 };
 use failure::_core::marker::PhantomData;
 use futures::{
@@ -22,11 +26,16 @@ use futures::{
     Async, Future, Stream,
 };
 use gdcf::api::{
-    client::{MakeRequest, Response},
+    client::{MakeRequest, RawResponse},
     request::{
         comment::{LevelCommentsRequest, ProfileCommentsRequest},
-        level::{LevelRequest, LevelsRequest},
-        user::{UserRequest, UserSearchRequest},
+        leaderboard::LeaderboardRequest,
+        level::{DailyLevelRequest, LevelRequest, LevelScoresRequest, LevelsRequest},
+        message::{MessageRequest, MessagesRequest},
+        pack::{GauntletsRequest, MapPacksRequest},
+        song::SongInfoRequest,
+        user::{BlockedUsersRequest, FriendListRequest, FriendRequestsRequest, UserRequest, UserSearchRequest},
+        write::{LikeRequest, RateStarsRequest, UploadLevelCommentRequest, UploadLevelRequest, UploadProfileCommentRequest},
         Request as GdcfRequest,
     },
     ApiClient,
@@ -67,6 +76,54 @@ pub enum Req<'a> {
 
     #[serde(with = "ProfileCommentsRequestRem")]
     ProfileCommentsRequest(&'a ProfileCommentsRequest),
+
+    #[serde(with = "LeaderboardRequestRem")]
+    LeaderboardRequest(&'a LeaderboardRequest),
+
+    #[serde(with = "LevelScoresRequestRem")]
+    LevelScoresRequest(&'a LevelScoresRequest),
+
+    #[serde(with = "MapPacksRequestRem")]
+    MapPacksRequest(&'a MapPacksRequest),
+
+    #[serde(with = "GauntletsRequestRem")]
+    GauntletsRequest(&'a GauntletsRequest),
+
+    #[serde(with = "DailyLevelRequestRem")]
+    DailyLevelRequest(&'a DailyLevelRequest),
+
+    #[serde(with = "SongInfoRequestRem")]
+    SongInfoRequest(&'a SongInfoRequest),
+
+    #[serde(with = "FriendListRequestRem")]
+    FriendListRequest(&'a FriendListRequest),
+
+    #[serde(with = "BlockedUsersRequestRem")]
+    BlockedUsersRequest(&'a BlockedUsersRequest),
+
+    #[serde(with = "FriendRequestsRequestRem")]
+    FriendRequestsRequest(&'a FriendRequestsRequest),
+
+    #[serde(with = "MessagesRequestRem")]
+    MessagesRequest(&'a MessagesRequest),
+
+    #[serde(with = "MessageRequestRem")]
+    MessageRequest(&'a MessageRequest),
+
+    #[serde(serialize_with = "ser::upload_level")]
+    UploadLevelRequest(&'a UploadLevelRequest),
+
+    #[serde(serialize_with = "ser::upload_level_comment")]
+    UploadLevelCommentRequest(&'a UploadLevelCommentRequest),
+
+    #[serde(serialize_with = "ser::upload_profile_comment")]
+    UploadProfileCommentRequest(&'a UploadProfileCommentRequest),
+
+    #[serde(serialize_with = "ser::like")]
+    LikeRequest(&'a LikeRequest),
+
+    #[serde(serialize_with = "ser::rate_stars")]
+    RateStarsRequest(&'a RateStarsRequest),
 }
 
 #[derive(Debug, Default, Clone)]
@@ -81,7 +138,7 @@ pub struct GdrsFuture<R: Handler> {
 
 impl<R: Handler> Future for GdrsFuture<R> {
     type Error = ApiError;
-    type Item = Response<R::Result>;
+    type Item = RawResponse<R::Result>;
 
     fn poll(&mut self) -> Result<Async<Self::Item>, Self::Error> {
         self.inner.poll()
@@ -155,7 +212,7 @@ enum ProcessRequestFuture<R: Handler> {
 
 impl<R: Handler> Future for ProcessRequestFuture<R> {
     type Error = ApiError;
-    type Item = Response<R::Result>;
+    type Item = RawResponse<R::Result>;
 
     fn poll(&mut self) -> Result<Async<Self::Item>, Self::Error> {
         let response_poll_result = match self {
@@ -203,7 +260,11 @@ impl<R: Handler> Future for ProcessRequestFuture<R> {
 
                                 Err(err)
                             },
-                            Ok(object) => Ok(Async::Ready(object)),
+                            Ok(response) =>
+                                Ok(Async::Ready(RawResponse {
+                                    body: body.to_string(),
+                                    response,
+                                })),
                         }
                     },
                     Err(err) => {
@@ -219,7 +280,7 @@ impl<R: Handler> Future for ProcessRequestFuture<R> {
 impl<R: GdcfRequest + Handler> Action for ApiRequestAction<R> {
     type Error = ApiError;
     type Future = ProcessRequestFuture<R>;
-    type Item = Response<R::Result>;
+    type Item = RawResponse<R::Result>;
 
     fn run(&mut self) -> Self::Future {
         ProcessRequestFuture::WaitingForResponse(self.client.request(make_request::<R>(&self.encoded_request)), PhantomData)
@@ -229,7 +290,8 @@ impl<R: GdcfRequest + Handler> Action for ApiRequestAction<R> {
 fn make_request<R: GdcfRequest + Handler>(encoded_request: &str) -> Request<Body> {
     let len = encoded_request.len();
 
-    info!("Preparing request {} to {}", encoded_request, R::endpoint());
+    // The encoded request isn't logged, as it might contain account credentials
+    info!("Preparing request to {}", R::endpoint());
 
     let mut req = Request::new(Body::from(encoded_request.to_string()));
 
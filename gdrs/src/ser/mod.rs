@@ -1,18 +1,26 @@
 pub use self::request::{
     comment::{LevelCommentsRequestRem, ProfileCommentsRequestRem},
-    level::{LevelRequestRem, LevelsRequestRem},
-    user::{UserRequestRem, UserSearchRequestRem},
+    leaderboard::LeaderboardRequestRem,
+    level::{DailyLevelRequestRem, LevelRequestRem, LevelScoresRequestRem, LevelsRequestRem},
+    message::{MessageRequestRem, MessagesRequestRem},
+    pack::{GauntletsRequestRem, MapPacksRequestRem},
+    song::SongInfoRequestRem,
+    user::{BlockedUsersRequestRem, FriendListRequestRem, FriendRequestsRequestRem, UserRequestRem, UserSearchRequestRem},
+    write::{like, rate_stars, upload_level, upload_level_comment, upload_profile_comment},
     BaseRequestRem,
 };
 use gdcf::api::request::{
     comment::SortMode,
-    level::{CompletionFilter, LevelRequestType, SearchFilters, SongFilter},
+    leaderboard::LeaderboardType,
+    level::{CompletionFilter, LevelRequestType, LevelScoreScope, SearchFilters, SongFilter},
+    write::LikeTarget,
+    Authentication, LevelRequest,
 };
 use gdcf_model::{
-    level::{DemonRating, LevelLength, LevelRating},
+    level::{DemonRating, LevelLength, LevelRating, Password},
     GameVersion,
 };
-use gdcf_parse::convert::RobtopInto;
+use gdcf_parse::convert::{Base64Converter, RobtopInto};
 use joinery::Joinable;
 use serde::{ser::SerializeMap, Serializer};
 
@@ -43,6 +51,23 @@ pub fn level_list(ids: &[u64]) -> String {
     ids
 }
 
+/// Serializes the given list of IDs into a comma separated list
+pub(super) fn id_list<S>(ids: &[u64], serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.collect_str(&ids.iter().join_with(","))
+}
+
+/// Gets the level ID to send for the given [`LevelRequest`], which is negative when requesting a
+/// [`SpecialLevel`](gdcf::api::request::SpecialLevel)
+pub(super) fn level_id(request: &LevelRequest) -> i64 {
+    match request.special {
+        Some(special) => special.into(),
+        None => request.level_id as i64,
+    }
+}
+
 pub(super) fn game_version<S>(version: &GameVersion, serializer: S) -> Result<S::Ok, S::Error>
 where
     S: Serializer,
@@ -85,6 +110,13 @@ where
     serializer.serialize_i32(i32::from(*req_type))
 }
 
+pub(super) fn level_score_scope<S>(scope: &LevelScoreScope, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.serialize_i32(i32::from(*scope))
+}
+
 pub(super) fn sort_mode<S>(sort_mode: &SortMode, serializer: S) -> Result<S::Ok, S::Error>
 where
     S: Serializer,
@@ -95,6 +127,13 @@ where
     }
 }
 
+pub(super) fn leaderboard_type<S>(leaderboard_type: &LeaderboardType, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.collect_str(leaderboard_type)
+}
+
 pub(super) fn search_filters<S>(filters: &SearchFilters, serializer: S) -> Result<S::Ok, S::Error>
 where
     S: Serializer,
@@ -136,3 +175,126 @@ where
 
     map.end()
 }
+
+pub(super) fn authentication<S>(authentication: &Option<Authentication>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    let mut map = serializer.serialize_map(None)?;
+
+    if let Some(authentication) = authentication {
+        map.serialize_entry("accountID", &authentication.account_id)?;
+        map.serialize_entry("gjp", &authentication.gjp)?;
+    }
+
+    map.end()
+}
+
+/// Serializes the authentication of a request to `getGJUserList20`, together with the `type` of
+/// user list to retrieve (`0` for the friend list, `1` for blocked users).
+fn user_list<S>(authentication: &Authentication, list_type: u8, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    let mut map = serializer.serialize_map(None)?;
+
+    map.serialize_entry("accountID", &authentication.account_id)?;
+    map.serialize_entry("gjp", &authentication.gjp)?;
+    map.serialize_entry("type", &list_type)?;
+
+    map.end()
+}
+
+pub(super) fn friend_list<S>(authentication: &Authentication, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    user_list(authentication, 0, serializer)
+}
+
+pub(super) fn blocked_users<S>(authentication: &Authentication, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    user_list(authentication, 1, serializer)
+}
+
+/// URL-safe base64 encodes the given string, which is how robtop transmits most user provided text
+pub fn encode_base64(value: &str) -> String {
+    RobtopInto::<Base64Converter, String>::robtop_into(value.to_string())
+}
+
+pub(super) fn base64<S>(value: &str, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.serialize_str(&encode_base64(value))
+}
+
+pub(super) fn base64_option<S>(value: &Option<String>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    match value {
+        Some(value) => base64(value, serializer),
+        None => serializer.serialize_str(""),
+    }
+}
+
+pub(super) fn level_length<S>(length: &LevelLength, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.serialize_str(&length.robtop_into())
+}
+
+pub(super) fn id_or_zero<S>(id: &Option<u64>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.serialize_u64(id.unwrap_or(0))
+}
+
+pub(super) fn progress<S>(progress: &Option<u8>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.serialize_u8(progress.unwrap_or(0))
+}
+
+/// Unlike in responses, the copy password of a level is transmitted in plain text when uploading
+/// it
+pub(super) fn upload_password<S>(password: &Password, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    match password {
+        Password::NoCopy => serializer.serialize_str("0"),
+        Password::FreeCopy => serializer.serialize_str("1"),
+        Password::PasswordCopy(password) => serializer.serialize_str(password),
+    }
+}
+
+/// Splits the given [`LikeTarget`] into the `itemID`, `type` and `special` values of a like
+/// request
+pub(super) fn like_target_values(target: &LikeTarget) -> (u64, u8, u64) {
+    match *target {
+        LikeTarget::Level(level_id) => (level_id, 1, 0),
+        LikeTarget::LevelComment { level_id, comment_id } => (comment_id, 2, level_id),
+        LikeTarget::ProfileComment(comment_id) => (comment_id, 3, 0),
+    }
+}
+
+pub(super) fn like_target<S>(target: &LikeTarget, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    let (item_id, item_type, special) = like_target_values(target);
+    let mut map = serializer.serialize_map(None)?;
+
+    map.serialize_entry("itemID", &item_id)?;
+    map.serialize_entry("type", &item_type)?;
+    map.serialize_entry("special", &special)?;
+
+    map.end()
+}
@@ -0,0 +1,22 @@
+use super::BaseRequestRem;
+use crate::ser;
+use gdcf::api::request::{
+    leaderboard::{LeaderboardRequest, LeaderboardType},
+    Authentication, BaseRequest,
+};
+use serde_derive::Serialize;
+
+#[derive(Serialize)]
+#[serde(remote = "LeaderboardRequest")]
+pub struct LeaderboardRequestRem {
+    #[serde(flatten, with = "BaseRequestRem")]
+    pub base: BaseRequest,
+
+    #[serde(serialize_with = "ser::leaderboard_type", rename = "type")]
+    pub leaderboard_type: LeaderboardType,
+
+    pub count: u32,
+
+    #[serde(flatten, serialize_with = "ser::authentication")]
+    pub authentication: Option<Authentication>,
+}
@@ -1,8 +1,8 @@
 use super::BaseRequestRem;
 use crate::ser;
 use gdcf::api::request::{
-    level::{LevelRequestType, LevelsRequest, SearchFilters},
-    BaseRequest, LevelRequest,
+    level::{DailyLevelRequest, LevelRequestType, LevelScoreScope, LevelScoresRequest, LevelsRequest, SearchFilters},
+    Authentication, BaseRequest, LevelRequest,
 };
 use gdcf_model::level::{DemonRating, LevelLength, LevelRating};
 use serde_derive::Serialize;
@@ -13,8 +13,8 @@ pub struct LevelRequestRem {
     #[serde(flatten, with = "BaseRequestRem")]
     base: BaseRequest,
 
-    #[serde(rename = "levelID")]
-    level_id: u64,
+    #[serde(rename = "levelID", getter = "ser::level_id")]
+    level_id: i64,
 
     #[serde(serialize_with = "ser::bool_to_int")]
     inc: bool,
@@ -54,4 +54,36 @@ pub struct LevelsRequestRem {
 
     #[serde(flatten, serialize_with = "ser::search_filters")]
     pub search_filters: SearchFilters,
+
+    #[serde(flatten, serialize_with = "ser::authentication")]
+    pub authentication: Option<Authentication>,
+
+    #[serde(skip_serializing_if = "Vec::is_empty", serialize_with = "ser::id_list")]
+    pub followed: Vec<u64>,
+}
+
+#[derive(Serialize)]
+#[serde(remote = "LevelScoresRequest")]
+pub struct LevelScoresRequestRem {
+    #[serde(flatten, with = "BaseRequestRem")]
+    base: BaseRequest,
+
+    #[serde(rename = "levelID")]
+    level_id: u64,
+
+    #[serde(rename = "type", serialize_with = "ser::level_score_scope")]
+    scope: LevelScoreScope,
+
+    #[serde(flatten, serialize_with = "ser::authentication")]
+    authentication: Option<Authentication>,
+}
+
+#[derive(Serialize)]
+#[serde(remote = "DailyLevelRequest")]
+pub struct DailyLevelRequestRem {
+    #[serde(flatten, with = "BaseRequestRem")]
+    base: BaseRequest,
+
+    #[serde(serialize_with = "ser::bool_to_int")]
+    weekly: bool,
 }
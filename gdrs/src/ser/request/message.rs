@@ -0,0 +1,40 @@
+use super::{AuthenticationRem, BaseRequestRem};
+use crate::ser;
+use gdcf::api::request::{
+    message::{MessageRequest, MessagesRequest},
+    Authentication, BaseRequest,
+};
+use serde_derive::Serialize;
+
+#[derive(Serialize)]
+#[serde(remote = "MessagesRequest")]
+pub struct MessagesRequestRem {
+    #[serde(flatten, with = "BaseRequestRem")]
+    base: BaseRequest,
+
+    #[serde(flatten, with = "AuthenticationRem")]
+    authentication: Authentication,
+
+    page: u32,
+
+    total: u32,
+
+    #[serde(rename = "getSent", serialize_with = "ser::bool_to_int")]
+    sent: bool,
+}
+
+#[derive(Serialize)]
+#[serde(remote = "MessageRequest")]
+pub struct MessageRequestRem {
+    #[serde(flatten, with = "BaseRequestRem")]
+    base: BaseRequest,
+
+    #[serde(flatten, with = "AuthenticationRem")]
+    authentication: Authentication,
+
+    #[serde(rename = "messageID")]
+    message_id: u64,
+
+    #[serde(rename = "isSender", serialize_with = "ser::bool_to_int")]
+    sent: bool,
+}
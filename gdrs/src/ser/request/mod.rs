@@ -1,11 +1,16 @@
 use crate::ser;
-use gdcf::api::request::BaseRequest;
+use gdcf::api::request::{Authentication, BaseRequest};
 use gdcf_model::GameVersion;
 use serde_derive::Serialize;
 
 pub(super) mod comment;
+pub(super) mod leaderboard;
 pub(super) mod level;
+pub(super) mod message;
+pub(super) mod pack;
+pub(super) mod song;
 pub(super) mod user;
+pub(super) mod write;
 
 #[derive(Serialize)]
 #[serde(remote = "BaseRequest")]
@@ -17,3 +22,11 @@ pub struct BaseRequestRem {
     pub binary_version: GameVersion,
     pub secret: &'static str,
 }
+
+#[derive(Serialize)]
+#[serde(remote = "Authentication")]
+pub struct AuthenticationRem {
+    #[serde(rename = "accountID")]
+    pub account_id: u64,
+    pub gjp: String,
+}
@@ -0,0 +1,22 @@
+use super::BaseRequestRem;
+use gdcf::api::request::{
+    pack::{GauntletsRequest, MapPacksRequest},
+    BaseRequest,
+};
+use serde_derive::Serialize;
+
+#[derive(Serialize)]
+#[serde(remote = "MapPacksRequest")]
+pub struct MapPacksRequestRem {
+    #[serde(flatten, with = "BaseRequestRem")]
+    pub base: BaseRequest,
+
+    pub page: u32,
+}
+
+#[derive(Serialize)]
+#[serde(remote = "GauntletsRequest")]
+pub struct GauntletsRequestRem {
+    #[serde(flatten, with = "BaseRequestRem")]
+    pub base: BaseRequest,
+}
@@ -0,0 +1,13 @@
+use super::BaseRequestRem;
+use gdcf::api::request::{song::SongInfoRequest, BaseRequest};
+use serde_derive::Serialize;
+
+#[derive(Serialize)]
+#[serde(remote = "SongInfoRequest")]
+pub struct SongInfoRequestRem {
+    #[serde(flatten, with = "BaseRequestRem")]
+    base: BaseRequest,
+
+    #[serde(rename = "songID")]
+    song_id: u64,
+}
@@ -1,7 +1,8 @@
-use super::BaseRequestRem;
+use super::{AuthenticationRem, BaseRequestRem};
+use crate::ser;
 use gdcf::api::request::{
-    user::{UserRequest, UserSearchRequest},
-    BaseRequest,
+    user::{BlockedUsersRequest, FriendListRequest, FriendRequestsRequest, UserRequest, UserSearchRequest},
+    Authentication, BaseRequest,
 };
 use serde_derive::Serialize;
 
@@ -28,3 +29,40 @@ pub struct UserSearchRequestRem {
     #[serde(rename = "str")]
     search_string: String,
 }
+
+#[derive(Serialize)]
+#[serde(remote = "FriendListRequest")]
+pub struct FriendListRequestRem {
+    #[serde(flatten, with = "BaseRequestRem")]
+    base: BaseRequest,
+
+    #[serde(flatten, serialize_with = "ser::friend_list")]
+    authentication: Authentication,
+}
+
+#[derive(Serialize)]
+#[serde(remote = "BlockedUsersRequest")]
+pub struct BlockedUsersRequestRem {
+    #[serde(flatten, with = "BaseRequestRem")]
+    base: BaseRequest,
+
+    #[serde(flatten, serialize_with = "ser::blocked_users")]
+    authentication: Authentication,
+}
+
+#[derive(Serialize)]
+#[serde(remote = "FriendRequestsRequest")]
+pub struct FriendRequestsRequestRem {
+    #[serde(flatten, with = "BaseRequestRem")]
+    base: BaseRequest,
+
+    #[serde(flatten, with = "AuthenticationRem")]
+    authentication: Authentication,
+
+    page: u32,
+
+    total: u32,
+
+    #[serde(rename = "getSent", serialize_with = "ser::bool_to_int")]
+    sent: bool,
+}
@@ -0,0 +1,302 @@
+use super::{AuthenticationRem, BaseRequestRem};
+use crate::ser;
+use gdcf::api::request::{
+    write::{LikeRequest, LikeTarget, RateStarsRequest, UploadLevelCommentRequest, UploadLevelRequest, UploadProfileCommentRequest},
+    Authentication, BaseRequest,
+};
+use gdcf_model::level::{LevelLength, Password};
+use gdcf_parse::util;
+use serde::{Serialize, Serializer};
+use serde_derive::Serialize;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+const COMMENT_SALT: &str = "xPT6iUrtws0J";
+const COMMENT_KEY: &str = "29481";
+const LIKE_SALT: &str = "ysg6pUrtjn0J";
+const LIKE_KEY: &str = "58281";
+const RATE_SALT: &str = "ysg6pUrtjn0J";
+const RATE_KEY: &str = "58281";
+
+// The like and rate endpoints want a device ID and a player ID to be included in their checksum.
+// The servers do not verify either, so we simply send placeholder values
+const UDID: &str = "S15212345678901234567890123456789012345678";
+const UUID: &str = "0";
+
+/// Generates the random string robtop wants included in some requests (the `rs` or `seed` field)
+///
+/// Its only purpose is to make the request's checksum unique, so the current time will do.
+fn random_string() -> String {
+    let nanos = SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.subsec_nanos()).unwrap_or(0);
+
+    format!("{:010}", nanos)
+}
+
+#[derive(Serialize)]
+#[serde(remote = "UploadLevelRequest")]
+pub struct UploadLevelRequestRem {
+    #[serde(flatten, with = "BaseRequestRem")]
+    base: BaseRequest,
+
+    #[serde(flatten, with = "AuthenticationRem")]
+    authentication: Authentication,
+
+    #[serde(rename = "userName")]
+    user_name: String,
+
+    #[serde(rename = "levelID")]
+    level_id: u64,
+
+    #[serde(rename = "levelName")]
+    name: String,
+
+    #[serde(rename = "levelDesc", serialize_with = "ser::base64_option")]
+    description: Option<String>,
+
+    #[serde(rename = "levelVersion")]
+    version: u32,
+
+    #[serde(rename = "levelLength", serialize_with = "ser::level_length")]
+    length: LevelLength,
+
+    #[serde(rename = "audioTrack")]
+    main_song: u8,
+
+    #[serde(rename = "songID", serialize_with = "ser::id_or_zero")]
+    custom_song: Option<u64>,
+
+    #[serde(serialize_with = "ser::upload_password")]
+    password: Password,
+
+    #[serde(rename = "original", serialize_with = "ser::id_or_zero")]
+    copy_of: Option<u64>,
+
+    #[serde(rename = "twoPlayer", serialize_with = "ser::bool_to_int")]
+    two_player: bool,
+
+    #[serde(rename = "objects")]
+    object_amount: u32,
+
+    #[serde(rename = "coins")]
+    coin_amount: u8,
+
+    #[serde(rename = "requestedStars")]
+    requested_stars: u8,
+
+    #[serde(serialize_with = "ser::bool_to_int")]
+    unlisted: bool,
+
+    #[serde(rename = "levelString")]
+    level_data: String,
+}
+
+pub fn upload_level<S>(request: &UploadLevelRequest, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    #[derive(Serialize)]
+    struct WithSeed<'a> {
+        #[serde(flatten, with = "UploadLevelRequestRem")]
+        request: &'a UploadLevelRequest,
+        seed: String,
+        seed2: String,
+    }
+
+    WithSeed {
+        request,
+        seed: random_string(),
+        seed2: util::level_upload_seed(&request.level_data),
+    }
+    .serialize(serializer)
+}
+
+#[derive(Serialize)]
+#[serde(remote = "UploadLevelCommentRequest")]
+pub struct UploadLevelCommentRequestRem {
+    #[serde(flatten, with = "BaseRequestRem")]
+    base: BaseRequest,
+
+    #[serde(flatten, with = "AuthenticationRem")]
+    authentication: Authentication,
+
+    #[serde(rename = "userName")]
+    user_name: String,
+
+    #[serde(rename = "levelID")]
+    level_id: u64,
+
+    #[serde(rename = "comment", serialize_with = "ser::base64")]
+    content: String,
+
+    #[serde(rename = "percent", serialize_with = "ser::progress")]
+    progress: Option<u8>,
+}
+
+pub fn upload_level_comment<S>(request: &UploadLevelCommentRequest, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    #[derive(Serialize)]
+    struct WithChecksum<'a> {
+        #[serde(flatten, with = "UploadLevelCommentRequestRem")]
+        request: &'a UploadLevelCommentRequest,
+        chk: String,
+    }
+
+    let chk = util::checksum(
+        &[
+            &request.user_name,
+            &ser::encode_base64(&request.content),
+            &request.level_id.to_string(),
+            &request.progress.unwrap_or(0).to_string(),
+            "0",
+        ],
+        COMMENT_SALT,
+        COMMENT_KEY,
+    );
+
+    WithChecksum { request, chk }.serialize(serializer)
+}
+
+#[derive(Serialize)]
+#[serde(remote = "UploadProfileCommentRequest")]
+pub struct UploadProfileCommentRequestRem {
+    #[serde(flatten, with = "BaseRequestRem")]
+    base: BaseRequest,
+
+    #[serde(flatten, with = "AuthenticationRem")]
+    authentication: Authentication,
+
+    #[serde(rename = "userName")]
+    user_name: String,
+
+    #[serde(rename = "comment", serialize_with = "ser::base64")]
+    content: String,
+}
+
+pub fn upload_profile_comment<S>(request: &UploadProfileCommentRequest, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    #[derive(Serialize)]
+    struct WithCommentType<'a> {
+        #[serde(flatten, with = "UploadProfileCommentRequestRem")]
+        request: &'a UploadProfileCommentRequest,
+
+        #[serde(rename = "cType")]
+        comment_type: u8,
+    }
+
+    WithCommentType { request, comment_type: 1 }.serialize(serializer)
+}
+
+#[derive(Serialize)]
+#[serde(remote = "LikeRequest")]
+pub struct LikeRequestRem {
+    #[serde(flatten, with = "BaseRequestRem")]
+    base: BaseRequest,
+
+    #[serde(flatten, with = "AuthenticationRem")]
+    authentication: Authentication,
+
+    #[serde(flatten, serialize_with = "ser::like_target")]
+    target: LikeTarget,
+
+    #[serde(serialize_with = "ser::bool_to_int")]
+    like: bool,
+}
+
+pub fn like<S>(request: &LikeRequest, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    #[derive(Serialize)]
+    struct WithChecksum<'a> {
+        #[serde(flatten, with = "LikeRequestRem")]
+        request: &'a LikeRequest,
+        rs: String,
+        udid: &'static str,
+        uuid: &'static str,
+        chk: String,
+    }
+
+    let (item_id, item_type, special) = ser::like_target_values(&request.target);
+    let rs = random_string();
+
+    let chk = util::checksum(
+        &[
+            &special.to_string(),
+            &item_id.to_string(),
+            &ser::bool(request.like).to_string(),
+            &item_type.to_string(),
+            &rs,
+            &request.authentication.account_id.to_string(),
+            UDID,
+            UUID,
+        ],
+        LIKE_SALT,
+        LIKE_KEY,
+    );
+
+    WithChecksum {
+        request,
+        rs,
+        udid: UDID,
+        uuid: UUID,
+        chk,
+    }
+    .serialize(serializer)
+}
+
+#[derive(Serialize)]
+#[serde(remote = "RateStarsRequest")]
+pub struct RateStarsRequestRem {
+    #[serde(flatten, with = "BaseRequestRem")]
+    base: BaseRequest,
+
+    #[serde(flatten, with = "AuthenticationRem")]
+    authentication: Authentication,
+
+    #[serde(rename = "levelID")]
+    level_id: u64,
+
+    stars: u8,
+}
+
+pub fn rate_stars<S>(request: &RateStarsRequest, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    #[derive(Serialize)]
+    struct WithChecksum<'a> {
+        #[serde(flatten, with = "RateStarsRequestRem")]
+        request: &'a RateStarsRequest,
+        rs: String,
+        udid: &'static str,
+        uuid: &'static str,
+        chk: String,
+    }
+
+    let rs = random_string();
+
+    let chk = util::checksum(
+        &[
+            &request.level_id.to_string(),
+            &request.stars.to_string(),
+            &rs,
+            &request.authentication.account_id.to_string(),
+            UDID,
+            UUID,
+        ],
+        RATE_SALT,
+        RATE_KEY,
+    );
+
+    WithChecksum {
+        request,
+        rs,
+        udid: UDID,
+        uuid: UUID,
+        chk,
+    }
+    .serialize(serializer)
+}